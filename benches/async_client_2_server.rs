@@ -10,7 +10,7 @@ use radius_rust::protocol::{
     error::RadiusError,
     radius_packet::{ RadiusPacket, RadiusMsgType }
 };
-use radius_rust::tools::{ encrypt_data, ipv4_string_to_bytes, integer_to_bytes };
+use radius_rust::tools::{ ipv4_string_to_bytes, integer_to_bytes };
 
 use async_std::net::UdpSocket;
 use async_std::task;
@@ -57,7 +57,7 @@ impl AsyncClientTrait for ClientWrapper {
                 break;
             }
 
-            self.socket.send_to(&packet.to_bytes(), &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             let mut response = [0; 4096];
             let (amount, _)  = self.socket.recv_from(&mut response).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
@@ -80,7 +80,7 @@ impl AsyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             let mut response = [0; 4096];
             let (amount, _)  = self.socket.recv_from(&mut response).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
@@ -111,7 +111,7 @@ fn test_async_auth_client_wo_response_against_server(b: &mut Bencher) {
     let mut auth_packet = client.base_client.create_auth_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, auth_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -141,7 +141,7 @@ fn test_async_auth_client_w_response_against_server(b: &mut Bencher) {
     let mut auth_packet = client.base_client.create_auth_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, auth_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -174,7 +174,7 @@ fn test_async_acct_client_wo_response_against_server(b: &mut Bencher) {
     let mut acct_packet = client.base_client.create_acct_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, acct_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -204,7 +204,7 @@ fn test_async_acct_client_w_response_against_server(b: &mut Bencher) {
     let mut acct_packet = client.base_client.create_acct_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, acct_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -237,7 +237,7 @@ fn test_async_coa_client_wo_response_against_server(b: &mut Bencher) {
     let mut coa_packet = client.base_client.create_coa_packet();
     let attributes     = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, coa_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -267,7 +267,7 @@ fn test_async_coa_client_w_response_against_server(b: &mut Bencher) {
     let mut coa_packet = client.base_client.create_coa_packet();
     let attributes     = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, coa_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -9,7 +9,7 @@ use radius_rust::protocol::{
     error::RadiusError,
     radius_packet::{ RadiusPacket, RadiusMsgType }
 };
-use radius_rust::tools::{ encrypt_data, ipv4_string_to_bytes, integer_to_bytes };
+use radius_rust::tools::{ ipv4_string_to_bytes, integer_to_bytes };
 
 use mio::net::UdpSocket;
 use mio::{ Events, Interest, Poll, Token };
@@ -64,7 +64,7 @@ impl SyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
             self.socket_poll.poll(&mut events, Some(timeout)).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             for event in events.iter() {
@@ -96,7 +96,7 @@ impl SyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             self.socket_poll.poll(&mut events, Some(timeout)).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
@@ -136,7 +136,7 @@ fn test_auth_client_wo_response_against_server(b: &mut Bencher) {
     let mut auth_packet = client.base_client.create_auth_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, auth_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -164,7 +164,7 @@ fn test_auth_client_w_response_against_server(b: &mut Bencher) {
     let mut auth_packet = client.base_client.create_auth_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, auth_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -195,7 +195,7 @@ fn test_acct_client_wo_response_against_server(b: &mut Bencher) {
     let mut acct_packet = client.base_client.create_acct_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, acct_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -223,7 +223,7 @@ fn test_acct_client_w_response_against_server(b: &mut Bencher) {
     let mut acct_packet = client.base_client.create_acct_packet();
     let attributes      = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, acct_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -254,7 +254,7 @@ fn test_coa_client_wo_response_against_server(b: &mut Bencher) {
     let mut coa_packet = client.base_client.create_coa_packet();
     let attributes     = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, coa_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
@@ -282,7 +282,7 @@ fn test_coa_client_w_response_against_server(b: &mut Bencher) {
     let mut coa_packet = client.base_client.create_coa_packet();
     let attributes     = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name).unwrap(),
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, coa_packet.authenticator(), client.base_client.secret().as_bytes())).unwrap(),
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec()).unwrap(),
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes).unwrap(),
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0)).unwrap(),
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2)).unwrap(),
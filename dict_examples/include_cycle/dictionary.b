@@ -0,0 +1,3 @@
+ATTRIBUTE NAS-IP-Address 4 ipaddr
+
+$INCLUDE dictionary.a
@@ -110,11 +110,11 @@ impl AsyncServerTrait for CustomServer {
                 self.base_server.create_attribute_by_name("Framed-IPv6-Prefix", ipv6_bytes)?
             ];
 
-            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccessAccept, attributes, &mut request);
+            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccessAccept, attributes, &mut request)?;
             // ============================
 
             // Send RADIUS packet
-            self.auth_socket.send_to(&reply_packet.to_bytes(), &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.auth_socket.send_to(&reply_packet.to_bytes()?, &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
             // ============================
         }
     }
@@ -139,11 +139,11 @@ impl AsyncServerTrait for CustomServer {
                 self.base_server.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes)?
             ];
 
-            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccountingResponse, attributes, &mut request);
+            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccountingResponse, attributes, &mut request)?;
             // ============================
 
             // Send RADIUS packet
-            self.acct_socket.send_to(&reply_packet.to_bytes(), &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.acct_socket.send_to(&reply_packet.to_bytes()?, &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
             // ============================
         }
     }
@@ -162,11 +162,11 @@ impl AsyncServerTrait for CustomServer {
             let attributes = vec![
                 self.base_server.create_attribute_by_name("State", state)?
             ];
-            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::CoAACK, attributes, &mut request);
+            let mut reply_packet = self.base_server.create_reply_packet(TypeCode::CoAACK, attributes, &mut request)?;
             // ============================
 
             // Send RADIUS packet
-            self.coa_socket.send_to(&reply_packet.to_bytes(), &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.coa_socket.send_to(&reply_packet.to_bytes()?, &source_addr).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
             // ============================
         }
     }
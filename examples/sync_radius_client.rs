@@ -10,7 +10,7 @@ use radius_rust::client::{ client::Client, SyncClientTrait };
 use radius_rust::protocol::dictionary::Dictionary;
 use radius_rust::protocol::error::RadiusError;
 use radius_rust::protocol::radius_packet::{ RadiusPacket, RadiusMsgType };
-use radius_rust::tools::{ encrypt_data, ipv4_string_to_bytes, integer_to_bytes };
+use radius_rust::tools::{ ipv4_string_to_bytes, integer_to_bytes };
 
 use log::{ debug, LevelFilter };
 use mio::net::UdpSocket;
@@ -64,8 +64,8 @@ impl SyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            debug!("Sending: {:?}", &packet.to_bytes());
-            self.socket.send_to(&packet.to_bytes(), remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
+            debug!("Sending: {:?}", &packet.to_bytes()?);
+            self.socket.send_to(&packet.to_bytes()?, remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
             self.socket_poll.poll(&mut events, Some(timeout)).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             for event in events.iter() {
@@ -107,7 +107,8 @@ fn main() -> Result<(), RadiusError> {
     let mut auth_packet = client.base_client.create_auth_packet();
     let attributes = vec![
         client.base_client.create_attribute_by_name("User-Name",          user_name)?,
-        client.base_client.create_attribute_by_name("Password",           encrypt_data(user_pass, auth_packet.authenticator(), client.base_client.secret().as_bytes()))?,
+        // Password is flagged with encrypt=1 in dictionary, so it is encrypted when packet is converted into bytes
+        client.base_client.create_attribute_by_name("Password",           user_pass.to_vec())?,
         client.base_client.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes)?,
         client.base_client.create_attribute_by_name("NAS-Port-Id",        integer_to_bytes(0))?,
         client.base_client.create_attribute_by_name("Service-Type",       integer_to_bytes(2))?,
@@ -95,7 +95,7 @@ impl SyncServerTrait for CustomServer {
                             Ok((packet_size, source_address)) => {
                                 if self.base_server.host_allowed(&source_address) {
                                     let response = self.handle_auth_request(&mut request[..packet_size])?;
-                                    self.auth_socket.send_to(&response.as_slice(), source_address)?;
+                                    self.auth_socket.send_to(response.as_slice(), source_address)?;
                                     break;
                                 } else {
                                     warn!("{:?} is not listed as allowed", &source_address);
@@ -118,7 +118,7 @@ impl SyncServerTrait for CustomServer {
                             Ok((packet_size, source_address)) => {
                                 if self.base_server.host_allowed(&source_address) {
                                     let response = self.handle_acct_request(&mut request[..packet_size])?;
-                                    self.acct_socket.send_to(&response.as_slice(), source_address)?;
+                                    self.acct_socket.send_to(response.as_slice(), source_address)?;
                                     break;
                                 } else {
                                     warn!("{:?} is not listed as allowed", &source_address);
@@ -141,7 +141,7 @@ impl SyncServerTrait for CustomServer {
                             Ok((packet_size, source_address)) => {
                                 if self.base_server.host_allowed(&source_address) {
                                     let response = self.handle_coa_request(&mut request[..packet_size])?;
-                                    self.coa_socket.send_to(&response.as_slice(), source_address)?;
+                                    self.coa_socket.send_to(response.as_slice(), source_address)?;
                                     break;
                                 } else {
                                     warn!("{:?} is not listed as allowed", &source_address);
@@ -175,8 +175,8 @@ impl SyncServerTrait for CustomServer {
             self.base_server.create_attribute_by_name("Framed-IPv6-Prefix", ipv6_bytes)?
         ];
 
-        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccessAccept, attributes, request);
-        Ok(reply_packet.to_bytes())
+        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccessAccept, attributes, request)?;
+        reply_packet.to_bytes()
     }
 
     fn handle_acct_request(&self, request: &mut [u8]) -> Result<Vec<u8>, RadiusError> {
@@ -191,8 +191,8 @@ impl SyncServerTrait for CustomServer {
             self.base_server.create_attribute_by_name("NAS-IP-Address",     nas_ip_addr_bytes)?
         ];
 
-        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccountingResponse, attributes, request);
-        Ok(reply_packet.to_bytes())
+        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::AccountingResponse, attributes, request)?;
+        reply_packet.to_bytes()
     }
 
     fn handle_coa_request(&self, request: &mut [u8]) -> Result<Vec<u8>, RadiusError> {
@@ -202,8 +202,8 @@ impl SyncServerTrait for CustomServer {
             self.base_server.create_attribute_by_name("State", state)?
         ];
 
-        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::CoAACK, attributes, request);
-        Ok(reply_packet.to_bytes())
+        let mut reply_packet = self.base_server.create_reply_packet(TypeCode::CoAACK, attributes, request)?;
+        reply_packet.to_bytes()
     }
     // ------------------------
 }
@@ -0,0 +1,74 @@
+//! Checks RADIUS dictionaries for parse errors and consistency problems
+//!
+//! Usage: `radius-dict-lint [--strict] <dictionary file or directory>...`
+//!
+//! Every file or directory is checked on its own (directories are loaded the same way as
+//! **Dictionary::from_dir()**, files could pull in others with `$INCLUDE`). Problems are printed
+//! as `file:line: reason (token: "...")` and the process exits with code 1, if any were found
+
+
+use radius_rust::protocol::dictionary::{ DictionaryParser, ParseMode };
+use radius_rust::protocol::error::RadiusError;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+
+fn lint(path: &str, mode: ParseMode) -> Result<usize, RadiusError> {
+    let mut parser = DictionaryParser::new(mode);
+    if Path::new(path).is_dir() {
+        parser.parse_dir(path)?;
+    } else {
+        parser.parse_file(path)?;
+    }
+
+    for warning in parser.warnings() {
+        println!("warning: {}", warning);
+    }
+
+    let diagnostics = parser.into_dictionary().validate();
+    for diagnostic in diagnostics.iter() {
+        println!("error: {}", diagnostic);
+    }
+    Ok(diagnostics.len())
+}
+
+fn main() {
+    let mut mode  = ParseMode::Lenient;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--strict" => mode = ParseMode::Strict,
+            _          => paths.push(arg)
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("Usage: radius-dict-lint [--strict] <dictionary file or directory>...");
+        process::exit(2);
+    }
+
+    let mut failed = false;
+    for path in paths.iter() {
+        match lint(path, mode) {
+            Ok(0)      => println!("{}: ok", path),
+            Ok(errors) => {
+                println!("{}: {} error(s) found", path, errors);
+                failed = true;
+            },
+            Err(RadiusError::DictionaryParseError { error }) => {
+                println!("error: {}", error);
+                failed = true;
+            },
+            Err(error) => {
+                println!("error: {}: {:?}", path, error);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -1,15 +1,16 @@
 //! RADIUS Generic Client implementation
 
 
-use crate::protocol::dictionary::Dictionary;
+use crate::protocol::dictionary::{ Dictionary, SharedDictionary };
 use crate::protocol::error::RadiusError;
 use crate::protocol::host::Host;
-use crate::protocol::radius_packet::{ RadiusAttribute, RadiusPacket, RadiusMsgType, TypeCode };
+use crate::protocol::radius_packet::{ verify_packet_length, DecodeMode, RadiusAttribute, RadiusPacket, RadiusPacketRef, RadiusMsgType, TypeCode };
 
 use crypto::digest::Digest;
 use crypto::hmac::Hmac;
 use crypto::mac::Mac;
 use crypto::md5::Md5;
+use std::sync::Arc;
 use log::debug;
 
 
@@ -29,10 +30,20 @@ impl Client {
     ///
     /// To be called **first** when creating RADIUS Client instance
     pub fn with_dictionary(dictionary: Dictionary) -> Client {
-        let host = Host::with_dictionary(dictionary);
+        Client::with_host(Host::with_dictionary(dictionary))
+    }
 
+    /// Initialise Client instance with SharedDictionary (other fields would be set to default values)
+    ///
+    /// Dictionary could then be reloaded through any clone of the handle, without rebuilding
+    /// Client instance. To be called **first** when creating RADIUS Client instance
+    pub fn with_shared_dictionary(dictionary: SharedDictionary) -> Client {
+        Client::with_host(Host::with_shared_dictionary(dictionary))
+    }
+
+    fn with_host(host: Host) -> Client {
         Client {
-            host:    host,
+            host,
             server:  String::from(""),
             secret:  String::from(""),
             retries: 1,
@@ -79,6 +90,15 @@ impl Client {
         self.timeout = timeout;
         self
     }
+
+    /// **Optional**
+    ///
+    /// Sets how attributes, that are not defined in dictionary, are treated when replies are
+    /// decoded, otherwise you would have a default value of [DecodeMode::Strict]
+    pub fn set_decode_mode(mut self, decode_mode: DecodeMode) -> Client {
+        self.host.set_decode_mode(decode_mode);
+        self
+    }
     // ===================
 
     /// Returns port of RADIUS server, that receives given type of RADIUS message/packet
@@ -86,6 +106,22 @@ impl Client {
         self.host.port(code)
     }
 
+    /// Returns snapshot of the dictionary, that is currently in use
+    ///
+    /// Snapshot is not affected by later reloads, so it is safe to hold on to it while
+    /// processing a single request. Other Client functions take their own snapshot on every
+    /// call, so to create request attributes & decode reply with the same dictionary, pass this
+    /// snapshot to [RadiusAttribute] constructors
+    /// and [initialise_reply_from_bytes_with_dictionary](Client::initialise_reply_from_bytes_with_dictionary)
+    pub fn dictionary_snapshot(&self) -> Arc<Dictionary> {
+        self.host.dictionary_snapshot()
+    }
+
+    /// Returns dictionary handle, which could be used to reload dictionary (see [SharedDictionary::reload])
+    pub fn dictionary(&self) -> &SharedDictionary {
+        self.host.dictionary()
+    }
+
     /// Returns hostname/FQDN of RADIUS Server
     pub fn server(&self) -> &str {
         &self.server
@@ -110,28 +146,35 @@ impl Client {
     ///
     /// You would need to set attributes manually via *set_attributes()* function
     pub fn create_packet(&self, code: TypeCode) -> RadiusPacket {
-        RadiusPacket::initialise_packet(code)
+        self.initialise_packet(code)
     }
 
     /// Creates RADIUS Access Request packet
     ///
     /// You would need to set attributes manually via *set_attributes()* function
     pub fn create_auth_packet(&self) -> RadiusPacket {
-        RadiusPacket::initialise_packet(TypeCode::AccessRequest)
+        self.initialise_packet(TypeCode::AccessRequest)
     }
 
     /// Creates RADIUS Accounting Request packet without attributes
     ///
     /// You would need to set attributes manually via *set_attributes()* function
     pub fn create_acct_packet(&self) -> RadiusPacket {
-        RadiusPacket::initialise_packet(TypeCode::AccountingRequest)
+        self.initialise_packet(TypeCode::AccountingRequest)
     }
 
     /// Creates RADIUS CoA Request packet without attributes
     ///
     /// You would need to set attributes manually via *set_attributes()* function
     pub fn create_coa_packet(&self) -> RadiusPacket {
-        RadiusPacket::initialise_packet(TypeCode::CoARequest)
+        self.initialise_packet(TypeCode::CoARequest)
+    }
+
+    fn initialise_packet(&self, code: TypeCode) -> RadiusPacket {
+        // Client's secret is set, so attributes flagged with encrypt= in dictionary are encrypted automatically
+        let mut packet = RadiusPacket::initialise_packet(code);
+        packet.set_secret(&self.secret);
+        packet
     }
 
     /// Creates RADIUS packet attribute by name, that is defined in dictionary file
@@ -158,6 +201,33 @@ impl Client {
         self.host.create_attribute_by_name(attribute_name, value)
     }
 
+    /// Creates tagged RADIUS packet attribute by name (ie `Tunnel-Type`), that is defined in
+    /// dictionary file and flagged with **has_tag**
+    ///
+    /// Tag allows to group several tunnel attributes together, RFC 2868 section 3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use radius_rust::client::client::Client;
+    /// use radius_rust::protocol::dictionary::Dictionary;
+    ///
+    /// fn main() {
+    ///     let dictionary = Dictionary::from_file("./dictionaries/dictionary").unwrap();
+    ///     let client     = Client::with_dictionary(dictionary)
+    ///        .set_server(String::from("127.0.0.1"))
+    ///        .set_secret(String::from("secret"))
+    ///        .set_retries(1)
+    ///        .set_timeout(2);
+    ///
+    ///     client.create_tagged_attribute_by_name("Tunnel-Type", 1, vec![0, 0, 0, 13]).unwrap();
+    ///     client.create_tagged_attribute_by_name("Tunnel-Private-Group-Id", 1, String::from("100").into_bytes()).unwrap();
+    /// }
+    /// ```
+    pub fn create_tagged_attribute_by_name(&self, attribute_name: &str, tag: u8, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
+        self.host.create_tagged_attribute_by_name(attribute_name, tag, value)
+    }
+
     /// Creates RADIUS packet attribute by ID, that is defined in dictionary file
     ///
     /// # Examples
@@ -182,22 +252,28 @@ impl Client {
         self.host.create_attribute_by_id(attribute_id, value)
     }
 
+    /// Creates RADIUS packet attribute by name, that holds VALUE with given name (ie `Service-Type`
+    /// & `Framed-User`), both are defined in dictionary file
+    pub fn create_attribute_by_value_name(&self, attribute_name: &str, value_name: &str) -> Result<RadiusAttribute, RadiusError> {
+        self.host.create_attribute_by_value_name(attribute_name, value_name)
+    }
+
     /// Generates HMAC-MD5 hash for Message-Authenticator attribute
     ///
     /// Note: this function assumes that RadiusAttribute Message-Authenticator already exists in RadiusPacket 
-    pub fn generate_message_hash(&self, packet: &mut RadiusPacket) -> Vec<u8> {
+    pub fn generate_message_hash(&self, packet: &mut RadiusPacket) -> Result<Vec<u8>, RadiusError> {
         // Feels redundant, but let it be for now
         let mut hash = Hmac::new(Md5::new(), self.secret.as_bytes());
 
-        hash.input(&packet.to_bytes());
-        hash.result().code().to_vec()
+        hash.input(&packet.to_bytes()?);
+        Ok(hash.result().code().to_vec())
     }
 
     /// Gets the original value as a String
     ///
     /// If the RadiusAttribute respresents dictionary attribute of type: string, ipaddr, ipv6addr or ipv6prefix
     pub fn radius_attr_original_string_value(&self, attribute: &RadiusAttribute) -> Result<String, RadiusError> {
-        let dict_attr = self.host.dictionary_attribute_by_id(attribute.id()).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with ID: {} found in dictionary", attribute.id())} )?;
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
         attribute.original_string_value(dict_attr.code_type())
     }
 
@@ -205,17 +281,67 @@ impl Client {
     ///
     /// If the RadiusAttribute respresents dictionary attribute of type: integer or date
     pub fn radius_attr_original_integer_value(&self, attribute: &RadiusAttribute) -> Result<u64, RadiusError> {
-        let dict_attr = self.host.dictionary_attribute_by_id(attribute.id()).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with ID: {} found in dictionary", attribute.id())} )?;
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
         attribute.original_integer_value(dict_attr.code_type())
     }
 
+    /// Gets the tag & original value as an Integer
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute of type integer, that is flagged
+    /// with **has_tag** (ie `Tunnel-Type`)
+    pub fn radius_attr_original_tagged_integer_value(&self, attribute: &RadiusAttribute) -> Result<(u8, u64), RadiusError> {
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
+        attribute.original_tagged_integer_value(dict_attr.code_type())
+    }
+
+    /// Gets the tag (if attribute carries one) & original value as a String
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute, that is flagged with **has_tag**
+    /// (ie `Tunnel-Private-Group-Id`)
+    pub fn radius_attr_original_tagged_string_value(&self, attribute: &RadiusAttribute) -> Result<(Option<u8>, String), RadiusError> {
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
+        attribute.original_tagged_string_value(dict_attr.code_type())
+    }
+
+    /// Gets the name of the VALUE, that RadiusAttribute value represents (ie `Framed-User` for
+    /// `Service-Type = 2`)
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute of type: integer, byte, short or integer64
+    pub fn radius_attr_value_name(&self, attribute: &RadiusAttribute) -> Result<Option<String>, RadiusError> {
+        self.host.attribute_value_name(attribute)
+    }
+
     /// Initialises RadiusPacket from bytes
     pub fn initialise_packet_from_bytes(&self, reply: &[u8]) -> Result<RadiusPacket, RadiusError> {
         self.host.initialise_packet_from_bytes(reply)
     }
 
+    /// Creates view of reply RadiusPacket, that borrows given bytes
+    ///
+    /// Packet & attribute lengths are checked and (unless [DecodeMode::Lenient] is set) every
+    /// attribute has to be defined in dictionary
+    pub fn parse_reply<'a>(&self, reply: &'a [u8]) -> Result<RadiusPacketRef<'a>, RadiusError> {
+        self.host.parse_packet(reply)
+    }
+
+    /// Initialises reply RadiusPacket from bytes
+    ///
+    /// Unlike [initialise_packet_from_bytes](Client::initialise_packet_from_bytes), decrypts values of
+    /// attributes, that are flagged with **encrypt=** in dictionary (ie Tunnel-Password)
+    pub fn initialise_reply_from_bytes(&self, request: &RadiusPacket, reply: &[u8]) -> Result<RadiusPacket, RadiusError> {
+        self.host.initialise_packet_from_bytes_with_secret(reply, &self.secret, Some(request.authenticator()))
+    }
+
+    /// Initialises reply RadiusPacket from bytes the same way, as [initialise_reply_from_bytes](Client::initialise_reply_from_bytes),
+    /// but with given dictionary snapshot (see [dictionary](Client::dictionary)) instead of the
+    /// one, that is currently in use
+    pub fn initialise_reply_from_bytes_with_dictionary(&self, dictionary: &Dictionary, request: &RadiusPacket, reply: &[u8]) -> Result<RadiusPacket, RadiusError> {
+        self.host.initialise_packet_from_bytes_with_dictionary(dictionary, reply, &self.secret, Some(request.authenticator()))
+    }
+
     /// Verifies that reply packet's ID and authenticator are a match
     pub fn verify_reply(&self, request: &RadiusPacket, reply: &[u8]) -> Result<(), RadiusError> {
+        let reply = &reply[..verify_packet_length(reply)?];
         if request.id() != reply[1] {
             return Err( RadiusError::ValidationError { error: String::from("Packet identifier mismatch") } )
         };
@@ -224,9 +350,9 @@ impl Client {
         let mut hash       = [0; 16];
 
         md5_hasher.input(&reply[0..4]);             // Append reply type code, reply ID and reply length
-        md5_hasher.input(&request.authenticator()); // Append request authenticator
+        md5_hasher.input(request.authenticator());  // Append request authenticator
         md5_hasher.input(&reply[20..]);             // Append rest of the reply
-        md5_hasher.input(&self.secret.as_bytes());  // Append secret
+        md5_hasher.input(self.secret.as_bytes());   // Append secret
 
         md5_hasher.result(&mut hash);
 
@@ -242,12 +368,12 @@ impl Client {
 
     /// Verifies that reply packet's Message-Authenticator attribute is valid
     pub fn verify_message_authenticator(&self, packet: &[u8]) -> Result<(), RadiusError> {
-        self.host.verify_message_authenticator(&self.secret, &packet)
+        self.host.verify_message_authenticator(&self.secret, packet)
     }
 
     /// Verifies that reply packet's attributes have valid values
     pub fn verify_packet_attributes(&self, packet: &[u8]) -> Result<(), RadiusError> {
-        self.host.verify_packet_attributes(&packet)
+        self.host.verify_packet_attributes(packet)
     }
 }
 
@@ -337,4 +463,50 @@ mod tests {
             Err(error) => assert_eq!(String::from("Radius packet attribute is malformed"), error.to_string())
         }
     }
+
+    #[test]
+    fn test_radius_attr_value_name() {
+        let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let client     = Client::with_dictionary(dictionary)
+            .set_server(String::from("127.0.0.1"))
+            .set_secret(String::from("secret"));
+
+        let service_type = client.create_attribute_by_value_name("Service-Type", "Framed-User").unwrap();
+
+        assert_eq!(vec![0, 0, 0, 2],                  service_type.value());
+        assert_eq!(Some(String::from("Framed-User")), client.radius_attr_value_name(&service_type).unwrap());
+        assert!(client.create_attribute_by_value_name("Service-Type", "Unknown-User").is_err());
+        assert!(client.create_attribute_by_value_name("User-Name",    "Framed-User").is_err());
+    }
+
+    #[test]
+    fn test_verify_malformed_reply() {
+        let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let client     = Client::with_dictionary(dictionary)
+            .set_server(String::from("127.0.0.1"))
+            .set_secret(String::from("secret"));
+        let request    = client.create_auth_packet();
+
+        assert!(client.verify_reply(&request, &[2, request.id()]).is_err());
+        assert!(client.verify_reply(&request, &[2, request.id(), 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(client.initialise_packet_from_bytes(&[2, request.id(), 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0]).is_err());
+    }
+
+    #[test]
+    fn test_create_attribute_by_resolved_name() {
+        let dictionary = Dictionary::from_str("VENDOR Cisco 9\nVENDOR Foo 100\n\
+            BEGIN-VENDOR Cisco\nATTRIBUTE AVPair 1 string\nEND-VENDOR Cisco\n\
+            BEGIN-VENDOR Foo\nATTRIBUTE AVPair 1 string\nEND-VENDOR Foo\nATTRIBUTE User-Name 1 string\n").unwrap();
+        let client     = Client::with_dictionary(dictionary)
+            .set_server(String::from("127.0.0.1"))
+            .set_secret(String::from("secret"));
+
+        assert_eq!("User-Name", client.create_attribute_by_name("user-name", vec![1]).unwrap().name());
+        assert_eq!("AVPair",    client.create_attribute_by_name("cisco.avpair", vec![1]).unwrap().name());
+
+        match client.create_attribute_by_name("AVPair", vec![1]) {
+            Err(RadiusError::AmbiguousAttributeError { error }) => assert_eq!("AVPair matches Cisco.AVPair, Foo.AVPair", error),
+            other                                               => panic!("Unexpected result: {:?}", other)
+        }
+    }
 }
@@ -0,0 +1,367 @@
+//! Generates Rust code from RADIUS dictionary
+//!
+//! Meant to be called from `build.rs`, so attributes are referenced through generated modules
+//! instead of string names and typos fail at compile time, not at runtime:
+//!
+//! ```no_run
+//! // build.rs
+//! use radius_rust::codegen;
+//!
+//! fn main() {
+//!     let code     = codegen::generate_from_file("./dict_examples/integration_dict").unwrap();
+//!     let out_path = format!("{}/radius_dictionary.rs", std::env::var("OUT_DIR").unwrap());
+//!
+//!     std::fs::write(out_path, code).unwrap();
+//!     println!("cargo:rerun-if-changed=./dict_examples/integration_dict");
+//! }
+//! ```
+//!
+//! and then in the crate itself
+//!
+//! ```ignore
+//! mod radius_dictionary {
+//!     include!(concat!(env!("OUT_DIR"), "/radius_dictionary.rs"));
+//! }
+//!
+//! use radius_dictionary::{ service_type, user_name, ServiceType };
+//!
+//! user_name::add(&mut packet, &dictionary, "testing")?;
+//! service_type::add(&mut packet, &dictionary, ServiceType::LoginUser)?;
+//! assert_eq!(Some(ServiceType::LoginUser), service_type::get(&packet).transpose()?);
+//! ```
+//!
+//! Every ATTRIBUTE gets its own module (ie `Framed-IPv6-Prefix` becomes `framed_ipv6_prefix`)
+//! with **NAME** & **CODE** constants (and **VENDOR_ID** for vendor attributes). Top-level
+//! attributes, both standard and vendor ones, also get typed **create()**, **add()** & **get()**
+//! functions, and VALUEs of their integer data types are generated as enums (ie
+//! `ServiceType::LoginUser`). Attributes nested into TLVs or extended attributes only get
+//! constants, as they could not be created by name alone
+
+
+use std::collections::HashSet;
+use std::fmt::{ self, Write };
+
+use crate::protocol::dictionary::{ Dictionary, DictionaryAttribute, SupportedAttributeTypes };
+use crate::protocol::error::RadiusError;
+
+const CRATE_PATH:  &str = "::radius_rust";
+const WRITE_ERROR: &str = "writing into String never fails";
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield"
+];
+
+
+/// Generates Rust code for every attribute & value of the dictionary
+///
+/// Generated code is meant to be `include!`d into a module of the crate, that depends on
+/// `radius-rust`
+pub fn generate(dictionary: &Dictionary) -> String {
+    let mut code    = String::from("// Generated by radius-rust from RADIUS dictionary, do not edit\n");
+    let mut modules = HashSet::new();
+    let mut enums   = HashSet::new();
+
+    for attr in dictionary.attributes() {
+        let module_name = snake_case(attr.name(), "attr");
+        if !modules.insert(module_name.to_string()) {
+            // Same name (after conversion) is already generated, first definition wins as in Dictionary lookups
+            continue;
+        }
+
+        let typed     = attr.parent_oid().is_empty();
+        let data_type = DataType::from_attribute(attr);
+
+        let enum_name = if typed && data_type.enumerable {
+            let enum_name = camel_case(attr.name(), "Attr");
+            if enums.insert(enum_name.to_string()) && write_enum(&mut code, dictionary, attr, &enum_name, &data_type).expect(WRITE_ERROR) {
+                Some(enum_name)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        write_module(&mut code, attr, &module_name, typed, &data_type, enum_name.as_deref()).expect(WRITE_ERROR);
+    }
+
+    code
+}
+
+/// Reads dictionary file (with default [ParseMode::Lenient](crate::protocol::dictionary::ParseMode::Lenient))
+/// and generates Rust code for it
+///
+/// See [generate] for details
+pub fn generate_from_file(dictionary_path: &str) -> Result<String, RadiusError> {
+    let dictionary = Dictionary::from_file(dictionary_path)?;
+    Ok(generate(&dictionary))
+}
+
+
+struct DataType {
+    supported_type: &'static str,
+    set_type:       &'static str,
+    get_type:       &'static str,
+    encode:         &'static str,
+    decode:         &'static str,
+    tools:          &'static str,
+    enumerable:     bool
+}
+
+impl DataType {
+    fn from_attribute(attr: &DictionaryAttribute) -> DataType {
+        let (supported_type, set_type, get_type, encode, decode, tools, enumerable) = match attr.code_type() {
+            Some(SupportedAttributeTypes::AsciiString) => ("AsciiString", "&str", "String",  "value.as_bytes().to_vec()",          "original_string_value",  "",                            false),
+            Some(SupportedAttributeTypes::Integer)     => ("Integer",     "u32",  "u32",     "integer_to_bytes(value)",            "original_integer_value", "integer_to_bytes",            true),
+            Some(SupportedAttributeTypes::Date)        => ("Date",        "u64",  "u64",     "timestamp_to_bytes(value)",          "original_integer_value", "timestamp_to_bytes",          false),
+            Some(SupportedAttributeTypes::Byte)        => ("Byte",        "u8",   "u8",      "byte_to_bytes(value)",               "original_integer_value", "byte_to_bytes",               true),
+            Some(SupportedAttributeTypes::Short)       => ("Short",       "u16",  "u16",     "short_to_bytes(value)",              "original_integer_value", "short_to_bytes",              true),
+            Some(SupportedAttributeTypes::Integer64)   => ("Integer64",   "u64",  "u64",     "integer64_to_bytes(value)",          "original_integer_value", "integer64_to_bytes",          true),
+            Some(SupportedAttributeTypes::Signed)      => ("Signed",      "i32",  "i32",     "signed_to_bytes(value)",             "original_signed_value",  "signed_to_bytes",             false),
+            Some(SupportedAttributeTypes::IPv4Addr)    => ("IPv4Addr",    "&str", "String",  "ipv4_string_to_bytes(value)?",       "original_string_value",  "ipv4_string_to_bytes",        false),
+            Some(SupportedAttributeTypes::IPv6Addr)    => ("IPv6Addr",    "&str", "String",  "ipv6_string_to_bytes(value)?",       "original_string_value",  "ipv6_string_to_bytes",        false),
+            Some(SupportedAttributeTypes::IPv6Prefix)  => ("IPv6Prefix",  "&str", "String",  "ipv6_string_to_bytes(value)?",       "original_string_value",  "ipv6_string_to_bytes",        false),
+            Some(SupportedAttributeTypes::IPv4Prefix)  => ("IPv4Prefix",  "&str", "String",  "ipv4_prefix_string_to_bytes(value)?", "original_string_value", "ipv4_prefix_string_to_bytes", false),
+            Some(SupportedAttributeTypes::ComboIP)     => ("ComboIP",     "&str", "String",  "combo_ip_string_to_bytes(value)?",   "original_string_value",  "combo_ip_string_to_bytes",    false),
+            Some(SupportedAttributeTypes::Ether)       => ("Ether",       "&str", "String",  "ether_string_to_bytes(value)?",      "original_string_value",  "ether_string_to_bytes",       false),
+            Some(SupportedAttributeTypes::IfId)        => ("IfId",        "&str", "String",  "ifid_string_to_bytes(value)?",       "original_string_value",  "ifid_string_to_bytes",        false),
+            _                                          => ("",            "&[u8]", "Vec<u8>", "value.to_vec()",                    "",                       "",                            false)
+        };
+
+        DataType { supported_type, set_type, get_type, encode, decode, tools, enumerable }
+    }
+
+    fn fits(&self, value: u64) -> bool {
+        match self.get_type {
+            "u8"  => value <= u64::from(u8::MAX),
+            "u16" => value <= u64::from(u16::MAX),
+            "u32" => value <= u64::from(u32::MAX),
+            _     => true
+        }
+    }
+}
+
+fn write_enum(code: &mut String, dictionary: &Dictionary, attr: &DictionaryAttribute, enum_name: &str, data_type: &DataType) -> Result<bool, fmt::Error> {
+    let mut variants = Vec::new();
+    let mut names    = HashSet::new();
+    for value in dictionary.values().iter().filter(|value| value.attribute_name() == attr.name() && value.vendor_name() == attr.vendor_name() && data_type.fits(value.value())) {
+        let variant = camel_case(value.name(), "Value");
+        if names.insert(variant.to_string()) {
+            variants.push((variant, value.name(), value.value()));
+        }
+    }
+    if variants.is_empty() {
+        return Ok(false)
+    }
+
+    writeln!(code)?;
+    writeln!(code, "/// Values of {} attribute", attr.name())?;
+    writeln!(code, "#[allow(dead_code)]")?;
+    writeln!(code, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(code, "pub enum {} {{", enum_name)?;
+    for (index, (variant, name, value)) in variants.iter().enumerate() {
+        writeln!(code, "    /// {} ({})", name, value)?;
+        writeln!(code, "    {}{}", variant, if index + 1 < variants.len() { "," } else { "" })?;
+    }
+    writeln!(code, "}}")?;
+    writeln!(code)?;
+    writeln!(code, "#[allow(dead_code)]")?;
+    writeln!(code, "impl {} {{", enum_name)?;
+    writeln!(code, "    /// Returns value, as defined in dictionary")?;
+    writeln!(code, "    pub fn value(&self) -> {} {{", data_type.get_type)?;
+    writeln!(code, "        match self {{")?;
+    for (index, (variant, _, value)) in variants.iter().enumerate() {
+        writeln!(code, "            {}::{} => {}{}", enum_name, variant, value, if index + 1 < variants.len() { "," } else { "" })?;
+    }
+    writeln!(code, "        }}")?;
+    writeln!(code, "    }}")?;
+    writeln!(code)?;
+    writeln!(code, "    /// Returns enumerated value by its number, if it is defined in dictionary")?;
+    writeln!(code, "    pub fn from_value(value: {}) -> Option<{}> {{", data_type.get_type, enum_name)?;
+    writeln!(code, "        match value {{")?;
+    let mut seen = HashSet::new();
+    for (variant, _, value) in variants.iter() {
+        // Several names could share the same number, first one wins as in Dictionary lookups
+        if seen.insert(*value) {
+            writeln!(code, "            {} => Some({}::{}),", value, enum_name, variant)?;
+        }
+    }
+    writeln!(code, "            _ => None")?;
+    writeln!(code, "        }}")?;
+    writeln!(code, "    }}")?;
+    writeln!(code, "}}")?;
+
+    Ok(true)
+}
+
+fn write_module(code: &mut String, attr: &DictionaryAttribute, module_name: &str, typed: bool, data_type: &DataType, enum_name: Option<&str>) -> fmt::Result {
+    let type_name = match attr.code_type() {
+        Some(code_type) => format!("{:?}", code_type),
+        None            => String::from("unknown type")
+    };
+
+    writeln!(code)?;
+    writeln!(code, "/// {} attribute ({}, {})", attr.name(), attr.oid().iter().map(|code| code.to_string()).collect::<Vec<String>>().join("."), type_name)?;
+    writeln!(code, "#[allow(dead_code)]")?;
+    writeln!(code, "pub mod {} {{", module_name)?;
+    if typed {
+        if !data_type.supported_type.is_empty() {
+            writeln!(code, "    use {}::protocol::dictionary::{{ Dictionary, SupportedAttributeTypes }};", CRATE_PATH)?;
+        } else {
+            writeln!(code, "    use {}::protocol::dictionary::Dictionary;", CRATE_PATH)?;
+        }
+        writeln!(code, "    use {}::protocol::error::RadiusError;", CRATE_PATH)?;
+        writeln!(code, "    use {}::protocol::radius_packet::{{ RadiusAttribute, RadiusPacket }};", CRATE_PATH)?;
+        if !data_type.tools.is_empty() {
+            writeln!(code, "    use {}::tools::{};", CRATE_PATH, data_type.tools)?;
+        }
+        if let Some(enum_name) = enum_name {
+            writeln!(code, "    use super::{};", enum_name)?;
+        }
+        writeln!(code)?;
+    }
+    writeln!(code, "    /// Attribute name, as defined in dictionary")?;
+    writeln!(code, "    pub const NAME: &str = {:?};", attr.name())?;
+    writeln!(code, "    /// Attribute code")?;
+    writeln!(code, "    pub const CODE: u32 = {};", attr.code())?;
+    if let Some(vendor_id) = attr.vendor_id() {
+        writeln!(code, "    /// Vendor-Id of attribute's vendor")?;
+        writeln!(code, "    pub const VENDOR_ID: u32 = {};", vendor_id)?;
+    }
+
+    if typed {
+        let (set_type, get_type, encode) = match enum_name {
+            Some(enum_name) => (enum_name, enum_name, data_type.encode.replace("(value)", "(value.value())")),
+            None            => (data_type.set_type, data_type.get_type, data_type.encode.to_string())
+        };
+
+        writeln!(code)?;
+        writeln!(code, "    /// Creates {} RadiusAttribute", attr.name())?;
+        writeln!(code, "    pub fn create(dictionary: &Dictionary, value: {}) -> Result<RadiusAttribute, RadiusError> {{", set_type)?;
+        writeln!(code, "        let bytes = {};", encode)?;
+        writeln!(code, "        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError {{ error: format!(\"Failed to create: {{:?}} attribute. Check if attribute exists in provided dictionary file\", NAME) }})")?;
+        writeln!(code, "    }}")?;
+        writeln!(code)?;
+        writeln!(code, "    /// Adds {} RadiusAttribute to RadiusPacket", attr.name())?;
+        writeln!(code, "    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: {}) -> Result<(), RadiusError> {{", set_type)?;
+        writeln!(code, "        packet.add_attribute(create(dictionary, value)?);")?;
+        writeln!(code, "        Ok(())")?;
+        writeln!(code, "    }}")?;
+        writeln!(code)?;
+        writeln!(code, "    /// Returns value of the first {} RadiusAttribute of RadiusPacket, if there is one", attr.name())?;
+        writeln!(code, "    pub fn get(packet: &RadiusPacket) -> Option<Result<{}, RadiusError>> {{", get_type)?;
+        match (data_type.decode, enum_name) {
+            ("", _)                   => {
+                writeln!(code, "        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))")?;
+            },
+            (decode, Some(enum_name)) => {
+                writeln!(code, "        packet.attribute_by_name(NAME).map(|attr| {{")?;
+                writeln!(code, "            let value = attr.{}(&Some(SupportedAttributeTypes::{}))?;", decode, data_type.supported_type)?;
+                writeln!(code, "            {}::from_value(value as {}).ok_or_else(|| RadiusError::MalformedAttributeError {{ error: format!(\"{{}} is not a known {{}} value\", value, NAME) }})", enum_name, data_type.get_type)?;
+                writeln!(code, "        }})")?;
+            },
+            (decode, None)            => {
+                let cast = match data_type.get_type {
+                    "u8" | "u16" | "u32" => format!(".map(|value| value as {})", data_type.get_type),
+                    _                    => String::new()
+                };
+                writeln!(code, "        packet.attribute_by_name(NAME).map(|attr| attr.{}(&Some(SupportedAttributeTypes::{})){})", decode, data_type.supported_type, cast)?;
+            }
+        }
+        writeln!(code, "    }}")?;
+    }
+    writeln!(code, "}}")
+}
+
+fn snake_case(name: &str, prefix: &str) -> String {
+    let ident = words(name).iter().map(|word| word.to_lowercase()).collect::<Vec<String>>().join("_");
+    identifier(ident, prefix, "_")
+}
+
+fn camel_case(name: &str, prefix: &str) -> String {
+    let ident = words(name).iter().map(|word| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect(),
+            None        => String::new()
+        }
+    }).collect::<String>();
+    identifier(ident, prefix, "")
+}
+
+fn words(name: &str) -> Vec<&str> {
+    name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()).collect()
+}
+
+fn identifier(ident: String, prefix: &str, separator: &str) -> String {
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}{}{}", prefix, separator, ident)
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!("framed_ipv6_prefix", snake_case("Framed-IPv6-Prefix", "attr"));
+        assert_eq!("attr_3gpp_imsi",     snake_case("3GPP-IMSI", "attr"));
+        assert_eq!("type_",              snake_case("Type", "attr"));
+        assert_eq!("LoginUser",          camel_case("Login-User", "Value"));
+        assert_eq!("CallbackNasPrompt",  camel_case("Callback-NAS-Prompt", "Value"));
+        assert_eq!("Value10BaseT",       camel_case("10-Base-T", "Value"));
+        assert_eq!("Self_",              camel_case("self", "Value"));
+    }
+
+    #[test]
+    fn test_generate() {
+        let dictionary_str = "ATTRIBUTE User-Name 1 string\n\
+                              ATTRIBUTE Service-Type 6 integer\n\
+                              ATTRIBUTE Class 25 octets\n\
+                              VALUE Service-Type Login-User 1\n\
+                              VALUE Service-Type Framed-User 2\n\
+                              VALUE Service-Type Framed 2\n\
+                              VENDOR Somevendor 10\n\
+                              BEGIN-VENDOR Somevendor\n\
+                              ATTRIBUTE Somevendor-Name 1 string\n\
+                              END-VENDOR Somevendor\n";
+        let dictionary     = Dictionary::from_str(dictionary_str).unwrap();
+        let code           = generate(&dictionary);
+
+        assert!(code.contains("pub mod user_name {"));
+        assert!(code.contains("pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {"));
+        assert!(code.contains("pub enum ServiceType {"));
+        assert!(code.contains("pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: ServiceType) -> Result<(), RadiusError> {"));
+        assert!(code.contains("            2 => Some(ServiceType::FramedUser),"));
+        assert!(!code.contains("Some(ServiceType::Framed)"));
+        assert!(code.contains("pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {"));
+
+        let vendor_module = &code[code.find("pub mod somevendor_name {").unwrap()..];
+        assert!(vendor_module.contains("pub const VENDOR_ID: u32 = 10;"));
+        assert!(vendor_module.contains("pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {"));
+    }
+
+    #[test]
+    fn test_generate_matches_golden_file() {
+        // tests/generated/*.rs files are compiled & exercised by tests/test_codegen.rs
+        let code   = generate_from_file("./dict_examples/integration_dict").unwrap();
+        let golden = std::fs::read_to_string("./tests/generated/integration_dict.rs").unwrap();
+
+        assert_eq!(golden, code);
+
+        let code   = generate_from_file("./dict_examples/test_dictionary_dict").unwrap();
+        let golden = std::fs::read_to_string("./tests/generated/test_dictionary_dict.rs").unwrap();
+
+        assert_eq!(golden, code);
+    }
+}
@@ -3,6 +3,9 @@
 //! If you want to built RADIUS Server, a good starting point is to look inside `examples/*_radius_server.rs`
 //!
 //! If you want to build RADIUS Client, a good starting point is to look inside `examples/*_radius_client.rs`
+//!
+//! If you want to reference dictionary attributes through typed Rust code instead of string names,
+//! have a look at `codegen` module
 
 
 #![deny(
@@ -17,14 +20,15 @@
 
 pub mod client;
 pub use client::{ client::Client, SyncClientTrait };
-#[cfg(all(feature = "async-radius"))]
+#[cfg(feature = "async-radius")]
 pub use client::AsyncClientTrait;
 
 pub mod server;
 pub use server::{ server::Server, SyncServerTrait };
-#[cfg(all(feature = "async-radius"))]
+#[cfg(feature = "async-radius")]
 pub use server::AsyncServerTrait;
 
+pub mod codegen;
 pub mod protocol;
 pub mod tools;
 
@@ -32,4 +36,8 @@ pub mod tools;
 pub mod features {
     #![cfg_attr(feature = "async-radius",      doc = "## Async RADIUS Server/Client Enabled")]
     #![cfg_attr(not(feature = "async-radius"), doc = "## Async RADIUS Server/Client Disabled")]
+    #![cfg_attr(feature = "std-dictionaries",      doc = "## Standard RFC Dictionaries Enabled")]
+    #![cfg_attr(not(feature = "std-dictionaries"), doc = "## Standard RFC Dictionaries Disabled")]
+    #![cfg_attr(feature = "serde-json",            doc = "## Dictionary JSON Export/Import Enabled")]
+    #![cfg_attr(not(feature = "serde-json"),       doc = "## Dictionary JSON Export/Import Disabled")]
 }
@@ -1,14 +1,27 @@
 //! RADIUS Dictionary implementation
 
 
-use std::fs::File;
+use std::collections::{ HashMap, HashSet };
+use std::fmt;
+use std::fs::{ self, File };
 use std::io::{self, BufRead};
+use std::path::{ Path, PathBuf };
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::sync::{ Arc, RwLock };
 
-use super::error::RadiusError;
+use super::error::{ DictionaryError, RadiusError };
 
-#[derive(Debug, PartialEq)]
+use log::warn;
+#[cfg(feature = "std-dictionaries")]
+use super::std_dictionaries::StandardDictionary;
+
+#[cfg(feature = "serde-json")]
+use serde::{ de, Deserialize, Deserializer, Serialize, Serializer };
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Represents a list of supported data types
-/// as defined in RFC 2865
+/// as defined in RFC 2865, RFC 6929 & RFC 8044 (and used by FreeRADIUS dictionaries)
 pub enum SupportedAttributeTypes {
     /// Rust's String
     AsciiString,
@@ -21,51 +34,247 @@ pub enum SupportedAttributeTypes {
     /// Rust's \[u8;16\]
     IPv6Addr,
     /// Rust's \[u8;18\]
-    IPv6Prefix
+    IPv6Prefix,
+    /// Rust's Vec<u8> (raw bytes)
+    Octets,
+    /// Rust's u8
+    Byte,
+    /// Rust's u16
+    Short,
+    /// Rust's i32
+    Signed,
+    /// Rust's u64
+    Integer64,
+    /// Rust's \[u8;6\] (MAC address)
+    Ether,
+    /// Rust's \[u8;8\] (Interface-Id)
+    IfId,
+    /// Rust's \[u8;6\] (reserved byte, prefix length & IPv4 address)
+    IPv4Prefix,
+    /// Rust's \[u8;4\] or \[u8;16\] (either IPv4 or IPv6 address)
+    ComboIP,
+    /// Rust's Vec<u8> (Ascend binary filter)
+    ABinary,
+    /// Rust's Vec<u8> (nested Type-Length-Value attributes)
+    Tlv,
+    /// Rust's Vec<u8> (Vendor-Id followed by vendor attributes)
+    Vsa,
+    /// Rust's Vec<u8> (Extended-Type followed by value)
+    Extended,
+    /// Rust's Vec<u8> (Extended-Type, flags byte & value)
+    LongExtended,
+    /// Rust's Vec<u8> (Vendor-Id, Vendor-Type & value of Extended-Vendor-Specific attribute)
+    Evs,
+    /// Rust's Vec<u8> (values of MEMBERs, one after another, without any headers)
+    Struct
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
+/// Represents a list of supported attribute encryption methods (`encrypt=` flag of ATTRIBUTE)
+pub enum EncryptionType {
+    /// `encrypt=1`, User-Password encryption (RFC 2865 section 5.2)
+    UserPassword,
+    /// `encrypt=2`, salted Tunnel-Password encryption (RFC 2868 section 3.5)
+    TunnelPassword,
+    /// `encrypt=3`, Ascend-Send-Secret encryption
+    AscendSecret
+}
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
+/// Represents flags of an ATTRIBUTE from RADIUS dictionary file
+///
+/// Flags are listed after the data type, separated with comma: `encrypt=2,has_tag`
+pub struct AttributeFlags {
+    encrypt: Option<EncryptionType>,
+    has_tag: bool,
+    concat:  bool,
+    array:   bool,
+    #[cfg_attr(feature = "serde-json", serde(default))]
+    key:     bool
+}
+
+impl AttributeFlags {
+    /// Sets encryption method of the Attribute (`encrypt=` flag)
+    pub fn set_encrypt(mut self, encrypt: Option<EncryptionType>) -> AttributeFlags {
+        self.encrypt = encrypt;
+        self
+    }
+
+    /// Sets `has_tag` flag
+    pub fn set_has_tag(mut self, has_tag: bool) -> AttributeFlags {
+        self.has_tag = has_tag;
+        self
+    }
+
+    /// Sets `concat` flag
+    pub fn set_concat(mut self, concat: bool) -> AttributeFlags {
+        self.concat = concat;
+        self
+    }
+
+    /// Sets `array` flag
+    pub fn set_array(mut self, array: bool) -> AttributeFlags {
+        self.array = array;
+        self
+    }
+
+    /// Sets `key` flag
+    pub fn set_key(mut self, key: bool) -> AttributeFlags {
+        self.key = key;
+        self
+    }
+
+    /// Return encryption method of the Attribute (None, if Attribute is sent as is)
+    pub fn encrypt(&self) -> Option<EncryptionType> {
+        self.encrypt
+    }
+
+    /// Return true, if Attribute value is prefixed with a tag (RFC 2868)
+    pub fn has_tag(&self) -> bool {
+        self.has_tag
+    }
+
+    /// Return true, if Attribute value could be split across several consecutive attributes (ie EAP-Message)
+    pub fn concat(&self) -> bool {
+        self.concat
+    }
+
+    /// Return true, if Attribute value is an array of values of Attribute data type
+    pub fn array(&self) -> bool {
+        self.array
+    }
+
+    /// Return true, if value of struct MEMBER selects STRUCT, that holds the rest of the struct
+    pub fn key(&self) -> bool {
+        self.key
+    }
 }
 
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
 /// Represents an ATTRIBUTE from RADIUS dictionary file
 pub struct DictionaryAttribute {
     /*
-     * |--------|   name  | code | code type |
-     * ATTRIBUTE User-Name   1      string
+     * |--------|     name    | code | code type | flags  |
+     * ATTRIBUTE User-Password   2      string    encrypt=1
      */
     name:        String,
     vendor_name: String,
-    code:        String,
-    code_type:   Option<SupportedAttributeTypes>
+    vendor_id:   Option<u32>,
+    parent_oid:  Vec<u32>,
+    code:        u32,
+    code_type:   Option<SupportedAttributeTypes>,
+    flags:       AttributeFlags
 }
 
 impl DictionaryAttribute {
+    /// Creates standard (non-vendor, top-level) DictionaryAttribute without flags
+    pub fn new(name: &str, code: u32, code_type: SupportedAttributeTypes) -> DictionaryAttribute {
+        DictionaryAttribute {
+            name:        name.to_string(),
+            vendor_name: String::new(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code,
+            code_type:   Some(code_type),
+            flags:       AttributeFlags::default()
+        }
+    }
+
+    /// Moves DictionaryAttribute into given vendor's namespace
+    pub fn set_vendor(mut self, vendor: &DictionaryVendor) -> DictionaryAttribute {
+        self.vendor_name = vendor.name.to_string();
+        self.vendor_id   = Some(vendor.id);
+        self
+    }
+
+    /// Makes DictionaryAttribute a child of given TLV attribute (vendor is taken from the parent)
+    pub fn set_parent(mut self, parent: &DictionaryAttribute) -> DictionaryAttribute {
+        self.vendor_name = parent.vendor_name.to_string();
+        self.vendor_id   = parent.vendor_id;
+        self.parent_oid  = parent.oid();
+        self
+    }
+
+    /// Sets DictionaryAttribute flags
+    pub fn set_flags(mut self, flags: AttributeFlags) -> DictionaryAttribute {
+        self.flags = flags;
+        self
+    }
+
     /// Return name of the Attribute
     pub fn name(&self) -> &str {
         &self.name
     }
 
     /// Return code of the Attribute
-    pub fn code(&self) -> &str {
-        &self.code
+    ///
+    /// Standard attributes always fit into u8, vendor attributes could be wider
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Return codes of the Attribute's parents (empty for top-level attributes)
+    ///
+    /// Attributes nested into TLVs (`BEGIN-TLV` blocks or dotted codes, ie `241.1.2`) have their
+    /// parent's full code path here
+    pub fn parent_oid(&self) -> &[u32] {
+        &self.parent_oid
+    }
+
+    /// Return full code path of the Attribute, that is parents' codes followed by Attribute's code
+    pub fn oid(&self) -> Vec<u32> {
+        [ self.parent_oid.as_slice(), &[self.code] ].concat()
     }
 
     /// Return code_type of the Attribute
     pub fn code_type(&self) -> &Option<SupportedAttributeTypes> {
         &self.code_type
     }
+
+    /// Return name of the vendor, Attribute belongs to (empty for standard attributes)
+    pub fn vendor_name(&self) -> &str {
+        &self.vendor_name
+    }
+
+    /// Return id of the vendor, Attribute belongs to (None for standard attributes)
+    pub fn vendor_id(&self) -> Option<u32> {
+        self.vendor_id
+    }
+
+    /// Return flags of the Attribute
+    pub fn flags(&self) -> &AttributeFlags {
+        &self.flags
+    }
 }
 
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
 /// Represents a VALUE from RADIUS dictionary file
 pub struct DictionaryValue {
     attribute_name: String,
     value_name:     String,
     vendor_name:    String,
-    value:          String
+    value:          u64
 }
 
 impl DictionaryValue {
+    /// Creates DictionaryValue for given attribute (VALUE shares vendor with its attribute)
+    pub fn new(attribute: &DictionaryAttribute, value_name: &str, value: u64) -> DictionaryValue {
+        DictionaryValue {
+            attribute_name: attribute.name.to_string(),
+            value_name:     value_name.to_string(),
+            vendor_name:    attribute.vendor_name.to_string(),
+            value
+        }
+    }
+
     /// Return name of the Value
     pub fn name(&self) -> &str {
         &self.value_name
@@ -77,63 +286,561 @@ impl DictionaryValue {
     }
 
     /// Return value of the Value
-    pub fn value(&self) -> &str {
-        &self.value
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Return name of the vendor, Value belongs to (empty for standard attributes)
+    pub fn vendor_name(&self) -> &str {
+        &self.vendor_name
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
+/// Represents an ALIAS from RADIUS dictionary file, that is an alternative name of ATTRIBUTE
+pub struct DictionaryAlias {
+    name:           String,
+    attribute_name: String
+}
+
+impl DictionaryAlias {
+    /// Creates DictionaryAlias with given name for given attribute
+    pub fn new(name: &str, attribute: &DictionaryAttribute) -> DictionaryAlias {
+        DictionaryAlias {
+            name:           name.to_string(),
+            attribute_name: attribute.name.to_string()
+        }
+    }
+
+    /// Return name of the Alias
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return name of the attribute, Alias refers to
+    pub fn attribute_name(&self) -> &str {
+        &self.attribute_name
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
+/// Represents layout of vendor attributes inside Vendor-Specific attribute (`format=t,l[,c]` of VENDOR)
+///
+/// Most vendors follow RFC 2865 layout (1 byte type & 1 byte length), which is the default
+pub struct VendorFormat {
+    type_width:   u8,
+    length_width: u8,
+    continuation: bool
+}
+
+impl VendorFormat {
+    /// Creates VendorFormat with given widths
+    ///
+    /// Returns None, if type width is not 1, 2 or 4, length width is not 0, 1 or 2, or
+    /// continuation is requested for format other than 1,1
+    pub fn new(type_width: u8, length_width: u8, continuation: bool) -> Option<VendorFormat> {
+        let valid_widths = matches!(type_width, 1 | 2 | 4) && length_width <= 2;
+        if !valid_widths || (continuation && (type_width, length_width) != (1, 1)) {
+            return None
+        }
+
+        Some(VendorFormat { type_width, length_width, continuation })
+    }
+
+    /// Return width of vendor attribute type in bytes (1, 2 or 4)
+    pub fn type_width(&self) -> u8 {
+        self.type_width
+    }
+
+    /// Return width of vendor attribute length in bytes (0, 1 or 2)
+    ///
+    /// 0 means there is no length field, so vendor attribute takes the rest of Vendor-Specific attribute
+    pub fn length_width(&self) -> u8 {
+        self.length_width
+    }
+
+    /// Return true, if vendor attribute length is followed by continuation byte (WiMAX)
+    pub fn continuation(&self) -> bool {
+        self.continuation
+    }
+}
+
+impl Default for VendorFormat {
+    fn default() -> VendorFormat {
+        VendorFormat {
+            type_width:   1,
+            length_width: 1,
+            continuation: false
+        }
     }
 }
 
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-json", derive(Serialize, Deserialize))]
 /// Represents a VENDOR from RADIUS dictionary file
 pub struct DictionaryVendor {
-    name: String,
-    id:   String  // ideally should be u16
+    /*
+     * |-----|  name | id  | format       |
+     * VENDOR  USR     429   format=4,0
+     */
+    name:   String,
+    id:     u32,
+    format: VendorFormat
+}
+
+impl DictionaryVendor {
+    /// Creates DictionaryVendor with default format (1 byte type & 1 byte length)
+    pub fn new(name: &str, id: u32) -> DictionaryVendor {
+        DictionaryVendor {
+            name:   name.to_string(),
+            id,
+            format: VendorFormat::default()
+        }
+    }
+
+    /// Sets format of the Vendor's attributes
+    pub fn set_format(mut self, format: VendorFormat) -> DictionaryVendor {
+        self.format = format;
+        self
+    }
+
+    /// Return name of the Vendor
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return id (IANA Private Enterprise Number) of the Vendor
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Return format of the Vendor's attributes
+    pub fn format(&self) -> &VendorFormat {
+        &self.format
+    }
+}
+
+
+const COMMENT_PREFIX: char = '#';
+
+#[derive(Debug, Clone)]
+/// Location (file & line), where dictionary entry was defined
+struct EntryLocation {
+    file: String,
+    line: usize
+}
+
+impl Default for EntryLocation {
+    fn default() -> EntryLocation {
+        // Entries, that were added in code, are not defined in any file
+        EntryLocation { file: String::from("<unknown>"), line: 0 }
+    }
 }
 
+impl fmt::Display for EntryLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
 
-const COMMENT_PREFIX: &str = "#";
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Defines how [Dictionary::merge] treats entries, that clash with already defined ones
+///
+/// ATTRIBUTEs clash, if they have the same name or the same code within the same vendor (or TLV
+/// parent), VALUEs - if they have the same name for the same attribute, VENDORs - if they have the
+/// same name or id. Identical entries do not clash and are merged silently
+pub enum ConflictPolicy {
+    /// Merge fails on the first clash and Dictionary stays unchanged
+    Error,
+    /// Merged entry replaces clashing ones
+    Override,
+    /// Merged entry is dropped in favour of already defined one
+    KeepFirst
+}
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Clone, Default)]
 /// Represents RADIUS dictionary
+///
+/// Attributes, values, vendors and aliases are kept in the order they were defined in, and are
+/// indexed for constant time lookups. If the same name or code is defined more than once, the
+/// first definition wins in lookups (see [Dictionary::validate] to find such definitions)
 pub struct Dictionary {
-    attributes: Vec<DictionaryAttribute>,
-    values:     Vec<DictionaryValue>,
-    vendors:    Vec<DictionaryVendor>
+    attributes:          Vec<DictionaryAttribute>,
+    values:              Vec<DictionaryValue>,
+    vendors:             Vec<DictionaryVendor>,
+    aliases:             Vec<DictionaryAlias>,
+    attribute_locations: Vec<EntryLocation>,
+    value_locations:     Vec<EntryLocation>,
+    vendor_locations:    Vec<EntryLocation>,
+    alias_locations:     Vec<EntryLocation>,
+    attributes_by_name:  HashMap<String, usize>,
+    attributes_by_code:  HashMap<(Option<u32>, u32), usize>,
+    attributes_by_oid:   HashMap<(Option<u32>, Vec<u32>), usize>,
+    values_by_name:      HashMap<(String, String), usize>,
+    values_by_number:    HashMap<(String, u64), usize>,
+    vendors_by_name:     HashMap<String, usize>,
+    vendors_by_id:       HashMap<u32, usize>,
+    aliases_by_name:     HashMap<String, usize>,
+    // Lowercase names, that are used to resolve names typed by users
+    attributes_by_folded_name: HashMap<String, Vec<usize>>,
+    aliases_by_folded_name:    HashMap<String, Vec<usize>>
+}
+
+impl PartialEq for Dictionary {
+    fn eq(&self, other: &Dictionary) -> bool {
+        // Indexes are built from entries, and the same entries could come from different files
+        self.attributes == other.attributes && self.values == other.values && self.vendors == other.vendors && self.aliases == other.aliases
+    }
 }
 
-#[allow(unused)]
 impl Dictionary {
     /// Creates Dictionary from a string
+    ///
+    /// Handy when dictionary is embedded into the binary with `include_str!`
+    ///
+    /// Unknown keywords & data types are skipped and logged as warnings, use [DictionaryParser]
+    /// to collect them or to reject them with [ParseMode::Strict]
     pub fn from_str(dictionary_str: &str) -> Result<Dictionary, RadiusError> {
-        todo!()
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_str(dictionary_str)?;
+
+        Ok(parser.into_logged_dictionary())
     }
 
     /// Creates Dictionary from a RADIUS dictionary file
+    ///
+    /// Unknown keywords & data types are skipped and logged as warnings, use [DictionaryParser]
+    /// to collect them or to reject them with [ParseMode::Strict]
     pub fn from_file(file_path: &str) -> Result<Dictionary, RadiusError> {
-        let mut attributes:  Vec<DictionaryAttribute> = Vec::new();
-        let mut values:      Vec<DictionaryValue>     = Vec::new();
-        let mut vendors:     Vec<DictionaryVendor>    = Vec::new();
-        let mut vendor_name: String                   = String::new();
-        let comment_prefix:  String                   = String::from("#");
-
-        let reader = io::BufReader::new(File::open(file_path).map_err(|error| RadiusError::MalformedDictionaryError { error })?);
-        let lines  = reader.lines()
-            .filter_map(Result::ok)
-            .filter(|line| !line.is_empty())
-            .filter(|line| !line.contains(&COMMENT_PREFIX));
-
-        for line in lines {
-            let parsed_line: Vec<&str> = line.split(" ").filter(|&item| !item.is_empty()).collect();
-            match parsed_line[0] {
-                "ATTRIBUTE"    => parse_attribute(parsed_line, &vendor_name, &mut attributes),
-                "VALUE"        => parse_value(parsed_line, &vendor_name, &mut values),
-                "VENDOR"       => parse_vendor(parsed_line, &mut vendors),
-                "BEGIN-VENDOR" => { vendor_name.insert_str(0, parsed_line[1]) },
-                "END-VENDOR"   => { vendor_name.clear() },
-                _              => continue
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_file(file_path)?;
+
+        Ok(parser.into_logged_dictionary())
+    }
+
+    /// Creates Dictionary from every dictionary file found in the directory
+    ///
+    /// See [DictionaryParser::parse_dir] for details on which files are picked up
+    pub fn from_dir(dir_path: &str) -> Result<Dictionary, RadiusError> {
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_dir(dir_path)?;
+
+        Ok(parser.into_logged_dictionary())
+    }
+
+    /// Creates Dictionary from any buffered reader, that yields RADIUS dictionary lines
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Dictionary, RadiusError> {
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_reader(reader)?;
+
+        Ok(parser.into_logged_dictionary())
+    }
+
+    #[cfg(feature = "std-dictionaries")]
+    /// Creates Dictionary from every embedded standard RFC dictionary
+    ///
+    /// Requires **std-dictionaries** feature
+    pub fn rfc_standard() -> Dictionary {
+        Dictionary::from_rfcs(StandardDictionary::all())
+    }
+
+    #[cfg(feature = "std-dictionaries")]
+    /// Creates Dictionary only from given embedded standard RFC dictionaries
+    ///
+    /// Requires **std-dictionaries** feature
+    pub fn from_rfcs(rfcs: &[StandardDictionary]) -> Dictionary {
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        for rfc in rfcs {
+            parser.parse_standard(*rfc);
+        }
+
+        parser.into_dictionary()
+    }
+
+    #[cfg(feature = "serde-json")]
+    /// Converts Dictionary into JSON (vendors, attributes & values, as they are defined)
+    ///
+    /// Requires **serde-json** feature
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Dictionary entries are always representable in JSON")
+    }
+
+    #[cfg(feature = "serde-json")]
+    /// Creates Dictionary from JSON, that was produced by [Dictionary::to_json]
+    ///
+    /// Requires **serde-json** feature
+    pub fn from_json(json: &str) -> Result<Dictionary, RadiusError> {
+        serde_json::from_str(json).map_err(|error| RadiusError::DictionaryParseError { error: DictionaryError::new("<json>", error.line(), "", &error.to_string()) })
+    }
+
+    /// Merges other Dictionary on top of this one (ie site-local dictionary on top of standard
+    /// one) and returns clashes, that were resolved with given [ConflictPolicy]
+    ///
+    /// Each clash is reported with location of the merged entry, and location of the definition it
+    /// clashed with. With [ConflictPolicy::Error] the first clash is returned as an error instead.
+    ///
+    /// Vendor attributes are bound to vendors by vendor name, so site-local dictionary could add
+    /// attributes to vendor, that is declared only in this Dictionary
+    pub fn merge(&mut self, other: Dictionary, policy: ConflictPolicy) -> Result<Vec<DictionaryError>, RadiusError> {
+        let mut vendors    = with_locations(self.vendors.to_vec(), &self.vendor_locations);
+        let mut attributes = with_locations(self.attributes.to_vec(), &self.attribute_locations);
+        let mut values     = with_locations(self.values.to_vec(), &self.value_locations);
+        let mut aliases    = with_locations(self.aliases.to_vec(), &self.alias_locations);
+        let mut report     = Vec::new();
+
+        for vendor in with_locations(other.vendors, &other.vendor_locations) {
+            let (name, id) = (vendor.0.name.to_string(), vendor.0.id);
+            merge_entry(&mut vendors, vendor, |existing| existing.name == name || existing.id == id, &name, policy, &mut report)?;
+        }
+        for attr in with_locations(other.attributes, &other.attribute_locations) {
+            let (name, vendor_name, oid) = (attr.0.name.to_string(), attr.0.vendor_name.to_string(), attr.0.oid());
+            merge_entry(&mut attributes, attr, |existing| existing.name == name || (existing.vendor_name == vendor_name && existing.oid() == oid), &name, policy, &mut report)?;
+        }
+        for value in with_locations(other.values, &other.value_locations) {
+            let (attribute_name, value_name) = (value.0.attribute_name.to_string(), value.0.value_name.to_string());
+            merge_entry(&mut values, value, |existing| existing.attribute_name == attribute_name && existing.value_name == value_name, &value_name, policy, &mut report)?;
+        }
+        for alias in with_locations(other.aliases, &other.alias_locations) {
+            let name = alias.0.name.to_string();
+            merge_entry(&mut aliases, alias, |existing| existing.name == name, &name, policy, &mut report)?;
+        }
+
+        for (attr, _) in attributes.iter_mut().filter(|(attr, _)| !attr.vendor_name.is_empty()) {
+            attr.vendor_id = vendors.iter().find(|(vendor, _)| vendor.name == attr.vendor_name).map(|(vendor, _)| vendor.id);
+        }
+
+        let (vendors, vendor_locations)       = vendors.into_iter().unzip();
+        let (attributes, attribute_locations) = attributes.into_iter().unzip();
+        let (values, value_locations)         = values.into_iter().unzip();
+        let (aliases, alias_locations)        = aliases.into_iter().unzip();
+
+        *self = Dictionary::with_entries(attributes, values, vendors, aliases);
+        self.attribute_locations = attribute_locations;
+        self.value_locations     = value_locations;
+        self.vendor_locations    = vendor_locations;
+        self.alias_locations     = alias_locations;
+        Ok(report)
+    }
+
+    /// Parses RADIUS dictionary file and merges it on top of this Dictionary
+    ///
+    /// See [Dictionary::merge] for details
+    pub fn merge_file(&mut self, file_path: &str, policy: ConflictPolicy) -> Result<Vec<DictionaryError>, RadiusError> {
+        let other = Dictionary::from_file(file_path)?;
+        self.merge(other, policy)
+    }
+
+    /// Adds VENDOR to Dictionary
+    ///
+    /// Handy, when Dictionary is built in code rather than parsed (see [DictionaryVendor::new])
+    pub fn add_vendor(&mut self, vendor: DictionaryVendor) {
+        self.vendors.push(vendor);
+        self.index_vendor(self.vendors.len() - 1);
+    }
+
+    /// Adds ATTRIBUTE to Dictionary
+    ///
+    /// Handy, when Dictionary is built in code rather than parsed (see [DictionaryAttribute::new])
+    pub fn add_attribute(&mut self, attribute: DictionaryAttribute) {
+        self.attributes.push(attribute);
+        self.index_attribute(self.attributes.len() - 1);
+    }
+
+    /// Adds VALUE to Dictionary
+    ///
+    /// Handy, when Dictionary is built in code rather than parsed (see [DictionaryValue::new])
+    pub fn add_value(&mut self, value: DictionaryValue) {
+        self.values.push(value);
+        self.index_value(self.values.len() - 1);
+    }
+
+    /// Adds ALIAS to Dictionary
+    ///
+    /// Handy, when Dictionary is built in code rather than parsed (see [DictionaryAlias::new])
+    pub fn add_alias(&mut self, alias: DictionaryAlias) {
+        self.aliases.push(alias);
+        self.index_alias(self.aliases.len() - 1);
+    }
+
+    fn with_entries(attributes: Vec<DictionaryAttribute>, values: Vec<DictionaryValue>, vendors: Vec<DictionaryVendor>, aliases: Vec<DictionaryAlias>) -> Dictionary {
+        let mut dictionary = Dictionary { attributes, values, vendors, aliases, ..Dictionary::default() };
+
+        for index in 0..dictionary.attributes.len() {
+            dictionary.index_attribute(index);
+        }
+        for index in 0..dictionary.values.len() {
+            dictionary.index_value(index);
+        }
+        for index in 0..dictionary.vendors.len() {
+            dictionary.index_vendor(index);
+        }
+        for index in 0..dictionary.aliases.len() {
+            dictionary.index_alias(index);
+        }
+
+        dictionary
+    }
+
+    fn index_attribute(&mut self, index: usize) {
+        let attr = &self.attributes[index];
+
+        self.attributes_by_name.entry(attr.name.to_string()).or_insert(index);
+        self.attributes_by_folded_name.entry(attr.name.to_ascii_lowercase()).or_default().push(index);
+        // Attributes of undeclared vendors cannot be addressed by code
+        if attr.vendor_name.is_empty() || attr.vendor_id.is_some() {
+            if attr.parent_oid.is_empty() {
+                self.attributes_by_code.entry((attr.vendor_id, attr.code)).or_insert(index);
             }
-        };
-        Ok(Dictionary { attributes, values, vendors })
+            self.attributes_by_oid.entry((attr.vendor_id, attr.oid())).or_insert(index);
+        }
+    }
+
+    fn index_value(&mut self, index: usize) {
+        let value = &self.values[index];
+
+        self.values_by_name.entry((value.attribute_name.to_string(), value.value_name.to_string())).or_insert(index);
+        self.values_by_number.entry((value.attribute_name.to_string(), value.value)).or_insert(index);
+    }
+
+    fn index_vendor(&mut self, index: usize) {
+        let vendor = &self.vendors[index];
+
+        self.vendors_by_name.entry(vendor.name.to_string()).or_insert(index);
+        self.vendors_by_id.entry(vendor.id).or_insert(index);
+    }
+
+    fn index_alias(&mut self, index: usize) {
+        self.aliases_by_name.entry(self.aliases[index].name.to_string()).or_insert(index);
+        self.aliases_by_folded_name.entry(self.aliases[index].name.to_ascii_lowercase()).or_default().push(index);
+    }
+
+    /// Checks dictionary for consistency problems and returns them, each with location of the
+    /// offending definition
+    ///
+    /// Following problems are reported:
+    /// * the same attribute name, VALUE name or VENDOR name/id is defined more than once
+    /// * the same attribute code is used more than once within the same vendor (or TLV parent)
+    /// * VALUE refers to attribute, that is not defined or is not of integer data type, or does
+    ///   not fit into attribute's data type
+    /// * vendor attribute is defined for vendor, that is not declared with VENDOR, or its code
+    ///   does not fit into vendor's type width
+    /// * attribute flags are not allowed for attribute's data type
+    /// * ALIAS refers to attribute, that is not defined, or its name is already used by attribute
+    ///
+    /// Empty list means dictionary is consistent
+    pub fn validate(&self) -> Vec<DictionaryError> {
+        let mut diagnostics = Vec::new();
+
+        let mut attribute_names = HashMap::new();
+        let mut attribute_codes = HashMap::new();
+        for (index, attr) in self.attributes.iter().enumerate() {
+            let oid = attr.oid();
+
+            let first = *attribute_names.entry(attr.name.as_str()).or_insert(index);
+            if first != index {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, &format!("attribute name is already defined at {}", location(&self.attribute_locations, first))));
+            }
+            let first = *attribute_codes.entry((attr.vendor_name.as_str(), oid.to_vec())).or_insert(index);
+            if first != index {
+                let oid_str = oid.iter().map(|code| code.to_string()).collect::<Vec<String>>().join(".");
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &oid_str, &format!("attribute code is already used by {} at {}", self.attributes[first].name, location(&self.attribute_locations, first))));
+            }
+
+            if !attr.vendor_name.is_empty() {
+                match attr.vendor_id.and_then(|vendor_id| self.vendor_by_id(vendor_id)) {
+                    Some(vendor) => {
+                        let max_code = match vendor.format.type_width {
+                            1 => u32::from(u8::MAX),
+                            2 => u32::from(u16::MAX),
+                            _ => u32::MAX
+                        };
+                        if attr.parent_oid.is_empty() && attr.code > max_code {
+                            diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.code.to_string(), &format!("attribute code does not fit vendor's type width of {} byte(s)", vendor.format.type_width)));
+                        }
+                    },
+                    None         => diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.vendor_name, "vendor is not defined with VENDOR keyword"))
+                }
+            }
+
+            let code_type = attr.code_type.as_ref();
+            if attr.flags.encrypt.is_some() && !matches!(code_type, Some(SupportedAttributeTypes::AsciiString) | Some(SupportedAttributeTypes::Octets)) {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, "encrypt flag is only allowed for string or octets data types"));
+            }
+            if attr.flags.has_tag && !matches!(code_type, Some(SupportedAttributeTypes::AsciiString) | Some(SupportedAttributeTypes::Octets) | Some(SupportedAttributeTypes::Integer)) {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, "has_tag flag is only allowed for string, octets or integer data types"));
+            }
+            if attr.flags.concat && !matches!(code_type, Some(SupportedAttributeTypes::Octets)) {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, "concat flag is only allowed for octets data type"));
+            }
+            if attr.flags.array && !matches!(code_type, Some(SupportedAttributeTypes::Byte) | Some(SupportedAttributeTypes::Short) | Some(SupportedAttributeTypes::Integer) | Some(SupportedAttributeTypes::Signed) | Some(SupportedAttributeTypes::Date) | Some(SupportedAttributeTypes::Integer64) | Some(SupportedAttributeTypes::IPv4Addr) | Some(SupportedAttributeTypes::IPv6Addr) | Some(SupportedAttributeTypes::IPv4Prefix) | Some(SupportedAttributeTypes::Ether) | Some(SupportedAttributeTypes::IfId)) {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, "array flag is only allowed for fixed size data types"));
+            }
+            if attr.flags.key && !matches!(code_type, Some(SupportedAttributeTypes::Byte) | Some(SupportedAttributeTypes::Short) | Some(SupportedAttributeTypes::Integer)) {
+                diagnostics.push(diagnostic(&self.attribute_locations, index, &attr.name, "key flag is only allowed for byte, short or integer data types"));
+            }
+        }
+
+        let mut alias_names = HashMap::new();
+        for (index, alias) in self.aliases.iter().enumerate() {
+            let first = *alias_names.entry(alias.name.as_str()).or_insert(index);
+            if first != index {
+                diagnostics.push(diagnostic(&self.alias_locations, index, &alias.name, &format!("alias name is already defined at {}", location(&self.alias_locations, first))));
+            }
+            if let Some(&attribute_index) = self.attributes_by_name.get(&alias.name) {
+                diagnostics.push(diagnostic(&self.alias_locations, index, &alias.name, &format!("alias name is already used by attribute at {}", location(&self.attribute_locations, attribute_index))));
+            }
+            if !self.attributes_by_name.contains_key(&alias.attribute_name) {
+                diagnostics.push(diagnostic(&self.alias_locations, index, &alias.attribute_name, "attribute is not defined"));
+            }
+        }
+
+        let mut value_names = HashMap::new();
+        for (index, value) in self.values.iter().enumerate() {
+            let first = *value_names.entry((value.attribute_name.as_str(), value.value_name.as_str())).or_insert(index);
+            if first != index {
+                diagnostics.push(diagnostic(&self.value_locations, index, &value.value_name, &format!("value name is already defined at {}", location(&self.value_locations, first))));
+            }
+
+            let max_value = match self.attribute_by_name(&value.attribute_name).map(|attr| attr.code_type) {
+                None                                               => {
+                    diagnostics.push(diagnostic(&self.value_locations, index, &value.attribute_name, "attribute is not defined"));
+                    continue;
+                },
+                Some(Some(SupportedAttributeTypes::Byte))          => u64::from(u8::MAX),
+                Some(Some(SupportedAttributeTypes::Short))         => u64::from(u16::MAX),
+                Some(Some(SupportedAttributeTypes::Integer))      |
+                Some(Some(SupportedAttributeTypes::Date))          => u64::from(u32::MAX),
+                Some(Some(SupportedAttributeTypes::Signed))        => i32::MAX as u64,
+                Some(Some(SupportedAttributeTypes::Integer64))     => u64::MAX,
+                Some(_)                                            => {
+                    diagnostics.push(diagnostic(&self.value_locations, index, &value.attribute_name, "attribute is not of integer data type"));
+                    continue;
+                }
+            };
+            if value.value > max_value {
+                diagnostics.push(diagnostic(&self.value_locations, index, &value.value.to_string(), &format!("value does not fit attribute's data type (max: {})", max_value)));
+            }
+        }
+
+        let mut vendor_names = HashMap::new();
+        let mut vendor_ids   = HashMap::new();
+        for (index, vendor) in self.vendors.iter().enumerate() {
+            let first = *vendor_names.entry(vendor.name.as_str()).or_insert(index);
+            if first != index {
+                diagnostics.push(diagnostic(&self.vendor_locations, index, &vendor.name, &format!("vendor name is already defined at {}", location(&self.vendor_locations, first))));
+            }
+            let first = *vendor_ids.entry(vendor.id).or_insert(index);
+            if first != index {
+                diagnostics.push(diagnostic(&self.vendor_locations, index, &vendor.id.to_string(), &format!("vendor id is already used by {} at {}", self.vendors[first].name, location(&self.vendor_locations, first))));
+            }
+        }
+
+        diagnostics
     }
 
     /// Returns parsed DictionaryAttributes
@@ -150,121 +857,1938 @@ impl Dictionary {
     pub fn vendors(&self) -> &[DictionaryVendor] {
         &self.vendors
     }
-}
 
-fn assign_attribute_type(code_type: &str) -> Option<SupportedAttributeTypes> {
-    match code_type {
-        "string"     => Some(SupportedAttributeTypes::AsciiString),
-        "integer"    => Some(SupportedAttributeTypes::Integer),
-        "date"       => Some(SupportedAttributeTypes::Date),
-        "ipaddr"     => Some(SupportedAttributeTypes::IPv4Addr),
-        "ipv6addr"   => Some(SupportedAttributeTypes::IPv6Addr),
-        "ipv6prefix" => Some(SupportedAttributeTypes::IPv6Prefix),
-        _            => None
+    /// Returns parsed DictionaryAliases
+    pub fn aliases(&self) -> &[DictionaryAlias] {
+        &self.aliases
     }
-}
 
-fn parse_attribute(parsed_line: Vec<&str>, vendor_name: &str, attributes: &mut Vec<DictionaryAttribute>) {
-    attributes.push(DictionaryAttribute {
-        name:        parsed_line[1].to_string(),
-        vendor_name: vendor_name.to_string(),
-        code:        parsed_line[2].to_string(),
-        code_type:   assign_attribute_type(parsed_line[3])
-    });
-}
+    /// Returns ATTRIBUTE with given name or ALIAS name
+    pub fn attribute_by_name(&self, attribute_name: &str) -> Option<&DictionaryAttribute> {
+        let index = match self.attributes_by_name.get(attribute_name) {
+            Some(index) => Some(index),
+            None        => self.aliases_by_name.get(attribute_name).and_then(|&index| self.attributes_by_name.get(&self.aliases[index].attribute_name))
+        };
+        index.map(|&index| &self.attributes[index])
+    }
 
-fn parse_value(parsed_line: Vec<&str>, vendor_name: &str, values: &mut Vec<DictionaryValue>) {
-    values.push(DictionaryValue {
-        attribute_name: parsed_line[1].to_string(),
-        value_name:     parsed_line[2].to_string(),
-        vendor_name:    vendor_name.to_string(),
-        value:          parsed_line[3].to_string()
-    })
-}
+    /// Resolves ATTRIBUTE by name, the way users type it
+    ///
+    /// Following forms are tried in order:
+    /// * exact attribute or ALIAS name, ie `User-Name`
+    /// * vendor-qualified name, ie `Cisco.AVPair` or `Cisco.Cisco-AVPair`, optionally prefixed
+    ///   with `Vendor-Specific.`. Vendor and attribute could also be given by id & code, ie
+    ///   `Vendor-Specific.9.1`. Names are matched case-insensitively
+    /// * case-insensitive attribute or ALIAS name, ie `user-name`
+    ///
+    /// Returns [AmbiguousAttributeError](RadiusError::AmbiguousAttributeError), if name matches
+    /// several attributes (ie the same name is used by different vendors), and
+    /// [MalformedAttributeError](RadiusError::MalformedAttributeError), if it matches none
+    pub fn resolve_attribute(&self, attribute_name: &str) -> Result<&DictionaryAttribute, RadiusError> {
+        let folded_name = attribute_name.to_ascii_lowercase();
 
-fn parse_vendor(parsed_line: Vec<&str>, vendors: &mut Vec<DictionaryVendor>) {
-    vendors.push(DictionaryVendor {
-        name: parsed_line[1].to_string(),
-        id:   parsed_line[2].to_string(),
-    })
-}
+        let exact_matches = self.attributes_by_folded_name(&folded_name, |name| name == attribute_name);
+        if !exact_matches.is_empty() {
+            return self.unique_attribute(attribute_name, exact_matches)
+        }
+        if let Some(attr) = self.qualified_attribute(attribute_name)? {
+            return Ok(attr)
+        }
+        let folded_matches = self.attributes_by_folded_name(&folded_name, |_| true);
+        if !folded_matches.is_empty() {
+            return self.unique_attribute(attribute_name, folded_matches)
+        }
 
+        Err( RadiusError::MalformedAttributeError { error: format!("No attribute with name: {} found in dictionary", attribute_name) } )
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns indexes of attributes, that have (or are aliased by) given folded name and whose
+    /// name matches
+    fn attributes_by_folded_name(&self, folded_name: &str, matches: impl Fn(&str) -> bool) -> Vec<usize> {
+        let attributes = self.attributes_by_folded_name.get(folded_name).into_iter().flatten()
+            .filter(|&&index| matches(&self.attributes[index].name));
+        let aliased    = self.aliases_by_folded_name.get(folded_name).into_iter().flatten()
+            .filter(|&&index| matches(&self.aliases[index].name))
+            .filter_map(|&index| self.attributes_by_name.get(&self.aliases[index].attribute_name));
 
-    #[test]
-    fn test_from_file() {
-        let dictionary_path = "./dict_examples/test_dictionary_dict";
+        let mut indexes: Vec<usize> = Vec::new();
+        for &index in attributes.chain(aliased) {
+            if !indexes.contains(&index) {
+                indexes.push(index);
+            }
+        }
+        indexes
+    }
 
-        let dict = Dictionary::from_file(dictionary_path).unwrap();
+    /// Resolves names like `Cisco.AVPair`, `Vendor-Specific.Cisco.AVPair` or `Vendor-Specific.9.1`
+    fn qualified_attribute(&self, attribute_name: &str) -> Result<Option<&DictionaryAttribute>, RadiusError> {
+        let mut path: Vec<&str> = attribute_name.split('.').collect();
+        if path.len() == 3 && path[0].eq_ignore_ascii_case("Vendor-Specific") {
+            path.remove(0);
+        }
+        if path.len() != 2 {
+            return Ok(None)
+        }
 
-        let mut attributes: Vec<DictionaryAttribute> = Vec::new();
-        attributes.push(DictionaryAttribute {
-            name:        "User-Name".to_string(),
-            vendor_name: "".to_string(),
-            code:        "1".to_string(),
-            code_type:   Some(SupportedAttributeTypes::AsciiString) 
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "NAS-IP-Address".to_string(),
-            vendor_name: "".to_string(),
-            code:        "4".to_string(),
-            code_type:   Some(SupportedAttributeTypes::IPv4Addr)
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "NAS-Port-Id".to_string(),
-            vendor_name: "".to_string(),
-            code:        "5".to_string(),
-            code_type:   Some(SupportedAttributeTypes::Integer)
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "Framed-Protocol".to_string(),
-            vendor_name: "".to_string(),
-            code:        "7".to_string(),
-            code_type:   Some(SupportedAttributeTypes::Integer)
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "Somevendor-Name".to_string(),
-            vendor_name: "Somevendor".to_string(),
-            code:        "1".to_string(),
-            code_type:   Some(SupportedAttributeTypes::AsciiString)
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "Somevendor-Number".to_string(),
-            vendor_name: "Somevendor".to_string(),
-            code:        "2".to_string(),
-            code_type:   Some(SupportedAttributeTypes::Integer)
-        });
-        attributes.push(DictionaryAttribute {
-            name:        "Test-IP".to_string(),
-            vendor_name: "".to_string(),
-            code:        "25".to_string(),
-            code_type:   Some(SupportedAttributeTypes::IPv4Addr)
-        });
-        
-        let mut values: Vec<DictionaryValue> = Vec::new();
-        values.push(DictionaryValue {
-            attribute_name: "Framed-Protocol".to_string(),
-            value_name:     "PPP".to_string(),
-            vendor_name:    "".to_string(),
-            value:          "1".to_string()
-        });
-        values.push(DictionaryValue {
-            attribute_name: "Somevendor-Number".to_string(),
-            value_name:     "Two".to_string(),
-            vendor_name:    "Somevendor".to_string(),
-            value:          "2".to_string()
-        });
+        let vendor = match parse_number(path[0]) {
+            Some(vendor_id) => u32::try_from(vendor_id).ok().and_then(|vendor_id| self.vendor_by_id(vendor_id)),
+            None            => self.vendors.iter().find(|vendor| vendor.name.eq_ignore_ascii_case(path[0]))
+        };
+        let vendor = match vendor {
+            Some(vendor) => vendor,
+            None         => return Ok(None)
+        };
 
-        let mut vendors: Vec<DictionaryVendor> = Vec::new();
-        vendors.push(DictionaryVendor {
-            name: "Somevendor".to_string(),
-            id:   "10".to_string(),
-        });
+        if let Some(code) = parse_number(path[1]) {
+            return Ok(u32::try_from(code).ok().and_then(|code| self.vendor_attribute_by_code(vendor.id, code)))
+        }
 
-        let expected_dict = Dictionary { attributes, values, vendors };
-        assert_eq!(dict, expected_dict)
+        // Vendor attributes are usually prefixed with vendor name, ie Cisco-AVPair
+        let prefixed_name = format!("{}-{}", vendor.name, path[1]);
+        let matches: Vec<usize> = self.attributes.iter().enumerate()
+            .filter(|(_, attr)| attr.vendor_id == Some(vendor.id) && (attr.name.eq_ignore_ascii_case(path[1]) || attr.name.eq_ignore_ascii_case(&prefixed_name)))
+            .map(|(index, _)| index)
+            .collect();
+        if matches.is_empty() {
+            return Ok(None)
+        }
+        self.unique_attribute(attribute_name, matches).map(Some)
+    }
+
+    fn unique_attribute(&self, attribute_name: &str, indexes: Vec<usize>) -> Result<&DictionaryAttribute, RadiusError> {
+        // The same definition repeated within the same vendor is not ambiguous, first one wins
+        let mut names: Vec<String> = Vec::new();
+        for &index in indexes.iter() {
+            let attr = &self.attributes[index];
+            let name = if attr.vendor_name.is_empty() { attr.name.to_string() } else { format!("{}.{}", attr.vendor_name, attr.name) };
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        if names.len() > 1 {
+            return Err( RadiusError::AmbiguousAttributeError { error: format!("{} matches {}", attribute_name, names.join(", ")) } )
+        }
+        Ok(&self.attributes[indexes[0]])
+    }
+
+    /// Returns standard (non-vendor) ATTRIBUTE with given code
+    pub fn attribute_by_code(&self, attribute_code: u8) -> Option<&DictionaryAttribute> {
+        self.attributes_by_code.get(&(None, u32::from(attribute_code))).map(|&index| &self.attributes[index])
+    }
+
+    /// Returns vendor ATTRIBUTE with given vendor id and code
+    pub fn vendor_attribute_by_code(&self, vendor_id: u32, attribute_code: u32) -> Option<&DictionaryAttribute> {
+        self.attributes_by_code.get(&(Some(vendor_id), attribute_code)).map(|&index| &self.attributes[index])
+    }
+
+    /// Returns ATTRIBUTE with given full code path, ie `[241, 1, 2]` (vendor_id is None for
+    /// standard attributes)
+    ///
+    /// Unlike [attribute_by_code](Dictionary::attribute_by_code), could return attributes nested into TLVs
+    pub fn attribute_by_oid(&self, vendor_id: Option<u32>, oid: &[u32]) -> Option<&DictionaryAttribute> {
+        self.attributes_by_oid.get(&(vendor_id, oid.to_vec())).map(|&index| &self.attributes[index])
+    }
+
+    /// Returns ATTRIBUTEs, that are direct children of given TLV ATTRIBUTE
+    pub fn child_attributes(&self, parent: &DictionaryAttribute) -> Vec<&DictionaryAttribute> {
+        let parent_oid = parent.oid();
+        self.attributes.iter().filter(|attr| attr.vendor_id == parent.vendor_id && attr.parent_oid == parent_oid).collect()
+    }
+
+    /// Returns Extended-Vendor-Specific ATTRIBUTE (ie `241.26`), that carries given vendor
+    /// ATTRIBUTE, RFC 6929 section 2.4
+    ///
+    /// Returns None for standard attributes and vendor attributes carried inside Vendor-Specific
+    /// attribute
+    pub fn evs_attribute(&self, attr: &DictionaryAttribute) -> Option<&DictionaryAttribute> {
+        attr.vendor_id?;
+        self.attribute_by_oid(None, attr.parent_oid.get(..2)?).filter(|evs_attr| evs_attr.code_type == Some(SupportedAttributeTypes::Evs))
+    }
+
+    /// Returns VALUE with given attribute & value name
+    pub fn value_by_name(&self, attribute_name: &str, value_name: &str) -> Option<&DictionaryValue> {
+        self.values_by_name.get(&(attribute_name.to_string(), value_name.to_string())).map(|&index| &self.values[index])
+    }
+
+    /// Returns VALUE with given attribute name & numeric value
+    pub fn value_by_number(&self, attribute_name: &str, value: u64) -> Option<&DictionaryValue> {
+        self.values_by_number.get(&(attribute_name.to_string(), value)).map(|&index| &self.values[index])
+    }
+
+    /// Returns VALUEs defined for attribute with given name, in the order they were defined in
+    pub fn attribute_values(&self, attribute_name: &str) -> Vec<&DictionaryValue> {
+        self.values.iter().filter(|value| value.attribute_name == attribute_name).collect()
+    }
+
+    /// Returns name of the VALUE for given numeric value of ATTRIBUTE with given code (vendor_id
+    /// is None for standard attributes), ie `Framed-User` for `Service-Type = 2`
+    pub fn value_name(&self, vendor_id: Option<u32>, attribute_code: u32, value: u64) -> Option<&str> {
+        let attr = self.attribute_by_oid(vendor_id, &[attribute_code])?;
+        self.value_by_number(&attr.name, value).map(|value| value.name())
+    }
+
+    /// Returns numeric value of the VALUE with given name of ATTRIBUTE with given code (vendor_id
+    /// is None for standard attributes), ie `2` for `Service-Type = Framed-User`
+    pub fn value_number(&self, vendor_id: Option<u32>, attribute_code: u32, value_name: &str) -> Option<u64> {
+        let attr = self.attribute_by_oid(vendor_id, &[attribute_code])?;
+        self.value_by_name(&attr.name, value_name).map(|value| value.value())
+    }
+
+    /// Returns VENDOR with given name
+    pub fn vendor_by_name(&self, vendor_name: &str) -> Option<&DictionaryVendor> {
+        self.vendors_by_name.get(vendor_name).map(|&index| &self.vendors[index])
+    }
+
+    /// Returns VENDOR with given id
+    pub fn vendor_by_id(&self, vendor_id: u32) -> Option<&DictionaryVendor> {
+        self.vendors_by_id.get(&vendor_id).map(|&index| &self.vendors[index])
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Represents Dictionary, that could be replaced at runtime (ie on SIGHUP), while it is in use
+///
+/// Handle is cheap to clone and every clone points to the same Dictionary. Readers take a
+/// [snapshot](SharedDictionary::snapshot), which stays untouched by later reloads.
+///
+/// Client & Server take a new snapshot on every call, so reload between two calls (ie between
+/// decoding request and creating reply attributes) is picked up by the second one. To use the
+/// same Dictionary for the whole request/reply cycle, take a snapshot once and pass it to
+/// `*_with_dictionary()` functions (ie
+/// [Server::initialise_packet_from_bytes_with_dictionary](crate::server::server::Server::initialise_packet_from_bytes_with_dictionary))
+/// and [RadiusAttribute](crate::protocol::radius_packet::RadiusAttribute) constructors
+pub struct SharedDictionary {
+    current: Arc<RwLock<Arc<Dictionary>>>
+}
+
+impl SharedDictionary {
+    /// Creates SharedDictionary, that holds given Dictionary
+    pub fn new(dictionary: Dictionary) -> SharedDictionary {
+        SharedDictionary {
+            current: Arc::new(RwLock::new(Arc::new(dictionary)))
+        }
+    }
+
+    /// Returns Dictionary, that is currently in use
+    pub fn snapshot(&self) -> Arc<Dictionary> {
+        // Lock only guards a pointer swap, so poisoned lock still holds a complete Dictionary
+        let current = self.current.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        Arc::clone(&current)
+    }
+
+    /// Replaces Dictionary without validating it and returns previous one
+    pub fn replace(&self, dictionary: Dictionary) -> Arc<Dictionary> {
+        let mut current = self.current.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        std::mem::replace(&mut *current, Arc::new(dictionary))
+    }
+
+    /// Validates Dictionary (see [Dictionary::validate]) and replaces current one with it
+    ///
+    /// If validation finds any problems, current Dictionary is kept and
+    /// [DictionaryValidationError](RadiusError::DictionaryValidationError) is returned
+    pub fn reload(&self, dictionary: Dictionary) -> Result<(), RadiusError> {
+        let errors = dictionary.validate();
+        if !errors.is_empty() {
+            return Err( RadiusError::DictionaryValidationError { errors } )
+        }
+
+        self.replace(dictionary);
+        Ok(())
+    }
+
+    /// Parses RADIUS dictionary file and reloads SharedDictionary with it
+    ///
+    /// See [SharedDictionary::reload] for details
+    pub fn reload_from_file(&self, file_path: &str) -> Result<(), RadiusError> {
+        self.reload(Dictionary::from_file(file_path)?)
+    }
+}
+
+impl From<Dictionary> for SharedDictionary {
+    fn from(dictionary: Dictionary) -> SharedDictionary {
+        SharedDictionary::new(dictionary)
+    }
+}
+
+#[cfg(feature = "serde-json")]
+impl Serialize for SupportedAttributeTypes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Data types are named the same way as in dictionary files
+        serializer.serialize_str(attribute_type_name(self))
+    }
+}
+
+#[cfg(feature = "serde-json")]
+impl<'de> Deserialize<'de> for SupportedAttributeTypes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SupportedAttributeTypes, D::Error> {
+        let code_type = String::deserialize(deserializer)?;
+        assign_attribute_type(&code_type).ok_or_else(|| de::Error::custom(format!("unknown attribute data type: {}", code_type)))
+    }
+}
+
+#[cfg(feature = "serde-json")]
+#[derive(Serialize)]
+struct DictionaryEntriesRef<'a> {
+    vendors:    &'a [DictionaryVendor],
+    attributes: &'a [DictionaryAttribute],
+    values:     &'a [DictionaryValue],
+    aliases:    &'a [DictionaryAlias]
+}
+
+#[cfg(feature = "serde-json")]
+#[derive(Deserialize)]
+struct DictionaryEntries {
+    vendors:    Vec<DictionaryVendor>,
+    attributes: Vec<DictionaryAttribute>,
+    values:     Vec<DictionaryValue>,
+    #[serde(default)]
+    aliases:    Vec<DictionaryAlias>
+}
+
+#[cfg(feature = "serde-json")]
+impl Serialize for Dictionary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DictionaryEntriesRef { vendors: &self.vendors, attributes: &self.attributes, values: &self.values, aliases: &self.aliases }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-json")]
+impl<'de> Deserialize<'de> for Dictionary {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Dictionary, D::Error> {
+        // Only entries are serialized, indexes are rebuilt from them
+        let entries = DictionaryEntries::deserialize(deserializer)?;
+        Ok(Dictionary::with_entries(entries.attributes, entries.values, entries.vendors, entries.aliases))
+    }
+}
+
+impl fmt::Display for Dictionary {
+    /// Writes Dictionary in FreeRADIUS dictionary format, that could be parsed back into the
+    /// same Dictionary
+    ///
+    /// VENDORs go first, followed by ATTRIBUTEs, VALUEs and ALIASes (each in the order they were
+    /// defined in), vendor entries are wrapped into `BEGIN-VENDOR`/`END-VENDOR` blocks and
+    /// TLV children & struct MEMBERs are written with dotted codes. Attributes of unknown data
+    /// type are written as octets
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for vendor in self.vendors.iter() {
+            let format = &vendor.format;
+            if *format == VendorFormat::default() {
+                writeln!(f, "VENDOR\t{}\t{}", vendor.name, vendor.id)?;
+            } else {
+                writeln!(f, "VENDOR\t{}\t{}\tformat={},{}{}", vendor.name, vendor.id, format.type_width, format.length_width, if format.continuation { ",c" } else { "" })?;
+            }
+        }
+
+        let mut vendor_block = ("", "");
+        writeln!(f)?;
+        for attr in self.attributes.iter() {
+            // Attributes of Extended-Vendor-Specific attribute are written into its vendor block
+            // with codes relative to that attribute
+            let evs_attr = self.evs_attribute(attr);
+            switch_vendor_block(f, &mut vendor_block, (&attr.vendor_name, evs_attr.map(|evs_attr| evs_attr.name.as_str()).unwrap_or_default()))?;
+
+            let oid       = attr.oid();
+            let code      = oid[evs_attr.map(|evs_attr| evs_attr.oid().len()).unwrap_or_default()..].iter().map(|code| code.to_string()).collect::<Vec<String>>().join(".");
+            let code_type = attr.code_type.as_ref().map(attribute_type_name).unwrap_or("octets");
+            let flags     = attribute_flags_names(&attr.flags);
+            if flags.is_empty() {
+                writeln!(f, "ATTRIBUTE\t{}\t{}\t{}", attr.name, code, code_type)?;
+            } else {
+                writeln!(f, "ATTRIBUTE\t{}\t{}\t{}\t{}", attr.name, code, code_type, flags.join(","))?;
+            }
+        }
+        switch_vendor_block(f, &mut vendor_block, ("", ""))?;
+
+        writeln!(f)?;
+        for value in self.values.iter() {
+            switch_vendor_block(f, &mut vendor_block, (&value.vendor_name, ""))?;
+            writeln!(f, "VALUE\t{}\t{}\t{}", value.attribute_name, value.value_name, value.value)?;
+        }
+        switch_vendor_block(f, &mut vendor_block, ("", ""))?;
+
+        if !self.aliases.is_empty() {
+            writeln!(f)?;
+        }
+        for alias in self.aliases.iter() {
+            writeln!(f, "ALIAS\t{}\t{}", alias.name, alias.attribute_name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Closes `BEGIN-VENDOR` block of the current vendor (if any) and opens the block of the next one
+///
+/// Block is identified by vendor name & name of Extended-Vendor-Specific attribute (empty for
+/// vendor attributes, that are carried inside Vendor-Specific attribute)
+fn switch_vendor_block<'a>(f: &mut fmt::Formatter<'_>, current: &mut (&'a str, &'a str), next: (&'a str, &'a str)) -> fmt::Result {
+    if *current == next {
+        return Ok(())
+    }
+
+    if !current.0.is_empty() {
+        writeln!(f, "END-VENDOR\t{}", current.0)?;
+    }
+    match next {
+        ("", _)            => {},
+        (vendor_name, "")  => writeln!(f, "BEGIN-VENDOR\t{}", vendor_name)?,
+        (vendor_name, evs) => writeln!(f, "BEGIN-VENDOR\t{}\tformat={}", vendor_name, evs)?
+    }
+    *current = next;
+    Ok(())
+}
+
+impl FromStr for Dictionary {
+    type Err = RadiusError;
+
+    fn from_str(dictionary_str: &str) -> Result<Dictionary, RadiusError> {
+        Dictionary::from_str(dictionary_str)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Defines how DictionaryParser treats entries it does not understand
+///
+/// Malformed lines (missing columns, non-numeric codes) are always rejected
+pub enum ParseMode {
+    /// Unknown keywords and data types are rejected with an error
+    Strict,
+    /// Unknown keywords are skipped and unknown data types are stored as `None`, both are
+    /// recorded as warnings
+    Lenient
+}
+
+#[derive(Debug)]
+/// Line-based RADIUS dictionary parser, that is shared by all Dictionary constructors
+///
+/// Could be used directly to pick [ParseMode] or to inspect warnings collected while parsing
+///
+/// Supports `$INCLUDE <path>` and `$INCLUDE- <path>` (same, but silently skipped if file does not
+/// exist) directives. Relative paths are resolved against the directory of the including file
+///
+/// Legacy Livingston/Cistron style is recognised as well: vendor attributes could name declared
+/// VENDOR in the fifth column (`ATTRIBUTE Ascend-Data-Filter 242 abinary Ascend`) instead of being
+/// wrapped into `BEGIN-VENDOR` block, and VENDOR lines could carry extra fields, that are ignored.
+/// Such VENDOR has to be defined before its attributes, otherwise ATTRIBUTE line is rejected
+///
+/// FreeRADIUS v4 keywords are supported as well:
+/// * `ALIAS <name> <attribute>` - alternative name of attribute (attribute could be referenced by
+///   its name or by path, ie `Vendor-Specific.Cisco.AVPair`)
+/// * `ENUM <name> <type>` - named set of VALUEs, that is shared by every ATTRIBUTE flagged with
+///   `enum=<name>` (VALUEs are copied to each such attribute)
+/// * `MEMBER <name> <type> [flags]` - field of the preceding ATTRIBUTE of struct data type, MEMBERs
+///   get codes 1, 2, 3 etc. in the order they are defined in. string & octets MEMBERs take the
+///   rest of the struct, so they should go last
+/// * `STRUCT <name> <key-member> <value>` - struct, that holds the rest of the parent struct, when
+///   its MEMBER flagged with `key` has given value. It is followed by its own MEMBERs
+/// * `BEGIN-PROTOCOL <name>` & `END-PROTOCOL <name>` - definitions of protocols other than RADIUS
+///   are skipped (`PROTOCOL` declarations are ignored)
+///
+/// # Examples
+///
+/// ```
+/// use radius_rust::protocol::dictionary::{ DictionaryParser, ParseMode };
+///
+/// fn main() {
+///     let mut parser = DictionaryParser::new(ParseMode::Lenient);
+///     parser.parse_str("ATTRIBUTE User-Name 1 string\nUNKNOWN-KEYWORD foo\n").unwrap();
+///
+///     assert_eq!(1, parser.warnings().len());
+///     let dictionary = parser.into_dictionary();
+///     assert_eq!(1, dictionary.attributes().len());
+/// }
+/// ```
+pub struct DictionaryParser {
+    mode:                ParseMode,
+    attributes:          Vec<DictionaryAttribute>,
+    values:              Vec<DictionaryValue>,
+    vendors:             Vec<DictionaryVendor>,
+    attribute_locations: Vec<EntryLocation>,
+    value_locations:     Vec<EntryLocation>,
+    vendor_locations:    Vec<EntryLocation>,
+    aliases:             Vec<DictionaryAlias>,
+    alias_locations:     Vec<EntryLocation>,
+    enums:               HashSet<String>,
+    enum_values:         Vec<(DictionaryValue, EntryLocation)>,
+    enum_references:     Vec<(usize, String)>,
+    vendor_name:         String,
+    vendor_id:           Option<u32>,
+    evs_oid:             Option<Vec<u32>>,
+    tlv_stack:           Vec<(String, Vec<u32>)>,
+    struct_members:      Option<(Vec<u32>, u32)>,
+    skipped_protocol:    Option<String>,
+    warnings:            Vec<DictionaryError>,
+    file:                String,
+    line:                usize,
+    includes:            Vec<PathBuf>,
+    loaded:              HashSet<PathBuf>
+}
+
+impl DictionaryParser {
+    /// Creates DictionaryParser with given parse mode
+    pub fn new(mode: ParseMode) -> DictionaryParser {
+        DictionaryParser {
+            mode,
+            attributes:          Vec::new(),
+            values:              Vec::new(),
+            vendors:             Vec::new(),
+            attribute_locations: Vec::new(),
+            value_locations:     Vec::new(),
+            vendor_locations:    Vec::new(),
+            aliases:             Vec::new(),
+            alias_locations:     Vec::new(),
+            enums:               HashSet::new(),
+            enum_values:         Vec::new(),
+            enum_references:     Vec::new(),
+            vendor_name:         String::new(),
+            vendor_id:           None,
+            evs_oid:             None,
+            tlv_stack:           Vec::new(),
+            struct_members:      None,
+            skipped_protocol:    None,
+            warnings:            Vec::new(),
+            file:                String::new(),
+            line:                0,
+            includes:            Vec::new(),
+            loaded:              HashSet::new()
+        }
+    }
+
+    /// Parses RADIUS dictionary file
+    ///
+    /// Files, that were already loaded by this parser (for example through `$INCLUDE`), are skipped
+    pub fn parse_file(&mut self, file_path: &str) -> Result<(), RadiusError> {
+        let file_path = fs::canonicalize(file_path).map_err(|error| RadiusError::MalformedDictionaryError { error })?;
+        self.parse_path(&file_path)
+    }
+
+    /// Parses every dictionary file in the directory (subdirectories are not visited)
+    ///
+    /// Files are parsed in alphabetical order, hidden files (starting with `.`) are ignored.
+    /// Files, that were already loaded through `$INCLUDE` of a previously parsed file, are
+    /// skipped, so directory with FreeRADIUS layout (top-level `dictionary` including
+    /// `dictionary.*` files) is loaded only once
+    pub fn parse_dir(&mut self, dir_path: &str) -> Result<(), RadiusError> {
+        let mut file_paths = Vec::new();
+        for entry in fs::read_dir(dir_path).map_err(|error| RadiusError::MalformedDictionaryError { error })? {
+            let entry = entry.map_err(|error| RadiusError::MalformedDictionaryError { error })?;
+            let path  = entry.path();
+
+            if path.is_file() && !entry.file_name().to_string_lossy().starts_with('.') {
+                file_paths.push(path);
+            }
+        }
+        file_paths.sort();
+
+        for file_path in file_paths {
+            let file_path = fs::canonicalize(&file_path).map_err(|error| RadiusError::MalformedDictionaryError { error })?;
+            self.parse_path(&file_path)?;
+        }
+        Ok(())
+    }
+
+    /// Parses RADIUS dictionary from a string
+    pub fn parse_str(&mut self, dictionary_str: &str) -> Result<(), RadiusError> {
+        self.parse_source(dictionary_str.as_bytes(), "<string>")
+    }
+
+    #[cfg(feature = "std-dictionaries")]
+    /// Parses embedded standard RFC dictionary
+    ///
+    /// Requires **std-dictionaries** feature
+    pub fn parse_standard(&mut self, rfc: StandardDictionary) {
+        // Embedded dictionaries are verified by tests to be valid, so there is nothing to fail on
+        self.parse_source(rfc.content().as_bytes(), rfc.file_name()).expect("embedded RFC dictionary is valid")
+    }
+
+    /// Parses RADIUS dictionary from any buffered reader
+    pub fn parse_reader<R: BufRead>(&mut self, reader: R) -> Result<(), RadiusError> {
+        self.parse_source(reader, "<reader>")
+    }
+
+    /// Returns warnings collected so far (only populated in [ParseMode::Lenient])
+    pub fn warnings(&self) -> &[DictionaryError] {
+        &self.warnings
+    }
+
+    // Dictionary constructors have no way to hand warnings over to the caller, so they are logged
+    fn into_logged_dictionary(self) -> Dictionary {
+        for warning in &self.warnings {
+            warn!("Skipped dictionary entry: {}", warning);
+        }
+        self.into_dictionary()
+    }
+
+    /// Consumes parser and returns Dictionary built from everything parsed so far
+    pub fn into_dictionary(self) -> Dictionary {
+        let mut values          = self.values;
+        let mut value_locations = self.value_locations;
+        // ENUM VALUEs could be defined after attributes, that refer to them
+        for (index, enum_name) in self.enum_references.iter() {
+            for (value, value_location) in self.enum_values.iter().filter(|(value, _)| &value.attribute_name == enum_name) {
+                values.push(DictionaryValue::new(&self.attributes[*index], &value.value_name, value.value));
+                value_locations.push(value_location.clone());
+            }
+        }
+
+        let mut dictionary = Dictionary::with_entries(self.attributes, values, self.vendors, self.aliases);
+
+        dictionary.attribute_locations = self.attribute_locations;
+        dictionary.value_locations     = value_locations;
+        dictionary.vendor_locations    = self.vendor_locations;
+        dictionary.alias_locations     = self.alias_locations;
+        dictionary
+    }
+
+    fn parse_path(&mut self, file_path: &Path) -> Result<(), RadiusError> {
+        // Expects canonical path, so the same file is always represented the same way
+        if !self.loaded.insert(file_path.to_path_buf()) {
+            return Ok(());
+        }
+        let file = File::open(file_path).map_err(|error| RadiusError::MalformedDictionaryError { error })?;
+
+        self.includes.push(file_path.to_path_buf());
+        let result = self.parse_source(io::BufReader::new(file), &file_path.to_string_lossy());
+        self.includes.pop();
+
+        result
+    }
+
+    fn parse_source<R: BufRead>(&mut self, reader: R, file_name: &str) -> Result<(), RadiusError> {
+        // Sources could be nested through $INCLUDE, so restore location of the parent afterwards
+        let parent_file = std::mem::replace(&mut self.file, file_name.to_string());
+        let parent_line = std::mem::replace(&mut self.line, 0);
+
+        let mut result = Ok(());
+        for line in reader.lines() {
+            let line = match line {
+                Ok(line)   => line,
+                Err(error) => {
+                    result = Err(RadiusError::MalformedDictionaryError { error });
+                    break;
+                }
+            };
+            self.line += 1;
+
+            result = self.parse_line(&line);
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.file = parent_file;
+        self.line = parent_line;
+        result
+    }
+
+    fn parse_include(&mut self, parsed_line: &[&str], optional: bool) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 2, "$INCLUDE <path>")?;
+
+        let include_path = Path::new(parsed_line[1]);
+        let include_path = match self.includes.last().and_then(|current| current.parent()) {
+            Some(current_dir) if include_path.is_relative() => current_dir.join(include_path),
+            _                                               => include_path.to_path_buf()
+        };
+
+        let include_path = match fs::canonicalize(&include_path) {
+            Ok(include_path)   => include_path,
+            Err(_) if optional => return Ok(()),
+            Err(error)         => return Err(self.error(parsed_line[1], &format!("cannot open included file: {}", error)))
+        };
+
+        if self.includes.contains(&include_path) {
+            return Err(self.error(parsed_line[1], "include cycle detected"))
+        }
+        self.parse_path(&include_path)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), RadiusError> {
+        // Anything after comment prefix is ignored, including trailing comments
+        let line = match line.find(COMMENT_PREFIX) {
+            Some(index) => &line[..index],
+            None        => line
+        };
+        // split_whitespace() takes care of tabs, multiple spaces and CRLF line endings
+        let parsed_line: Vec<&str> = line.split_whitespace().collect();
+        if parsed_line.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(protocol) = &self.skipped_protocol {
+            if parsed_line[0] == "END-PROTOCOL" && parsed_line.get(1) == Some(&protocol.as_str()) {
+                self.skipped_protocol = None;
+            }
+            return Ok(());
+        }
+        // MEMBERs (and their VALUEs) only follow struct ATTRIBUTE or STRUCT
+        if !matches!(parsed_line[0], "MEMBER" | "VALUE") {
+            self.struct_members = None;
+        }
+
+        match parsed_line[0] {
+            "ATTRIBUTE"      => self.parse_attribute(&parsed_line),
+            "VALUE"          => self.parse_value(&parsed_line),
+            "VENDOR"         => self.parse_vendor(&parsed_line),
+            "BEGIN-VENDOR"   => self.parse_begin_vendor(&parsed_line),
+            "END-VENDOR"     => {
+                self.vendor_name.clear();
+                self.vendor_id = None;
+                self.evs_oid   = None;
+                self.tlv_stack.clear();
+                Ok(())
+            },
+            "BEGIN-TLV"      => self.parse_begin_tlv(&parsed_line),
+            "END-TLV"        => self.parse_end_tlv(&parsed_line),
+            "ALIAS"          => self.parse_alias(&parsed_line),
+            "ENUM"           => self.parse_enum(&parsed_line),
+            "STRUCT"         => self.parse_struct(&parsed_line),
+            "MEMBER"         => self.parse_member(&parsed_line),
+            "PROTOCOL"       => self.expect_columns(&parsed_line, 3, "PROTOCOL <name> <number>"),
+            "BEGIN-PROTOCOL" => self.parse_begin_protocol(&parsed_line),
+            "END-PROTOCOL"   => self.expect_columns(&parsed_line, 2, "END-PROTOCOL <name>"),
+            "$INCLUDE"       => self.parse_include(&parsed_line, false),
+            "$INCLUDE-"      => self.parse_include(&parsed_line, true),
+            keyword          => self.unknown(keyword, "unknown keyword")
+        }
+    }
+
+    fn parse_attribute(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 4, "ATTRIBUTE <name> <code> <type>")?;
+
+        // Livingston/Cistron dictionaries name vendor in the fifth column (followed by flags)
+        // instead of wrapping vendor attributes into BEGIN-VENDOR block
+        let legacy_vendor = parsed_line.get(4)
+            .and_then(|vendor_name| self.vendors.iter().rev().find(|vendor| vendor.name == *vendor_name))
+            .map(|vendor| (vendor.name.to_string(), vendor.id));
+
+        match legacy_vendor {
+            Some((vendor_name, vendor_id)) => {
+                let block_vendor_name = std::mem::replace(&mut self.vendor_name, vendor_name);
+                let block_vendor_id   = self.vendor_id.replace(vendor_id);
+                let block_evs_oid     = self.evs_oid.take();
+                let block_tlv_stack   = std::mem::take(&mut self.tlv_stack);
+
+                let result = self.parse_attribute_columns(parsed_line, parsed_line.get(5));
+
+                self.vendor_name = block_vendor_name;
+                self.vendor_id   = block_vendor_id;
+                self.evs_oid     = block_evs_oid;
+                self.tlv_stack   = block_tlv_stack;
+                result
+            },
+            None                           => match parsed_line.get(4) {
+                Some(column) if !is_flags_column(column) => Err(self.error(column, &format!("{} is neither attribute flag nor defined vendor (VENDOR {} has to be defined before its attributes)", column, column))),
+                flags_column                             => self.parse_attribute_columns(parsed_line, flags_column)
+            }
+        }
+    }
+
+    fn parse_attribute_columns(&mut self, parsed_line: &[&str], flags_column: Option<&&str>) -> Result<(), RadiusError> {
+        // Attributes inside BEGIN-TLV block are children of that TLV, attributes inside
+        // `BEGIN-VENDOR <vendor> format=<evs attribute>` block are children of that
+        // Extended-Vendor-Specific attribute. Dotted codes (ie 241.1.2) go deeper from there
+        let mut parent_oid = self.tlv_stack.last().map(|(_, oid)| oid.to_vec()).or_else(|| self.evs_oid.clone()).unwrap_or_default();
+        for code in parsed_line[2].split('.') {
+            // Only top-level vendor attributes could have codes wider than u8
+            let max_code = if self.vendor_name.is_empty() || !parent_oid.is_empty() { u64::from(u8::MAX) } else { u64::from(u32::MAX) };
+            parent_oid.push(self.expect_number(code, max_code, "attribute code")? as u32);
+        }
+        let code = parent_oid.pop().unwrap_or_default();
+
+        if !parent_oid.is_empty() && Some(&parent_oid) != self.evs_oid.as_ref() && self.defined_attribute(&parent_oid).is_none() {
+            self.unknown(parsed_line[2], "parent attribute is not defined")?;
+        }
+
+        self.push_attribute(parsed_line[1], parent_oid, code, parsed_line[3], flags_column)?;
+        if self.attributes.last().map(|attr| attr.code_type) == Some(Some(SupportedAttributeTypes::Struct)) {
+            self.struct_members = self.attributes.last().map(|attr| (attr.oid(), 1));
+        }
+        Ok(())
+    }
+
+    fn push_attribute(&mut self, name: &str, parent_oid: Vec<u32>, code: u32, code_type_column: &str, flags_column: Option<&&str>) -> Result<(), RadiusError> {
+        let code_type = assign_attribute_type(code_type_column);
+        if code_type.is_none() {
+            self.unknown(code_type_column, "unknown attribute data type")?;
+        }
+
+        let flags = match flags_column {
+            Some(flags) => self.parse_attribute_flags(flags)?,
+            None        => AttributeFlags::default()
+        };
+
+        self.attribute_locations.push(self.location());
+        self.attributes.push(DictionaryAttribute {
+            name:        name.to_string(),
+            vendor_name: self.vendor_name.to_string(),
+            vendor_id:   self.vendor_id,
+            parent_oid,
+            code,
+            code_type,
+            flags
+        });
+        Ok(())
+    }
+
+    fn parse_attribute_flags(&mut self, flags_column: &str) -> Result<AttributeFlags, RadiusError> {
+        let mut flags = AttributeFlags::default();
+
+        for flag in flags_column.split(',') {
+            match flag {
+                "encrypt=1" => flags.encrypt = Some(EncryptionType::UserPassword),
+                "encrypt=2" => flags.encrypt = Some(EncryptionType::TunnelPassword),
+                "encrypt=3" => flags.encrypt = Some(EncryptionType::AscendSecret),
+                "has_tag"   => flags.has_tag = true,
+                "concat"    => flags.concat  = true,
+                "array"     => flags.array   = true,
+                "key"       => flags.key     = true,
+                flag if flag.starts_with("enum=") => {
+                    // Attribute is about to be pushed, so its VALUEs are copied from ENUM later on
+                    let enum_name = &flag["enum=".len()..];
+                    if self.enums.contains(enum_name) {
+                        self.enum_references.push((self.attributes.len(), enum_name.to_string()));
+                    } else {
+                        self.unknown(enum_name, "enum is not defined with ENUM keyword")?;
+                    }
+                },
+                flag        => self.unknown(flag, "unknown attribute flag")?
+            }
+        }
+        Ok(flags)
+    }
+
+    fn parse_value(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 4, "VALUE <attribute-name> <value-name> <value>")?;
+        let value = self.expect_number(parsed_line[3], u64::MAX, "value")?;
+
+        // VALUEs of attributes with legacy vendor column are not wrapped into BEGIN-VENDOR block either
+        let vendor_name = if self.vendor_name.is_empty() {
+            self.attributes.iter().rev().find(|attr| attr.name == parsed_line[1]).map(|attr| attr.vendor_name.to_string()).unwrap_or_default()
+        } else {
+            self.vendor_name.to_string()
+        };
+
+        let value = DictionaryValue {
+            attribute_name: parsed_line[1].to_string(),
+            value_name:     parsed_line[2].to_string(),
+            vendor_name,
+            value
+        };
+        if self.enums.contains(parsed_line[1]) {
+            self.enum_values.push((value, self.location()));
+        } else {
+            self.value_locations.push(self.location());
+            self.values.push(value);
+        }
+        Ok(())
+    }
+
+    fn parse_alias(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 3, "ALIAS <name> <attribute>")?;
+
+        let attribute_name = match self.referenced_attribute(parsed_line[2]) {
+            Some(attr) => attr.name.to_string(),
+            None       => return self.unknown(parsed_line[2], "attribute is not defined")
+        };
+
+        self.alias_locations.push(self.location());
+        self.aliases.push(DictionaryAlias {
+            name:           parsed_line[1].to_string(),
+            attribute_name
+        });
+        Ok(())
+    }
+
+    fn parse_enum(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 3, "ENUM <name> <type>")?;
+
+        if assign_attribute_type(parsed_line[2]).is_none() {
+            self.unknown(parsed_line[2], "unknown attribute data type")?;
+        }
+        self.enums.insert(parsed_line[1].to_string());
+        Ok(())
+    }
+
+    fn parse_struct(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 4, "STRUCT <name> <key-member> <value>")?;
+
+        let key = self.attributes.iter().rev()
+            .find(|attr| attr.name == parsed_line[2] && attr.vendor_name == self.vendor_name)
+            .map(|attr| (attr.flags.key, attr.oid()));
+        let key_oid = match key {
+            Some((true, oid)) => oid,
+            Some(_)           => return Err(self.error(parsed_line[2], "attribute is not a MEMBER flagged with key")),
+            None              => return Err(self.error(parsed_line[2], "attribute is not defined"))
+        };
+        let value = self.expect_number(parsed_line[3], u64::from(u8::MAX), "struct key value")? as u32;
+
+        self.push_attribute(parsed_line[1], key_oid.to_vec(), value, "struct", parsed_line.get(4))?;
+        self.struct_members = Some(([ key_oid.as_slice(), &[value] ].concat(), 1));
+        Ok(())
+    }
+
+    fn parse_member(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 3, "MEMBER <name> <type>")?;
+
+        let (struct_oid, code) = match self.struct_members.take() {
+            Some(members) => members,
+            None          => return Err(self.error(parsed_line[1], "MEMBER does not follow ATTRIBUTE of struct data type or STRUCT"))
+        };
+        if code > u32::from(u8::MAX) {
+            return Err(self.error(parsed_line[1], "struct has too many members"))
+        }
+
+        self.push_attribute(parsed_line[1], struct_oid.to_vec(), code, parsed_line[2], parsed_line.get(3))?;
+        self.struct_members = Some((struct_oid, code + 1));
+        Ok(())
+    }
+
+    fn parse_begin_protocol(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 2, "BEGIN-PROTOCOL <name>")?;
+
+        if parsed_line[1] != "RADIUS" {
+            self.skipped_protocol = Some(parsed_line[1].to_string());
+        }
+        Ok(())
+    }
+
+    /// Finds attribute by name or by FreeRADIUS v4 path (ie `Vendor-Specific.Cisco.AVPair`)
+    fn referenced_attribute(&self, reference: &str) -> Option<&DictionaryAttribute> {
+        if let Some(attr) = self.attributes.iter().rev().find(|attr| attr.name == reference) {
+            return Some(attr)
+        }
+
+        let path: Vec<&str> = reference.split('.').collect();
+        match path.as_slice() {
+            // Vendor attributes are usually prefixed with vendor name, ie Cisco-AVPair
+            ["Vendor-Specific", vendor_name, .., name] => {
+                let prefixed_name = format!("{}-{}", vendor_name, name);
+                self.attributes.iter().rev().find(|attr| attr.vendor_name == *vendor_name && (attr.name == *name || attr.name == prefixed_name))
+            },
+            [_, .., name]                              => self.attributes.iter().rev().find(|attr| attr.name == *name),
+            _                                          => None
+        }
+    }
+
+    fn parse_vendor(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 3, "VENDOR <vendor-name> <vendor-id>")?;
+        let id = self.expect_number(parsed_line[2], u64::from(u32::MAX), "vendor id")? as u32;
+
+        // Livingston/Cistron dictionaries carry extra fields after vendor id, they are not used
+        let format = match parsed_line[3..].iter().find(|column| column.starts_with("format=")) {
+            Some(format) => self.parse_vendor_format(format)?,
+            None         => VendorFormat::default()
+        };
+
+        self.vendor_locations.push(self.location());
+        self.vendors.push(DictionaryVendor {
+            name:   parsed_line[1].to_string(),
+            id,
+            format
+        });
+        Ok(())
+    }
+
+    fn parse_vendor_format(&self, format_column: &str) -> Result<VendorFormat, RadiusError> {
+        let options: Vec<&str> = format_column["format=".len()..].split(',').collect();
+
+        let type_width   = match options[0] {
+            "1" => 1,
+            "2" => 2,
+            "4" => 4,
+            _   => return Err(self.error(format_column, "vendor type width should be 1, 2 or 4"))
+        };
+        let length_width = match options.get(1) {
+            Some(&"0") => 0,
+            Some(&"1") => 1,
+            Some(&"2") => 2,
+            _          => return Err(self.error(format_column, "vendor length width should be 0, 1 or 2"))
+        };
+        let continuation = match options.get(2) {
+            // Continuation byte is only defined for WiMAX style attributes
+            Some(&"c") if type_width == 1 && length_width == 1 => true,
+            Some(_)                                            => return Err(self.error(format_column, "continuation is only allowed with format=1,1,c")),
+            None                                               => false
+        };
+
+        if options.len() > 3 {
+            return Err(self.error(format_column, "vendor format has too many options"))
+        }
+
+        Ok(VendorFormat {
+            type_width,
+            length_width,
+            continuation
+        })
+    }
+
+    fn parse_begin_vendor(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 2, "BEGIN-VENDOR <vendor-name> [format=<evs-attribute-name>]")?;
+
+        self.vendor_name = parsed_line[1].to_string();
+        self.vendor_id   = self.vendors.iter().find(|vendor| vendor.name == parsed_line[1]).map(|vendor| vendor.id);
+        self.evs_oid     = None;
+        if self.vendor_id.is_none() {
+            self.unknown(parsed_line[1], "vendor is not defined with VENDOR keyword")?;
+        }
+
+        // Vendor attributes could be carried inside Extended-Vendor-Specific attribute, RFC 6929 section 2.4
+        match parsed_line.get(2) {
+            Some(option) if option.starts_with("format=") => {
+                let evs_name = &option["format=".len()..];
+                let evs_attr = self.attributes.iter().rev()
+                    .find(|attr| attr.name == evs_name && attr.vendor_name.is_empty())
+                    .map(|attr| (attr.code_type, attr.oid()));
+
+                match evs_attr {
+                    Some((Some(SupportedAttributeTypes::Evs), oid)) => self.evs_oid = Some(oid),
+                    Some(_)                                         => return Err(self.error(evs_name, "attribute is not of evs data type")),
+                    None                                            => return Err(self.error(evs_name, "attribute is not defined"))
+                }
+            },
+            Some(option)                                  => self.unknown(option, "unknown BEGIN-VENDOR option")?,
+            None                                          => {}
+        }
+        Ok(())
+    }
+
+    fn parse_begin_tlv(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 2, "BEGIN-TLV <attribute-name>")?;
+
+        let parent = self.attributes.iter().rev()
+            .find(|attr| attr.name == parsed_line[1] && attr.vendor_name == self.vendor_name)
+            .map(|attr| (attr.code_type, attr.oid()));
+
+        match parent {
+            Some((Some(SupportedAttributeTypes::Tlv), oid)) => {
+                self.tlv_stack.push((parsed_line[1].to_string(), oid));
+                Ok(())
+            },
+            Some(_)                                         => Err(self.error(parsed_line[1], "attribute is not of tlv data type")),
+            None                                            => Err(self.error(parsed_line[1], "attribute is not defined"))
+        }
+    }
+
+    fn parse_end_tlv(&mut self, parsed_line: &[&str]) -> Result<(), RadiusError> {
+        self.expect_columns(parsed_line, 2, "END-TLV <attribute-name>")?;
+
+        match self.tlv_stack.last() {
+            Some((name, _)) if name == parsed_line[1] => {
+                self.tlv_stack.pop();
+                Ok(())
+            },
+            _                                         => Err(self.error(parsed_line[1], "END-TLV does not match BEGIN-TLV"))
+        }
+    }
+
+    fn defined_attribute(&self, oid: &[u32]) -> Option<&DictionaryAttribute> {
+        self.attributes.iter().rev().find(|attr| attr.vendor_name == self.vendor_name && attr.parent_oid.len() + 1 == oid.len() && attr.oid() == oid)
+    }
+
+    fn expect_columns(&self, parsed_line: &[&str], columns: usize, expected: &str) -> Result<(), RadiusError> {
+        if parsed_line.len() < columns {
+            let token = parsed_line[parsed_line.len() - 1];
+            return Err(self.error(token, &format!("line is truncated, expected: {}", expected)))
+        }
+        Ok(())
+    }
+
+    fn expect_number(&self, token: &str, max: u64, what: &str) -> Result<u64, RadiusError> {
+        match parse_number(token) {
+            Some(number) if number <= max => Ok(number),
+            Some(_)                       => Err(self.error(token, &format!("{} is out of range (max: {})", what, max))),
+            None                          => Err(self.error(token, &format!("{} is not a number", what)))
+        }
+    }
+
+    fn unknown(&mut self, token: &str, reason: &str) -> Result<(), RadiusError> {
+        match self.mode {
+            ParseMode::Strict  => Err(self.error(token, reason)),
+            ParseMode::Lenient => {
+                self.warnings.push(DictionaryError::new(&self.file, self.line, token, reason));
+                Ok(())
+            }
+        }
+    }
+
+    fn error(&self, token: &str, reason: &str) -> RadiusError {
+        RadiusError::DictionaryParseError { error: DictionaryError::new(&self.file, self.line, token, reason) }
+    }
+
+    fn location(&self) -> EntryLocation {
+        EntryLocation { file: self.file.to_string(), line: self.line }
+    }
+}
+
+// Flags column holds comma separated flags, single flag with value (ie encrypt=1) or single bare flag
+fn is_flags_column(column: &str) -> bool {
+    column.contains(',') || column.contains('=') || matches!(column, "has_tag" | "concat" | "array" | "key")
+}
+
+fn diagnostic(locations: &[EntryLocation], index: usize, token: &str, reason: &str) -> DictionaryError {
+    let entry_location = location(locations, index);
+    DictionaryError::new(&entry_location.file, entry_location.line, token, reason)
+}
+
+fn location(locations: &[EntryLocation], index: usize) -> EntryLocation {
+    locations.get(index).cloned().unwrap_or_default()
+}
+
+fn with_locations<T>(entries: Vec<T>, locations: &[EntryLocation]) -> Vec<(T, EntryLocation)> {
+    entries.into_iter().enumerate().map(|(index, entry)| (entry, location(locations, index))).collect()
+}
+
+/// Merges entry into the list of already defined ones, clashes are resolved with given policy and
+/// recorded into the report
+fn merge_entry<T: PartialEq>(entries: &mut Vec<(T, EntryLocation)>, entry: (T, EntryLocation), clashes: impl Fn(&T) -> bool, token: &str, policy: ConflictPolicy, report: &mut Vec<DictionaryError>) -> Result<(), RadiusError> {
+    let (entry, entry_location) = entry;
+    let clashing: Vec<usize>    = entries.iter().enumerate().filter(|(_, (existing, _))| clashes(existing)).map(|(index, _)| index).collect();
+
+    if clashing.len() == 1 && entries[clashing[0]].0 == entry {
+        return Ok(())
+    }
+    for &index in clashing.iter() {
+        let reason = match policy {
+            ConflictPolicy::Error     => {
+                let reason = format!("clashes with definition at {}", entries[index].1);
+                return Err(RadiusError::DictionaryParseError { error: DictionaryError::new(&entry_location.file, entry_location.line, token, &reason) })
+            },
+            ConflictPolicy::Override  => format!("overrides definition at {}", entries[index].1),
+            ConflictPolicy::KeepFirst => format!("is ignored in favour of definition at {}", entries[index].1)
+        };
+        report.push(DictionaryError::new(&entry_location.file, entry_location.line, token, &reason));
+    }
+
+    if clashing.is_empty() || policy == ConflictPolicy::Override {
+        for &index in clashing.iter().rev() {
+            entries.remove(index);
+        }
+        entries.push((entry, entry_location));
+    }
+    Ok(())
+}
+
+fn attribute_type_name(code_type: &SupportedAttributeTypes) -> &'static str {
+    match code_type {
+        SupportedAttributeTypes::AsciiString  => "string",
+        SupportedAttributeTypes::Integer      => "integer",
+        SupportedAttributeTypes::Date         => "date",
+        SupportedAttributeTypes::IPv4Addr     => "ipaddr",
+        SupportedAttributeTypes::IPv6Addr     => "ipv6addr",
+        SupportedAttributeTypes::IPv6Prefix   => "ipv6prefix",
+        SupportedAttributeTypes::Octets       => "octets",
+        SupportedAttributeTypes::Byte         => "byte",
+        SupportedAttributeTypes::Short        => "short",
+        SupportedAttributeTypes::Signed       => "signed",
+        SupportedAttributeTypes::Integer64    => "integer64",
+        SupportedAttributeTypes::Ether        => "ether",
+        SupportedAttributeTypes::IfId         => "ifid",
+        SupportedAttributeTypes::IPv4Prefix   => "ipv4prefix",
+        SupportedAttributeTypes::ComboIP      => "combo-ip",
+        SupportedAttributeTypes::ABinary      => "abinary",
+        SupportedAttributeTypes::Tlv          => "tlv",
+        SupportedAttributeTypes::Vsa          => "vsa",
+        SupportedAttributeTypes::Extended     => "extended",
+        SupportedAttributeTypes::LongExtended => "long-extended",
+        SupportedAttributeTypes::Evs          => "evs",
+        SupportedAttributeTypes::Struct       => "struct"
+    }
+}
+
+fn attribute_flags_names(flags: &AttributeFlags) -> Vec<&'static str> {
+    let mut names = Vec::new();
+    match flags.encrypt {
+        Some(EncryptionType::UserPassword)   => names.push("encrypt=1"),
+        Some(EncryptionType::TunnelPassword) => names.push("encrypt=2"),
+        Some(EncryptionType::AscendSecret)   => names.push("encrypt=3"),
+        None                                 => {}
+    }
+    if flags.has_tag {
+        names.push("has_tag");
+    }
+    if flags.concat {
+        names.push("concat");
+    }
+    if flags.array {
+        names.push("array");
+    }
+    if flags.key {
+        names.push("key");
+    }
+    names
+}
+
+fn assign_attribute_type(code_type: &str) -> Option<SupportedAttributeTypes> {
+    // Fixed size declarations, ie octets[16], share data type with their unsized variant
+    let code_type = code_type.split('[').next().unwrap_or(code_type);
+
+    match code_type {
+        "string"        => Some(SupportedAttributeTypes::AsciiString),
+        "integer"       => Some(SupportedAttributeTypes::Integer),
+        "date"          => Some(SupportedAttributeTypes::Date),
+        "ipaddr"        => Some(SupportedAttributeTypes::IPv4Addr),
+        "ipv6addr"      => Some(SupportedAttributeTypes::IPv6Addr),
+        "ipv6prefix"    => Some(SupportedAttributeTypes::IPv6Prefix),
+        "octets"        => Some(SupportedAttributeTypes::Octets),
+        "byte"          => Some(SupportedAttributeTypes::Byte),
+        "short"         => Some(SupportedAttributeTypes::Short),
+        "signed"        => Some(SupportedAttributeTypes::Signed),
+        "integer64"     => Some(SupportedAttributeTypes::Integer64),
+        "ether"         => Some(SupportedAttributeTypes::Ether),
+        "ifid"          => Some(SupportedAttributeTypes::IfId),
+        "ipv4prefix"    => Some(SupportedAttributeTypes::IPv4Prefix),
+        "combo-ip"      => Some(SupportedAttributeTypes::ComboIP),
+        "abinary"       => Some(SupportedAttributeTypes::ABinary),
+        "tlv"           => Some(SupportedAttributeTypes::Tlv),
+        "vsa"           => Some(SupportedAttributeTypes::Vsa),
+        "extended"      => Some(SupportedAttributeTypes::Extended),
+        "long-extended" => Some(SupportedAttributeTypes::LongExtended),
+        "evs"           => Some(SupportedAttributeTypes::Evs),
+        "struct"        => Some(SupportedAttributeTypes::Struct),
+        // FreeRADIUS v4 names of the same data types
+        "uint8"         => Some(SupportedAttributeTypes::Byte),
+        "uint16"        => Some(SupportedAttributeTypes::Short),
+        "uint32"        => Some(SupportedAttributeTypes::Integer),
+        "uint64"        => Some(SupportedAttributeTypes::Integer64),
+        "int32"         => Some(SupportedAttributeTypes::Signed),
+        "ipv4addr"      => Some(SupportedAttributeTypes::IPv4Addr),
+        "ethernet"      => Some(SupportedAttributeTypes::Ether),
+        _               => None
+    }
+}
+
+/// Parses decimal or hexadecimal (`0x` prefixed) number, as both are allowed in dictionaries
+fn parse_number(token: &str) -> Option<u64> {
+    if token.starts_with("0x") || token.starts_with("0X") {
+        u64::from_str_radix(&token[2..], 16).ok()
+    } else {
+        token.parse::<u64>().ok()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file() {
+        let dictionary_path = "./dict_examples/test_dictionary_dict";
+
+        let dict = Dictionary::from_file(dictionary_path).unwrap();
+
+        let mut attributes: Vec<DictionaryAttribute> = Vec::new();
+        attributes.push(DictionaryAttribute {
+            name:        "User-Name".to_string(),
+            vendor_name: "".to_string(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code:        1,
+            code_type:   Some(SupportedAttributeTypes::AsciiString),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "NAS-IP-Address".to_string(),
+            vendor_name: "".to_string(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code:        4,
+            code_type:   Some(SupportedAttributeTypes::IPv4Addr),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "NAS-Port-Id".to_string(),
+            vendor_name: "".to_string(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code:        5,
+            code_type:   Some(SupportedAttributeTypes::Integer),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "Framed-Protocol".to_string(),
+            vendor_name: "".to_string(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code:        7,
+            code_type:   Some(SupportedAttributeTypes::Integer),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "Somevendor-Name".to_string(),
+            vendor_name: "Somevendor".to_string(),
+            vendor_id:   Some(10),
+            parent_oid:  Vec::new(),
+            code:        1,
+            code_type:   Some(SupportedAttributeTypes::AsciiString),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "Somevendor-Number".to_string(),
+            vendor_name: "Somevendor".to_string(),
+            vendor_id:   Some(10),
+            parent_oid:  Vec::new(),
+            code:        2,
+            code_type:   Some(SupportedAttributeTypes::Integer),
+            flags:       AttributeFlags::default()
+        });
+        attributes.push(DictionaryAttribute {
+            name:        "Test-IP".to_string(),
+            vendor_name: "".to_string(),
+            vendor_id:   None,
+            parent_oid:  Vec::new(),
+            code:        25,
+            code_type:   Some(SupportedAttributeTypes::IPv4Addr),
+            flags:       AttributeFlags::default()
+        });
+        
+        let mut values: Vec<DictionaryValue> = Vec::new();
+        values.push(DictionaryValue {
+            attribute_name: "Framed-Protocol".to_string(),
+            value_name:     "PPP".to_string(),
+            vendor_name:    "".to_string(),
+            value:          1
+        });
+        values.push(DictionaryValue {
+            attribute_name: "Somevendor-Number".to_string(),
+            value_name:     "Two".to_string(),
+            vendor_name:    "Somevendor".to_string(),
+            value:          2
+        });
+
+        let mut vendors: Vec<DictionaryVendor> = Vec::new();
+        vendors.push(DictionaryVendor {
+            name:   "Somevendor".to_string(),
+            id:     10,
+            format: VendorFormat::default()
+        });
+
+        let expected_dict = Dictionary::with_entries(attributes, values, vendors, Vec::new());
+        assert_eq!(dict, expected_dict)
+    }
+
+    #[test]
+    fn test_validate() {
+        let dict        = Dictionary::from_file("./dict_examples/validate_dict").unwrap();
+        let diagnostics = dict.validate();
+
+        let reported: Vec<(usize, &str, String)> = diagnostics.iter().map(|diagnostic| (diagnostic.line(), diagnostic.token(), diagnostic.reason().to_string())).collect();
+        let file = diagnostics[0].file();
+
+        assert!(file.ends_with("validate_dict"));
+        assert_eq!(vec![
+            (6,  "6",              "attribute code is already used by Service-Type at ".to_string() + file + ":5"),
+            (7,  "User-Name",      "attribute name is already defined at ".to_string() + file + ":3"),
+            (9,  "Password",       "encrypt flag is only allowed for string or octets data types".to_string()),
+            (10, "Addresses",      "array flag is only allowed for fixed size data types".to_string()),
+            (21, "Undeclared",     "vendor is not defined with VENDOR keyword".to_string()),
+            (12, "Login-User",     "value name is already defined at ".to_string() + file + ":11"),
+            (13, "Unknown-Attr",   "attribute is not defined".to_string()),
+            (14, "NAS-IP-Address", "attribute is not of integer data type".to_string()),
+            (15, "9000",           "value does not fit attribute's data type (max: 255)".to_string()),
+            (18, "10",             "vendor id is already used by Somevendor at ".to_string() + file + ":17")
+        ], reported);
+    }
+
+    #[test]
+    fn test_validate_consistent_dictionary() {
+        let dict = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        assert!(dict.validate().is_empty());
+
+        let dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+        assert!(dict.validate().is_empty());
+    }
+
+    #[test]
+    fn test_legacy_vendor_column() {
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        parser.parse_file("./dict_examples/legacy_dict").unwrap();
+        assert!(parser.warnings().is_empty());
+        let dict = parser.into_dictionary();
+
+        assert!(dict.validate().is_empty());
+        assert_eq!(None,                                  dict.attribute_by_code(244));
+        assert_eq!("Ascend-Idle-Limit",                   dict.vendor_attribute_by_code(529, 244).unwrap().name());
+        assert_eq!("Ascend",                              dict.vendor_attribute_by_code(529, 244).unwrap().vendor_name());
+        assert_eq!("USR-Connect-Speed",                   dict.vendor_attribute_by_code(429, 0x9823).unwrap().name());
+        assert_eq!(4,                                     dict.vendor_by_name("USR").unwrap().format().type_width());
+        assert_eq!(Some(EncryptionType::AscendSecret),    dict.attribute_by_name("Ascend-Send-Secret").unwrap().flags().encrypt());
+        assert_eq!(Some(EncryptionType::UserPassword),    dict.attribute_by_name("User-Password").unwrap().flags().encrypt());
+        assert_eq!("",                                    dict.attribute_by_name("User-Password").unwrap().vendor_name());
+        assert_eq!("Ascend",                              dict.value_by_name("Ascend-Idle-Limit", "Never").unwrap().vendor_name());
+        assert_eq!(Some("9600"),                          dict.value_name(Some(429), 0x9823, 4));
+    }
+
+    #[test]
+    fn test_legacy_vendor_column_before_vendor() {
+        for mode in [ParseMode::Strict, ParseMode::Lenient].iter() {
+            let mut parser = DictionaryParser::new(*mode);
+            match parser.parse_str("ATTRIBUTE Ascend-Idle-Limit 244 integer Ascend\nVENDOR Ascend 529\n") {
+                Err(RadiusError::DictionaryParseError { error }) => {
+                    assert_eq!(1,        error.line());
+                    assert_eq!("Ascend", error.token());
+                    assert!(error.reason().contains("VENDOR Ascend has to be defined before its attributes"));
+                },
+                _                                                => unreachable!()
+            }
+        }
+
+        let dict = Dictionary::from_str("VENDOR Ascend 529\nATTRIBUTE Ascend-Idle-Limit 244 integer Ascend has_tag\nATTRIBUTE Tunnel-Type 64 integer has_tag\n").unwrap();
+        assert_eq!("Ascend", dict.attribute_by_name("Ascend-Idle-Limit").unwrap().vendor_name());
+        assert!(dict.attribute_by_name("Tunnel-Type").unwrap().flags().has_tag());
+    }
+
+    #[test]
+    fn test_freeradius_v4_keywords() {
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        parser.parse_file("./dict_examples/v4_dict").unwrap();
+        let dict = parser.into_dictionary();
+
+        assert!(dict.validate().is_empty());
+        assert_eq!(None,                                   dict.attribute_by_name("Subnet-Mask"));
+        assert_eq!(None,                                   dict.attribute_by_name("Mask"));
+        assert_eq!("User-Name",                            dict.attribute_by_name("Login-Name").unwrap().name());
+        assert_eq!("Somevendor-AVPair",                    dict.attribute_by_name("Somevendor-Pair").unwrap().name());
+        assert_eq!(Some(SupportedAttributeTypes::Integer), *dict.attribute_by_name("NAS-Port-Type").unwrap().code_type());
+
+        assert_eq!(Some("Ethernet"), dict.value_name(None, 61, 15));
+        assert_eq!(Some("Virtual"),  dict.value_name(Some(10), 2, 5));
+        assert_eq!(None,             dict.value_by_name("Port-Kind", "Virtual"));
+
+        let location = dict.attribute_by_name("Location").unwrap();
+        let members: Vec<&str> = dict.child_attributes(location).iter().map(|attr| attr.name()).collect();
+        assert_eq!(Some(SupportedAttributeTypes::Struct), *location.code_type());
+        assert_eq!(vec!["Location-Version", "Location-Kind", "Location-Floor"], members);
+        assert!(dict.attribute_by_name("Location-Kind").unwrap().flags().key());
+        assert_eq!(vec![200, 2, 1],                       dict.attribute_by_name("Location-Civic").unwrap().oid());
+        assert_eq!(vec![200, 2, 2, 2],                    dict.attribute_by_name("Location-Geo-Longitude").unwrap().oid());
+    }
+
+    #[test]
+    fn test_freeradius_v4_keywords_errors() {
+        match Dictionary::from_str("ATTRIBUTE User-Name 1 string\nMEMBER User-Name-Length uint8\n") {
+            Err(RadiusError::DictionaryParseError { error }) => assert_eq!(2, error.line()),
+            _                                                => unreachable!()
+        }
+        match Dictionary::from_str("ATTRIBUTE Location 200 struct\nMEMBER Location-Kind uint8\nSTRUCT Location-Civic Location-Kind 1\n") {
+            Err(RadiusError::DictionaryParseError { error }) => assert_eq!("attribute is not a MEMBER flagged with key", error.reason()),
+            _                                                => unreachable!()
+        }
+
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_str("ALIAS Login-Name User-Name\nATTRIBUTE NAS-Port-Type 61 integer enum=Port-Kind\n").unwrap();
+        let warnings: Vec<&str> = parser.warnings().iter().map(|warning| warning.token()).collect();
+        assert_eq!(vec!["User-Name", "Port-Kind"], warnings);
+    }
+
+    #[test]
+    fn test_to_string_round_trip() {
+        for dictionary_path in ["./dict_examples/integration_dict", "./dict_examples/test_dictionary_dict", "./dict_examples/validate_dict", "./dict_examples/v4_dict", "./dict_examples/legacy_dict"].iter() {
+            let dict = Dictionary::from_file(dictionary_path).unwrap();
+            assert_eq!(dict, Dictionary::from_str(&dict.to_string()).unwrap());
+        }
+
+        let dictionary_str = "VENDOR Somevendor 10 format=2,1\n\
+                              BEGIN-VENDOR Somevendor\n\
+                              ATTRIBUTE Somevendor-TLV 1 tlv\n\
+                              BEGIN-TLV Somevendor-TLV\n\
+                              ATTRIBUTE Somevendor-Child 1 string encrypt=2,has_tag\n\
+                              END-TLV Somevendor-TLV\n\
+                              END-VENDOR Somevendor\n\
+                              ATTRIBUTE EAP-Message 79 octets concat\n";
+        let dict           = Dictionary::from_str(dictionary_str).unwrap();
+
+        assert_eq!(dict, Dictionary::from_str(&dict.to_string()).unwrap());
+        assert_eq!("VENDOR\tSomevendor\t10\tformat=2,1\n\
+                    \n\
+                    BEGIN-VENDOR\tSomevendor\n\
+                    ATTRIBUTE\tSomevendor-TLV\t1\ttlv\n\
+                    ATTRIBUTE\tSomevendor-Child\t1.1\tstring\tencrypt=2,has_tag\n\
+                    END-VENDOR\tSomevendor\n\
+                    ATTRIBUTE\tEAP-Message\t79\toctets\tconcat\n\
+                    \n", dict.to_string());
+    }
+
+    #[cfg(feature = "serde-json")]
+    #[test]
+    fn test_json_round_trip() {
+        let dict = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let json = dict.to_json();
+
+        assert!(json.contains("\"code_type\": \"ipaddr\""));
+        assert_eq!(dict, Dictionary::from_json(&json).unwrap());
+        assert_eq!(dict.attribute_by_code(4), Dictionary::from_json(&json).unwrap().attribute_by_code(4));
+
+        let dict = Dictionary::from_file("./dict_examples/v4_dict").unwrap();
+        let json = dict.to_json();
+        assert_eq!(dict, Dictionary::from_json(&json).unwrap());
+        assert_eq!("User-Name", Dictionary::from_json(&json).unwrap().attribute_by_name("Login-Name").unwrap().name());
+
+        match Dictionary::from_json("{\"vendors\": [], \"attributes\": [{\"name\": \"Foo\", \"code_type\": \"unknown\"}], \"values\": []}") {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!("<json>", error.file());
+                assert_eq!(1,        error.line());
+            },
+            _                                                => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_build_in_code() {
+        let mut dict = Dictionary::default();
+
+        let vendor   = DictionaryVendor::new("Somevendor", 10).set_format(VendorFormat::new(2, 1, false).unwrap());
+        let tlv      = DictionaryAttribute::new("Somevendor-TLV", 1, SupportedAttributeTypes::Tlv).set_vendor(&vendor);
+        let child    = DictionaryAttribute::new("Somevendor-Child", 2, SupportedAttributeTypes::Integer).set_parent(&tlv);
+        let value    = DictionaryValue::new(&child, "Enabled", 1);
+        let password = DictionaryAttribute::new("Tunnel-Password", 69, SupportedAttributeTypes::AsciiString)
+            .set_flags(AttributeFlags::default().set_encrypt(Some(EncryptionType::TunnelPassword)).set_has_tag(true));
+
+        dict.add_vendor(vendor);
+        dict.add_attribute(tlv);
+        dict.add_attribute(child);
+        dict.add_attribute(password);
+        dict.add_value(value);
+
+        assert_eq!(Some(10),                           dict.vendor_by_name("Somevendor").map(|vendor| vendor.id()));
+        assert_eq!("Somevendor-Child",                 dict.attribute_by_oid(Some(10), &[1, 2]).unwrap().name());
+        assert_eq!(Some(EncryptionType::TunnelPassword), dict.attribute_by_code(69).unwrap().flags().encrypt());
+        assert_eq!(1,                                  dict.value_by_name("Somevendor-Child", "Enabled").unwrap().value());
+        assert!(dict.validate().is_empty());
+
+        let expected_dict = Dictionary::from_str("VENDOR Somevendor 10 format=2,1\n\
+                                                  BEGIN-VENDOR Somevendor\n\
+                                                  ATTRIBUTE Somevendor-TLV 1 tlv\n\
+                                                  ATTRIBUTE Somevendor-Child 1.2 integer\n\
+                                                  VALUE Somevendor-Child Enabled 1\n\
+                                                  END-VENDOR Somevendor\n\
+                                                  ATTRIBUTE Tunnel-Password 69 string encrypt=2,has_tag\n").unwrap();
+        assert_eq!(expected_dict, dict);
+        assert_eq!(expected_dict, Dictionary::from_str(&dict.to_string()).unwrap());
+
+        assert_eq!(None, VendorFormat::new(3, 1, false));
+        assert_eq!(None, VendorFormat::new(1, 0, true));
+    }
+
+    #[test]
+    fn test_merge() {
+        let base_path = "./dict_examples/test_dictionary_dict";
+        let site_path = "./dict_examples/site_dict";
+
+        let mut dict = Dictionary::from_file(base_path).unwrap();
+        match dict.merge_file(site_path, ConflictPolicy::Error) {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!(5,             error.line());
+                assert_eq!("NAS-Port-Id", error.token());
+            },
+            _                                                => unreachable!()
+        }
+        assert_eq!(Dictionary::from_file(base_path).unwrap(), dict);
+
+        let report = dict.merge_file(site_path, ConflictPolicy::KeepFirst).unwrap();
+        let report: Vec<(usize, &str)> = report.iter().map(|error| (error.line(), error.token())).collect();
+        assert_eq!(vec![(5, "NAS-Port-Id"), (6, "Site-Port"), (9, "PPP")], report);
+        assert_eq!(Some(SupportedAttributeTypes::Integer), *dict.attribute_by_name("NAS-Port-Id").unwrap().code_type());
+        assert_eq!(None,                                   dict.attribute_by_name("Site-Port"));
+        assert_eq!("Site-Name",                            dict.attribute_by_code(26).unwrap().name());
+        assert_eq!("Somevendor-Site",                      dict.vendor_attribute_by_code(10, 3).unwrap().name());
+
+        let mut dict = Dictionary::from_file(base_path).unwrap();
+        let report   = dict.merge_file(site_path, ConflictPolicy::Override).unwrap();
+        assert_eq!(3, report.len());
+        assert!(report[0].reason().starts_with("overrides definition at "));
+        assert!(report[0].reason().ends_with("test_dictionary_dict:5"));
+        assert_eq!(Some(SupportedAttributeTypes::AsciiString), *dict.attribute_by_name("NAS-Port-Id").unwrap().code_type());
+        assert_eq!("Site-Port",                                dict.attribute_by_code(7).unwrap().name());
+        assert_eq!(None,                                       dict.attribute_by_name("Framed-Protocol"));
+        assert_eq!(5,                                          dict.value_by_name("Framed-Protocol", "PPP").unwrap().value());
+        assert_eq!(1,                                          dict.attributes().iter().filter(|attr| attr.name() == "Test-IP").count());
+    }
+
+    #[test]
+    fn test_value_lookups() {
+        let dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        assert_eq!(Some("PPP"), dict.value_name(None, 7, 1));
+        assert_eq!(Some(1),     dict.value_number(None, 7, "PPP"));
+        assert_eq!(None,        dict.value_name(None, 7, 100));
+        assert_eq!(None,        dict.value_number(None, 7, "SLIP"));
+        assert_eq!(None,        dict.value_name(Some(10), 7, 1));
+        assert_eq!(Some("Two"), dict.value_name(Some(10), 2, 2));
+        assert_eq!(Some(2),     dict.value_number(Some(10), 2, "Two"));
+        assert_eq!(None,        dict.value_name(None, 2, 2));
+        assert_eq!(vec!["PPP"], dict.attribute_values("Framed-Protocol").iter().map(|value| value.name()).collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn test_resolve_attribute() {
+        let dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        assert_eq!("User-Name",         dict.resolve_attribute("User-Name").unwrap().name());
+        assert_eq!("User-Name",         dict.resolve_attribute("user-name").unwrap().name());
+        assert_eq!("Somevendor-Name",   dict.resolve_attribute("SOMEVENDOR-NAME").unwrap().name());
+        assert_eq!("Somevendor-Name",   dict.resolve_attribute("Somevendor.Somevendor-Name").unwrap().name());
+        assert_eq!("Somevendor-Name",   dict.resolve_attribute("somevendor.name").unwrap().name());
+        assert_eq!("Somevendor-Number", dict.resolve_attribute("Vendor-Specific.Somevendor.Number").unwrap().name());
+        assert_eq!("Somevendor-Number", dict.resolve_attribute("Vendor-Specific.10.2").unwrap().name());
+        assert_eq!("Somevendor-Number", dict.resolve_attribute("10.Number").unwrap().name());
+
+        match dict.resolve_attribute("Somevendor.User-Name") {
+            Err(RadiusError::MalformedAttributeError { error }) => assert_eq!("No attribute with name: Somevendor.User-Name found in dictionary", error),
+            other                                               => panic!("Unexpected result: {:?}", other)
+        }
+        assert!(dict.resolve_attribute("Vendor-Specific.10.100").is_err());
+        assert!(dict.resolve_attribute("Othervendor.Name").is_err());
+    }
+
+    #[test]
+    fn test_resolve_attribute_ambiguous() {
+        let dict = Dictionary::from_str("VENDOR Cisco 9\nVENDOR Foo 100\n\
+            BEGIN-VENDOR Cisco\nATTRIBUTE AVPair 1 string\nATTRIBUTE Session-Id 2 string\nEND-VENDOR Cisco\n\
+            BEGIN-VENDOR Foo\nATTRIBUTE AVPair 1 string\nATTRIBUTE SESSION-ID 2 string\nEND-VENDOR Foo\n").unwrap();
+
+        assert_eq!(Some(9),   dict.resolve_attribute("Cisco.AVPair").unwrap().vendor_id());
+        assert_eq!(Some(100), dict.resolve_attribute("Foo.avpair").unwrap().vendor_id());
+        assert_eq!(Some(9),   dict.resolve_attribute("Session-Id").unwrap().vendor_id());
+        assert_eq!(Some(100), dict.resolve_attribute("SESSION-ID").unwrap().vendor_id());
+
+        match dict.resolve_attribute("AVPair") {
+            Err(RadiusError::AmbiguousAttributeError { error }) => assert_eq!("AVPair matches Cisco.AVPair, Foo.AVPair", error),
+            other                                               => panic!("Unexpected result: {:?}", other)
+        }
+        match dict.resolve_attribute("session-id") {
+            Err(RadiusError::AmbiguousAttributeError { error }) => assert_eq!("session-id matches Cisco.Session-Id, Foo.SESSION-ID", error),
+            other                                               => panic!("Unexpected result: {:?}", other)
+        }
+    }
+
+    #[test]
+    fn test_shared_dictionary_reload() {
+        let shared = SharedDictionary::new(Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap());
+        let handle = shared.clone();
+        let before = shared.snapshot();
+
+        handle.reload_from_file("./dict_examples/integration_dict").unwrap();
+
+        let after = shared.snapshot();
+        assert!(before.attribute_by_name("Test-IP").is_some());
+        assert!(after.attribute_by_name("Test-IP").is_none());
+        assert_eq!(Dictionary::from_file("./dict_examples/integration_dict").unwrap(), *after);
+
+        match shared.reload_from_file("./dict_examples/validate_dict") {
+            Err(RadiusError::DictionaryValidationError { errors }) => assert!(!errors.is_empty()),
+            _                                                      => unreachable!()
+        }
+        assert!(Arc::ptr_eq(&after, &handle.snapshot()));
+
+        let previous = shared.replace(Dictionary::default());
+        assert!(Arc::ptr_eq(&after, &previous));
+        assert!(handle.snapshot().attributes().is_empty());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let dictionary_str = include_str!("../../dict_examples/test_dictionary_dict");
+
+        let dict          = Dictionary::from_str(dictionary_str).unwrap();
+        let expected_dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        assert_eq!(dict, expected_dict)
+    }
+
+    #[test]
+    fn test_from_str_tabs_trailing_comments_and_crlf() {
+        let dictionary_str = "# Leading comment\r\n\
+                              ATTRIBUTE\tUser-Name\t1\tstring # trailing comment\r\n\
+                              \r\n\
+                              VENDOR Somevendor 10#no space before comment\r\n\
+                              BEGIN-VENDOR\tSomevendor\r\n\
+                              ATTRIBUTE Somevendor-Name 1 string\r\n\
+                              END-VENDOR Somevendor\r\n";
+
+        let dict = Dictionary::from_str(dictionary_str).unwrap();
+
+        assert_eq!(2, dict.attributes().len());
+        assert_eq!("User-Name",       dict.attributes()[0].name());
+        assert_eq!(1,                 dict.attributes()[0].code());
+        assert_eq!(&Some(SupportedAttributeTypes::AsciiString), dict.attributes()[0].code_type());
+        assert_eq!("Somevendor-Name", dict.attributes()[1].name());
+        assert_eq!("Somevendor",      dict.attributes()[1].vendor_name());
+        assert_eq!(vec![DictionaryVendor { name: "Somevendor".to_string(), id: 10, format: VendorFormat::default() }], dict.vendors);
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let reader = io::Cursor::new("ATTRIBUTE Framed-Protocol 7 integer\nVALUE Framed-Protocol PPP 1\n");
+
+        let dict = Dictionary::from_reader(reader).unwrap();
+
+        assert_eq!(1,     dict.attributes().len());
+        assert_eq!("PPP", dict.values()[0].name());
+        assert_eq!(1,     dict.values()[0].value());
+    }
+
+    #[test]
+    fn test_truncated_line_error() {
+        let dictionary_str = "ATTRIBUTE User-Name 1 string\n\nATTRIBUTE NAS-IP-Address 4\n";
+
+        match Dictionary::from_str(dictionary_str) {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!("<string>",       error.file());
+                assert_eq!(3,                error.line());
+                assert_eq!("4",              error.token());
+                assert!(error.reason().starts_with("line is truncated"));
+            },
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_not_a_number_error() {
+        match Dictionary::from_str("VALUE Framed-Protocol PPP one") {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!(1,     error.line());
+                assert_eq!("one", error.token());
+            },
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        match parser.parse_str("ATTRIBUTE User-Name 1 string\nATTRIBUTE Foo 2 bar\n") {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!(2,     error.line());
+                assert_eq!("bar", error.token());
+            },
+            _ => unreachable!()
+        }
+
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        assert!(parser.parse_str("FOO-KEYWORD bar\n").is_err());
+
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        assert!(parser.parse_file("./dict_examples/test_dictionary_dict").is_ok());
+    }
+
+    #[test]
+    fn test_lenient_mode_warnings() {
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_str("FOO-KEYWORD bar\nATTRIBUTE Foo 2 bar\n").unwrap();
+
+        let expected_warnings = vec![
+            DictionaryError::new("<string>", 1, "FOO-KEYWORD", "unknown keyword"),
+            DictionaryError::new("<string>", 2, "bar",         "unknown attribute data type")
+        ];
+        assert_eq!(expected_warnings, parser.warnings());
+
+        let dict = parser.into_dictionary();
+        assert_eq!(&None, dict.attributes()[0].code_type());
+    }
+
+    #[test]
+    fn test_include() {
+        let dict = Dictionary::from_file("./dict_examples/include_dict/dictionary").unwrap();
+
+        let attribute_names: Vec<&str> = dict.attributes().iter().map(|attr| attr.name()).collect();
+        assert_eq!(vec!["User-Name", "NAS-IP-Address", "Somevendor-Name", "Framed-Protocol"], attribute_names);
+        assert_eq!("Somevendor", dict.attributes()[2].vendor_name());
+        assert_eq!("",           dict.attributes()[3].vendor_name());
+        assert_eq!(1,            dict.vendors().len());
+    }
+
+    #[test]
+    fn test_include_missing_file() {
+        match Dictionary::from_str("$INCLUDE ./dict_examples/does_not_exist\n") {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert_eq!(1,                               error.line());
+                assert_eq!("./dict_examples/does_not_exist", error.token());
+            },
+            _ => unreachable!()
+        }
+
+        let dict = Dictionary::from_str("$INCLUDE- ./dict_examples/does_not_exist\n").unwrap();
+        assert!(dict.attributes().is_empty());
+    }
+
+    #[test]
+    fn test_include_cycle() {
+        match Dictionary::from_file("./dict_examples/include_cycle/dictionary.a") {
+            Err(RadiusError::DictionaryParseError { error }) => {
+                assert!(error.file().ends_with("dictionary.b"));
+                assert_eq!(3,              error.line());
+                assert_eq!("dictionary.a", error.token());
+                assert_eq!("include cycle detected", error.reason());
+            },
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dict          = Dictionary::from_dir("./dict_examples/include_dict").unwrap();
+        let expected_dict = Dictionary::from_file("./dict_examples/include_dict/dictionary").unwrap();
+
+        assert_eq!(expected_dict, dict);
+    }
+
+    #[test]
+    fn test_lookups() {
+        let dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        assert_eq!(5,                   dict.attribute_by_name("NAS-Port-Id").unwrap().code());
+        assert_eq!("User-Name",         dict.attribute_by_code(1).unwrap().name());
+        assert_eq!("Somevendor-Name",   dict.vendor_attribute_by_code(10, 1).unwrap().name());
+        assert_eq!("Somevendor-Number", dict.vendor_attribute_by_code(10, 2).unwrap().name());
+        assert_eq!(None,                dict.vendor_attribute_by_code(11, 1));
+        assert_eq!(None,                dict.attribute_by_code(2));
+
+        assert_eq!(1,                   dict.value_by_name("Framed-Protocol", "PPP").unwrap().value());
+        assert_eq!("Two",               dict.value_by_number("Somevendor-Number", 2).unwrap().name());
+        assert_eq!(None,                dict.value_by_number("Framed-Protocol", 2));
+
+        assert_eq!(10,                  dict.vendor_by_name("Somevendor").unwrap().id());
+        assert_eq!("Somevendor",        dict.vendor_by_id(10).unwrap().name());
+    }
+
+    #[test]
+    fn test_code_out_of_range() {
+        match Dictionary::from_str("ATTRIBUTE Too-Big 256 string") {
+            Err(RadiusError::DictionaryParseError { error }) => assert_eq!("256", error.token()),
+            _                                                => unreachable!()
+        }
+
+        let dict = Dictionary::from_str("VENDOR Wide 429\nBEGIN-VENDOR Wide\nATTRIBUTE Wide-Attr 0x1FF string\nEND-VENDOR Wide").unwrap();
+        assert_eq!("Wide-Attr", dict.vendor_attribute_by_code(429, 511).unwrap().name());
+    }
+
+    #[test]
+    fn test_undefined_vendor() {
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        assert!(parser.parse_str("BEGIN-VENDOR Nobody\nATTRIBUTE Nobody-Attr 1 string\nEND-VENDOR Nobody").is_err());
+
+        let mut parser = DictionaryParser::new(ParseMode::Lenient);
+        parser.parse_str("BEGIN-VENDOR Nobody\nATTRIBUTE Nobody-Attr 1 string\nEND-VENDOR Nobody").unwrap();
+        assert_eq!(1, parser.warnings().len());
+
+        let dict = parser.into_dictionary();
+        assert_eq!(None, dict.attribute_by_name("Nobody-Attr").unwrap().vendor_id());
+        assert_eq!(None, dict.attribute_by_code(1));
+    }
+
+    #[test]
+    fn test_data_types() {
+        let dict_str = "ATTRIBUTE Octets-Attr 1 octets[16]\nATTRIBUTE Short-Attr 2 short\nATTRIBUTE Signed-Attr 3 signed\nATTRIBUTE Ether-Attr 4 ether\nATTRIBUTE Combo-Attr 5 combo-ip\nATTRIBUTE Tlv-Attr 6 tlv\nATTRIBUTE Ext-Attr 241 extended\nATTRIBUTE Long-Ext-Attr 245 long-extended";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        assert_eq!(&Some(SupportedAttributeTypes::Octets),       dict.attribute_by_name("Octets-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::Short),        dict.attribute_by_name("Short-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::Signed),       dict.attribute_by_name("Signed-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::Ether),        dict.attribute_by_name("Ether-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::ComboIP),      dict.attribute_by_name("Combo-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::Tlv),          dict.attribute_by_name("Tlv-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::Extended),     dict.attribute_by_name("Ext-Attr").unwrap().code_type());
+        assert_eq!(&Some(SupportedAttributeTypes::LongExtended), dict.attribute_by_name("Long-Ext-Attr").unwrap().code_type());
+    }
+
+    #[test]
+    fn test_attribute_flags() {
+        let dict_str = "ATTRIBUTE User-Password 2 string encrypt=1\nATTRIBUTE Tunnel-Password 69 string has_tag,encrypt=2\nATTRIBUTE EAP-Message 79 octets concat\nATTRIBUTE Some-Addresses 200 ipaddr array\nATTRIBUTE User-Name 1 string";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        assert_eq!(Some(EncryptionType::UserPassword),   dict.attribute_by_name("User-Password").unwrap().flags().encrypt());
+        assert_eq!(Some(EncryptionType::TunnelPassword), dict.attribute_by_name("Tunnel-Password").unwrap().flags().encrypt());
+        assert!(dict.attribute_by_name("Tunnel-Password").unwrap().flags().has_tag());
+        assert!(dict.attribute_by_name("EAP-Message").unwrap().flags().concat());
+        assert!(dict.attribute_by_name("Some-Addresses").unwrap().flags().array());
+        assert_eq!(&AttributeFlags::default(),           dict.attribute_by_name("User-Name").unwrap().flags());
+
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        match parser.parse_str("ATTRIBUTE User-Password 2 string encrypt=9") {
+            Err(RadiusError::DictionaryParseError { error }) => assert_eq!("encrypt=9", error.token()),
+            _                                                => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_vendor_format() {
+        let dict = Dictionary::from_str("VENDOR USR 429 format=4,0\nVENDOR Lucent 4846 format=2,1\nVENDOR WiMAX 24757 format=1,1,c\nVENDOR Cisco 9").unwrap();
+
+        let usr_format = dict.vendor_by_name("USR").unwrap().format();
+        assert_eq!((4, 0, false), (usr_format.type_width(), usr_format.length_width(), usr_format.continuation()));
+
+        let lucent_format = dict.vendor_by_name("Lucent").unwrap().format();
+        assert_eq!((2, 1, false), (lucent_format.type_width(), lucent_format.length_width(), lucent_format.continuation()));
+
+        let wimax_format = dict.vendor_by_name("WiMAX").unwrap().format();
+        assert_eq!((1, 1, true),  (wimax_format.type_width(), wimax_format.length_width(), wimax_format.continuation()));
+
+        assert_eq!(&VendorFormat::default(), dict.vendor_by_name("Cisco").unwrap().format());
+
+        for invalid_format in &["format=3,1", "format=1,3", "format=2,1,c", "format=1"] {
+            match Dictionary::from_str(&format!("VENDOR Broken 1 {}", invalid_format)) {
+                Err(RadiusError::DictionaryParseError { error }) => assert_eq!(*invalid_format, error.token()),
+                _                                                => unreachable!()
+            }
+        }
+    }
+
+    #[test]
+    fn test_tlv_attributes() {
+        let dict_str = "ATTRIBUTE Test-TLV 200 tlv\nBEGIN-TLV Test-TLV\nATTRIBUTE Test-TLV-Name 1 string\nATTRIBUTE Test-TLV-Nested 2 tlv\nEND-TLV Test-TLV\nATTRIBUTE Test-TLV-Nested-Number 200.2.1 integer";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let nested_number = dict.attribute_by_name("Test-TLV-Nested-Number").unwrap();
+        assert_eq!(1,                         nested_number.code());
+        assert_eq!(&[200, 2],                 nested_number.parent_oid());
+        assert_eq!(vec![200, 2, 1],           nested_number.oid());
+        assert_eq!("Test-TLV-Name",           dict.attribute_by_oid(None, &[200, 1]).unwrap().name());
+        assert_eq!("Test-TLV",                dict.attribute_by_oid(None, &[200]).unwrap().name());
+        assert_eq!(None,                      dict.attribute_by_code(1));
+
+        let children: Vec<&str> = dict.child_attributes(dict.attribute_by_name("Test-TLV").unwrap()).iter().map(|attr| attr.name()).collect();
+        assert_eq!(vec!["Test-TLV-Name", "Test-TLV-Nested"], children);
+
+        for broken_dict_str in &["ATTRIBUTE Test-TLV 200 tlv\nBEGIN-TLV Test-TLV\nEND-TLV Other-TLV", "ATTRIBUTE User-Name 1 string\nBEGIN-TLV User-Name", "BEGIN-TLV Test-TLV"] {
+            assert!(Dictionary::from_str(broken_dict_str).is_err());
+        }
+
+        let mut parser = DictionaryParser::new(ParseMode::Strict);
+        match parser.parse_str("ATTRIBUTE Orphan 201.1 string") {
+            Err(RadiusError::DictionaryParseError { error }) => assert_eq!("201.1", error.token()),
+            _                                                => unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_evs_attributes() {
+        let dict_str = "ATTRIBUTE Extended-Attribute-1 241 extended\n\
+                        ATTRIBUTE Frag-Status 241.1 integer\n\
+                        ATTRIBUTE Extended-Vendor-Specific-1 241.26 evs\n\
+                        VENDOR Somevendor 10\n\
+                        BEGIN-VENDOR Somevendor format=Extended-Vendor-Specific-1\n\
+                        ATTRIBUTE Somevendor-Ext-Name 1 string\n\
+                        END-VENDOR Somevendor\n\
+                        BEGIN-VENDOR Somevendor\n\
+                        ATTRIBUTE Somevendor-Name 1 string\n\
+                        END-VENDOR Somevendor\n";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let evs_attr = dict.attribute_by_name("Somevendor-Ext-Name").unwrap();
+        assert_eq!(Some(10),                     evs_attr.vendor_id());
+        assert_eq!(vec![241, 26, 1],             evs_attr.oid());
+        assert_eq!("Extended-Vendor-Specific-1", dict.evs_attribute(evs_attr).unwrap().name());
+        assert_eq!(None,                         dict.evs_attribute(dict.attribute_by_name("Somevendor-Name").unwrap()));
+        assert_eq!(None,                         dict.evs_attribute(dict.attribute_by_name("Frag-Status").unwrap()));
+        assert_eq!("Somevendor-Name",            dict.vendor_attribute_by_code(10, 1).unwrap().name());
+
+        assert_eq!(dict, Dictionary::from_str(&dict.to_string()).unwrap());
+        assert!(dict.to_string().contains("BEGIN-VENDOR\tSomevendor\tformat=Extended-Vendor-Specific-1\nATTRIBUTE\tSomevendor-Ext-Name\t1\tstring\n"));
+
+        for broken_dict_str in &["VENDOR Somevendor 10\nBEGIN-VENDOR Somevendor format=Extended-Vendor-Specific-1", "ATTRIBUTE Extended-Attribute-1 241 extended\nVENDOR Somevendor 10\nBEGIN-VENDOR Somevendor format=Extended-Attribute-1"] {
+            assert!(Dictionary::from_str(broken_dict_str).is_err());
+        }
     }
 }
@@ -2,6 +2,7 @@
 //! states
 
 
+use std::fmt;
 use thiserror::Error;
 
 // TODO - https://rust-lang.github.io/api-guidelines/naming.html#c-word-order
@@ -26,6 +27,12 @@ pub enum RadiusError {
         /// Error definition received from crate
         error: String
     },
+    /// Error happens, when attribute name matches more than one dictionary attribute
+    #[error("Attribute name is ambiguous: {error}")]
+    AmbiguousAttributeError {
+        /// Error definition received from crate
+        error: String
+    },
     /// Error happens, when IPv6 Address was badly added to Radius Packet or got corrupted
     #[error("Provided IPv6 address is malformed")]
     MalformedIpAddrError    {
@@ -51,6 +58,18 @@ pub enum RadiusError {
         /// Error definition received from crate
         error: std::io::Error
     },
+    /// Error happens, when dictionary content cannot be parsed
+    #[error("Dictionary is malformed: {error}")]
+    DictionaryParseError         {
+        /// Location and reason of the failure
+        error: DictionaryError
+    },
+    /// Error happens, when dictionary fails [validation](crate::protocol::dictionary::Dictionary::validate)
+    #[error("Dictionary failed validation with {} problem(s)", .errors.len())]
+    DictionaryValidationError    {
+        /// Every problem found in dictionary
+        errors: Vec<DictionaryError>
+    },
     /// Error happens, when wrong RADIUS Code is supplied
     #[error("Supplied RADIUS Code is not supported by this library")]
     UnsupportedTypeCodeError     {
@@ -58,3 +77,54 @@ pub enum RadiusError {
         error: String
     },
 }
+
+
+#[derive(Debug, Clone, PartialEq)]
+/// Describes a problem found on a specific line of RADIUS dictionary
+///
+/// Returned as part of [DictionaryParseError](RadiusError::DictionaryParseError) and collected
+/// as a warning, when dictionary is parsed in lenient mode
+pub struct DictionaryError {
+    file:   String,
+    line:   usize,
+    token:  String,
+    reason: String
+}
+
+impl DictionaryError {
+    /// Creates DictionaryError for given file name, line number (starting from 1) and token
+    pub fn new(file: &str, line: usize, token: &str, reason: &str) -> DictionaryError {
+        DictionaryError {
+            file:   file.to_string(),
+            line,
+            token:  token.to_string(),
+            reason: reason.to_string()
+        }
+    }
+
+    /// Returns name of the file (or source), where problem was found
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns line number (starting from 1), where problem was found
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns offending token
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns description of the problem
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {} (token: {:?})", self.file, self.line, self.reason, self.token)
+    }
+}
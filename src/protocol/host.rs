@@ -1,33 +1,39 @@
 //! Shared base for RADIUS Client & Server implementations
 
 
-use super::dictionary::{ Dictionary, DictionaryAttribute, DictionaryValue };
+use super::dictionary::{ Dictionary, DictionaryAttribute, DictionaryValue, SharedDictionary };
 use super::error::RadiusError;
-use super::radius_packet::{ RadiusAttribute, RadiusMsgType, RadiusPacket, TypeCode };
+use super::radius_packet::{ DecodeMode, RadiusAttribute, RadiusMsgType, RadiusPacket, RadiusPacketRef, TypeCode };
 
-use crypto::hmac::Hmac;
-use crypto::mac::Mac;
-use crypto::md5::Md5;
+use std::sync::Arc;
 
 
 #[derive(Debug)]
 /// Generic struct that holds Server & Client common functions and attributes
 pub struct Host {
-    auth_port:  u16,
-    acct_port:  u16,
-    coa_port:   u16,
-    dictionary: Dictionary
+    auth_port:   u16,
+    acct_port:   u16,
+    coa_port:    u16,
+    dictionary:  SharedDictionary,
+    decode_mode: DecodeMode
 }
 
 impl Host{
     /// Initialises host instance only with Dictionary (ports should be set through *set_port()*,
     /// otherwise default to 0)
     pub fn with_dictionary(dictionary: Dictionary) -> Host {
+        Host::with_shared_dictionary(SharedDictionary::new(dictionary))
+    }
+
+    /// Initialises host instance only with SharedDictionary (ports should be set through
+    /// *set_port()*, otherwise default to 0)
+    pub fn with_shared_dictionary(dictionary: SharedDictionary) -> Host {
         Host {
-            auth_port:  0,
-            acct_port:  0,
-            coa_port:   0,
-            dictionary: dictionary
+            auth_port:   0,
+            acct_port:   0,
+            coa_port:    0,
+            dictionary,
+            decode_mode: DecodeMode::Strict
         }
     }
 
@@ -43,18 +49,38 @@ impl Host{
     #[allow(dead_code)]
     /// Initialises host instance with all required fields
     pub fn initialise_host(auth_port: u16, acct_port: u16, coa_port: u16, dictionary: Dictionary) -> Host {
-        Host { auth_port, acct_port, coa_port, dictionary }
+        Host { auth_port, acct_port, coa_port, dictionary: SharedDictionary::new(dictionary), decode_mode: DecodeMode::Strict }
     }
 
 
-    /// Creates RadiusAttribute with given name (name is checked against Dictionary)
+    /// Creates RadiusAttribute with given name (name is resolved against Dictionary)
     pub fn create_attribute_by_name(&self, attribute_name: &str, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
-        RadiusAttribute::create_by_name(&self.dictionary, attribute_name, value).ok_or(RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", attribute_name) })
+        RadiusAttribute::try_create_by_name(&self.dictionary_snapshot(), attribute_name, value)
     }
 
     /// Creates RadiusAttribute with given id (id is checked against Dictionary)
     pub fn create_attribute_by_id(&self, attribute_id: u8, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
-        RadiusAttribute::create_by_id(&self.dictionary, attribute_id, value).ok_or(RadiusError::MalformedAttributeError { error: format!("Failed to create: attribute with ID {}. Check if attribute exists in provided dictionary file", attribute_id) })
+        RadiusAttribute::create_by_id(&self.dictionary_snapshot(), attribute_id, value).ok_or(RadiusError::MalformedAttributeError { error: format!("Failed to create: attribute with ID {}. Check if attribute exists in provided dictionary file", attribute_id) })
+    }
+
+    /// Creates RadiusAttribute with given name, that holds VALUE with given name (both are checked
+    /// against Dictionary)
+    pub fn create_attribute_by_value_name(&self, attribute_name: &str, value_name: &str) -> Result<RadiusAttribute, RadiusError> {
+        RadiusAttribute::create_by_value_name(&self.dictionary_snapshot(), attribute_name, value_name).ok_or_else(|| match self.dictionary_value_by_attr_and_value_name(attribute_name, value_name) {
+            Some(_) => RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute with {:?} value. Check if attribute is of integer data type", attribute_name, value_name) },
+            None    => RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute with {:?} value. Check if attribute and its VALUE exist in provided dictionary file", attribute_name, value_name) }
+        })
+    }
+
+    /// Creates RadiusAttribute with given name & tag (name is resolved against Dictionary and
+    /// ATTRIBUTE has to be flagged with **has_tag**)
+    pub fn create_tagged_attribute_by_name(&self, attribute_name: &str, tag: u8, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
+        RadiusAttribute::create_tagged_by_name(&self.dictionary_snapshot(), attribute_name, tag, value).ok_or(RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute with tag {}. Check if attribute exists in provided dictionary file, is flagged with has_tag, tag is not greater than 0x1F and integer value fits into 3 bytes", attribute_name, tag) })
+    }
+
+    /// Returns name of the VALUE, that RadiusAttribute value represents
+    pub fn attribute_value_name(&self, attribute: &RadiusAttribute) -> Result<Option<String>, RadiusError> {
+        Ok(attribute.value_name(&self.dictionary_snapshot())?.map(str::to_string))
     }
 
     /// Returns port of RADIUS server, that receives given type of RADIUS message/packet
@@ -67,66 +93,95 @@ impl Host{
         }
     }
 
-    /// Returns host's dictionary instance
-    pub fn dictionary(&self) -> &Dictionary {
+    /// Returns host's dictionary handle
+    pub fn dictionary(&self) -> &SharedDictionary {
         &self.dictionary
     }
 
-    #[allow(dead_code)]
+    /// Returns snapshot of host's dictionary
+    pub fn dictionary_snapshot(&self) -> Arc<Dictionary> {
+        self.dictionary.snapshot()
+    }
+
     /// Returns VALUE from dictionary with given attribute & value name
-    pub fn dictionary_value_by_attr_and_value_name(&self, attr_name: &str, value_name: &str) -> Option<&DictionaryValue> {
-        self.dictionary.values().iter().find(|&value| value.name() == value_name && value.attribute_name() == attr_name)
+    pub fn dictionary_value_by_attr_and_value_name(&self, attr_name: &str, value_name: &str) -> Option<DictionaryValue> {
+        self.dictionary_snapshot().value_by_name(attr_name, value_name).cloned()
     }
 
+    /// Returns ATTRIBUTE from dictionary, that RadiusAttribute represents
+    ///
+    /// Vendor attributes are looked up by their vendor id & code, so they are not confused with
+    /// standard attributes, that share the same code
+    pub fn dictionary_attribute(&self, attribute: &RadiusAttribute) -> Option<DictionaryAttribute> {
+        self.dictionary_snapshot().attribute_by_oid(attribute.vendor_id(), &attribute.oid()).cloned()
+    }
+
+    #[allow(dead_code)]
     /// Returns ATTRIBUTE from dictionary with given id
-    pub fn dictionary_attribute_by_id(&self, packet_attr_id: u8) -> Option<&DictionaryAttribute> {
-        self.dictionary.attributes().iter().find(|&attr| attr.code() == packet_attr_id.to_string())
+    pub fn dictionary_attribute_by_id(&self, packet_attr_id: u8) -> Option<DictionaryAttribute> {
+        self.dictionary_snapshot().attribute_by_code(packet_attr_id).cloned()
     }
 
     #[allow(dead_code)]
     /// Returns ATTRIBUTE from dictionary with given name
-    pub fn dictionary_attribute_by_name(&self, packet_attr_name: &str) -> Option<&DictionaryAttribute> {
-        self.dictionary.attributes().iter().find(|&attr| attr.name() == packet_attr_name)
+    pub fn dictionary_attribute_by_name(&self, packet_attr_name: &str) -> Option<DictionaryAttribute> {
+        self.dictionary_snapshot().attribute_by_name(packet_attr_name).cloned()
+    }
+
+    /// Sets how attributes, that are not defined in dictionary, are treated when packets are
+    /// decoded
+    pub fn set_decode_mode(&mut self, decode_mode: DecodeMode) {
+        self.decode_mode = decode_mode;
     }
 
     /// Initialises RadiusPacket from bytes
     pub fn initialise_packet_from_bytes(&self, packet: &[u8]) -> Result<RadiusPacket, RadiusError> {
-        RadiusPacket::initialise_packet_from_bytes(&self.dictionary, packet)
+        RadiusPacket::initialise_packet_from_bytes_with_mode(&self.dictionary_snapshot(), packet, self.decode_mode)
+    }
+
+    /// Initialises RadiusPacket from bytes and decrypts values of attributes, that are flagged
+    /// with **encrypt=** in dictionary
+    pub fn initialise_packet_from_bytes_with_secret(&self, packet: &[u8], secret: &str, request_authenticator: Option<&[u8]>) -> Result<RadiusPacket, RadiusError> {
+        self.initialise_packet_from_bytes_with_dictionary(&self.dictionary_snapshot(), packet, secret, request_authenticator)
+    }
+
+    /// Initialises RadiusPacket from bytes with given Dictionary (ie snapshot, that is held
+    /// for the whole request/reply cycle) and decrypts values of attributes, that are flagged
+    /// with **encrypt=** in dictionary
+    pub fn initialise_packet_from_bytes_with_dictionary(&self, dictionary: &Dictionary, packet: &[u8], secret: &str, request_authenticator: Option<&[u8]>) -> Result<RadiusPacket, RadiusError> {
+        RadiusPacket::initialise_packet_from_bytes_with_secret(dictionary, packet, secret, request_authenticator, self.decode_mode)
+    }
+
+    /// Creates view of RadiusPacket from bytes without copying them
+    ///
+    /// With [DecodeMode::Strict] every attribute has to be defined in dictionary
+    pub fn parse_packet<'a>(&self, packet: &'a [u8]) -> Result<RadiusPacketRef<'a>, RadiusError> {
+        self.parse_packet_with(&self.dictionary_snapshot(), packet)
+    }
+
+    fn parse_packet_with<'a>(&self, dictionary: &Dictionary, packet: &'a [u8]) -> Result<RadiusPacketRef<'a>, RadiusError> {
+        let packet_ref = RadiusPacketRef::from_bytes(packet)?;
+        if self.decode_mode == DecodeMode::Strict {
+            packet_ref.verify_known_attributes(dictionary)?;
+        }
+        Ok(packet_ref)
     }
 
     /// Verifies that RadiusPacket attributes have valid values
     ///
     /// Note: doesn't verify Message-Authenticator attribute, because it is HMAC-MD5 hash, not an
-    /// ASCII string
+    /// ASCII string. Same goes for attributes flagged with **encrypt=** in dictionary, as their
+    /// values are not decrypted, and unknown attributes, that are kept by [DecodeMode::Lenient]
     pub fn verify_packet_attributes(&self, packet: &[u8]) -> Result<(), RadiusError> {
-        let ignore_attribute = "Message-Authenticator";
-        let _packet_tmp      = RadiusPacket::initialise_packet_from_bytes(&self.dictionary, &packet)?;
-
-        for packet_attr in _packet_tmp.attributes().iter().filter(|&attr| attr.name() != ignore_attribute) {
-            let _dict_attr           = self.dictionary_attribute_by_id(packet_attr.id()).unwrap();
-            let _dict_attr_data_type = _dict_attr.code_type();
+        // The same snapshot is used for the whole check, even if dictionary is reloaded meanwhile
+        let dictionary = self.dictionary_snapshot();
 
-            match packet_attr.verify_original_value(_dict_attr_data_type) {
-                Err(err) => return Err( RadiusError::ValidationError {error: err.to_string()} ),
-                _        => continue
-            }
-        }
-        Ok(())
+        self.parse_packet_with(&dictionary, packet)?.verify_attributes(&dictionary)
     }
 
     /// Verifies Message-Authenticator value
     pub fn verify_message_authenticator(&self, secret: &str, packet: &[u8]) -> Result<(), RadiusError> {
-        let _packet_tmp     = RadiusPacket::initialise_packet_from_bytes(&self.dictionary, &packet)?;
-        let packet_msg_auth = _packet_tmp.message_authenticator()?;
-
-        let mut hash = Hmac::new(Md5::new(), secret.as_bytes());
-        hash.input(&packet);
-
-        if hash.result().code() == packet_msg_auth {
-            Ok(())
-        } else {
-            Err( RadiusError::ValidationError {error: String::from("Packet Message-Authenticator mismatch")} )
-        }
+        self.parse_packet(packet)?.verify_message_authenticator(secret)
     }
 }
 
@@ -145,7 +200,7 @@ mod tests {
 
         assert_eq!("Service-Type", dict_value.attribute_name());
         assert_eq!("Login-User",   dict_value.name());
-        assert_eq!("1",            dict_value.value());
+        assert_eq!(1,              dict_value.value());
     }
 
     #[test]
@@ -157,6 +212,35 @@ mod tests {
         assert_eq!(None, dict_value);
     }
 
+    #[test]
+    fn test_dictionary_handle_and_snapshot() {
+        let dictionary = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+        let host       = Host::with_dictionary(dictionary);
+
+        let snapshot = host.dictionary_snapshot();
+        host.dictionary().reload_from_file("./dict_examples/integration_dict").unwrap();
+
+        assert!(snapshot.attribute_by_name("Test-IP").is_some());
+        assert!(host.dictionary_snapshot().attribute_by_name("Test-IP").is_none());
+        assert!(host.dictionary_attribute_by_name("Acct-Session-Time").is_some());
+    }
+
+    #[test]
+    fn test_create_attribute_by_value_name_error() {
+        let dictionary = Dictionary::from_str("ATTRIBUTE Service-Type 6 integer\nATTRIBUTE Login-Name 200 string\nVALUE Service-Type Login-User 1\nVALUE Login-Name Admin 1\n").unwrap();
+        let host       = Host::with_dictionary(dictionary);
+
+        match host.create_attribute_by_value_name("Service-Type", "Lin-User") {
+            Err(RadiusError::MalformedAttributeError { error }) => assert!(error.ends_with("Check if attribute and its VALUE exist in provided dictionary file")),
+            _                                                   => unreachable!()
+        }
+        match host.create_attribute_by_value_name("Login-Name", "Admin") {
+            Err(RadiusError::MalformedAttributeError { error }) => assert!(error.ends_with("Check if attribute is of integer data type")),
+            _                                                   => unreachable!()
+        }
+        assert!(host.create_attribute_by_value_name("Service-Type", "Login-User").is_ok());
+    }
+
     #[test]
     fn test_get_dictionary_attribute_by_id() {
         let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
@@ -164,9 +248,9 @@ mod tests {
 
         let dict_attr = host.dictionary_attribute_by_id(80).unwrap();
 
-        assert_eq!("Message-Authenticator",                    dict_attr.name());
-        assert_eq!("80",                                       dict_attr.code());
-        assert_eq!(&Some(SupportedAttributeTypes::AsciiString), dict_attr.code_type());
+        assert_eq!("Message-Authenticator",               dict_attr.name());
+        assert_eq!(80,                                    dict_attr.code());
+        assert_eq!(&Some(SupportedAttributeTypes::Octets), dict_attr.code_type());
     }
 
     #[test]
@@ -183,7 +267,7 @@ mod tests {
         let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
         let host       = Host::initialise_host(1812, 1813, 3799, dictionary);
 
-        let packet_bytes = [4, 43, 0, 83, 215, 189, 213, 172, 57, 94, 141, 70, 134, 121, 101, 57, 187, 220, 227, 73, 4, 6, 192, 168, 1, 10, 5, 6, 0, 0, 0, 0, 32, 10, 116, 114, 105, 108, 108, 105, 97, 110, 30, 19, 48, 48, 45, 48, 52, 45, 53, 70, 45, 48, 48, 45, 48, 70, 45, 68, 49, 31, 19, 48, 48, 45, 48, 49, 45, 50, 52, 45, 56, 48, 45, 66, 51, 45, 57, 67, 8, 6, 10, 0, 0, 100];
+        let packet_bytes = [4, 43, 0, 86, 215, 189, 213, 172, 57, 94, 141, 70, 134, 121, 101, 57, 187, 220, 227, 73, 4, 6, 192, 168, 1, 10, 5, 6, 0, 0, 0, 0, 32, 10, 116, 114, 105, 108, 108, 105, 97, 110, 30, 19, 48, 48, 45, 48, 52, 45, 53, 70, 45, 48, 48, 45, 48, 70, 45, 68, 49, 31, 19, 48, 48, 45, 48, 49, 45, 50, 52, 45, 56, 48, 45, 66, 51, 45, 57, 67, 8, 6, 10, 0, 0, 100];
 
         match host.verify_packet_attributes(&packet_bytes) {
             Err(err) => {
@@ -7,9 +7,14 @@
 //! Client to RADIUS Server and/or RADIUS Server to RADIUS Client
 //!
 //! `error` module - represents custom errors defined for `radius-rust` crate
+//!
+//! `std_dictionaries` module - standard RFC dictionaries embedded into the crate (only available
+//! with `std-dictionaries` feature)
 
 
 pub mod dictionary;
 pub mod radius_packet;
 pub(crate) mod host;
 pub mod error;
+#[cfg(feature = "std-dictionaries")]
+pub mod std_dictionaries;
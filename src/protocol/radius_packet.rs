@@ -1,13 +1,38 @@
 //! RADIUS Packet implementation
 
 
-use super::dictionary::{ Dictionary, SupportedAttributeTypes };
+use super::dictionary::{ AttributeFlags, Dictionary, DictionaryAttribute, DictionaryVendor, EncryptionType, SupportedAttributeTypes, VendorFormat };
 use super::error::RadiusError;
-use crate::tools::{ bytes_to_integer, bytes_to_timestamp, bytes_to_ipv4_string, bytes_to_ipv6_string };
-
+use crate::tools::{
+    ascend_decrypt_data,
+    ascend_encrypt_data,
+    bytes_to_combo_ip_string,
+    bytes_to_ether_string,
+    bytes_to_ifid_string,
+    bytes_to_integer,
+    bytes_to_integer64,
+    bytes_to_ipv4_prefix_string,
+    bytes_to_ipv4_string,
+    bytes_to_ipv6_string,
+    bytes_to_short,
+    bytes_to_signed,
+    bytes_to_timestamp,
+    byte_to_bytes,
+    decrypt_data,
+    encrypt_data,
+    integer64_to_bytes,
+    integer_to_bytes,
+    salt_decrypt_data,
+    salt_encrypt_data,
+    short_to_bytes
+};
+
+use crypto::hmac::Hmac;
+use crypto::mac::Mac;
+use crypto::md5::Md5;
 use rand::Rng;
 
-use std::convert::TryInto;
+use std::convert::{ TryFrom, TryInto };
 use std::fmt;
 
 
@@ -114,43 +139,240 @@ impl TypeCode {
 }
 
 
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// Defines how RadiusPacket decoder treats attributes, that are not defined in dictionary
+///
+/// Malformed packets (wrong lengths, truncated attributes) are always rejected
+pub enum DecodeMode {
+    /// Packet with unknown attribute is rejected with an error
+    #[default]
+    Strict,
+    /// Unknown attributes (and unknown TLV children) are kept as raw octets with their numeric id,
+    /// see [RadiusAttribute::create_unknown]. TLV or struct value, that could not be decoded, is
+    /// kept as raw octets of its attribute
+    Lenient
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Defines how RadiusAttribute is carried inside RadiusPacket
+enum AttributeSpace {
+    /// Attribute (or TLV child) is written as is
+    Standard,
+    /// Vendor attribute is wrapped into Vendor-Specific attribute, RFC 2865 section 5.26
+    Vendor(VendorFormat),
+    /// Attribute is wrapped into Extended-Attribute-1..4, RFC 6929 section 2.1
+    Extended,
+    /// Attribute is wrapped into Extended-Attribute-5..6 and fragmented, if its value does not
+    /// fit into single attribute, RFC 6929 section 2.2
+    LongExtended
+}
+
 #[derive(Debug, PartialEq)]
 /// Represents an attribute, which would be sent to RADIUS Server/client as a part of RadiusPacket
+///
+/// Vendor attributes (ATTRIBUTEs defined inside `BEGIN-VENDOR` block) are sent inside
+/// Vendor-Specific attribute, so their id is 26, while their vendor type is available via
+/// [code](RadiusAttribute::code) and vendor via [vendor_id](RadiusAttribute::vendor_id)
+///
+/// Attributes of RFC 6929 extended spaces (ie `241.1`) have id of their Extended-Attribute (241
+/// to 246), while their Extended-Type (or Vendor-Type, if attribute is carried inside
+/// Extended-Vendor-Specific attribute) is available via [code](RadiusAttribute::code)
 pub struct RadiusAttribute {
-    id:    u8,
-    name:  String,
-    value: Vec<u8>
+    id:        u8,
+    code:      u32,
+    vendor_id: Option<u32>,
+    space:     AttributeSpace,
+    name:      String,
+    value:     Vec<u8>,
+    flags:     AttributeFlags,
+    children:  Vec<RadiusAttribute>,
+    unknown:   bool
 }
 
 impl RadiusAttribute {
     /// Creates RadiusAttribute with given name
     ///
-    /// Returns None, if ATTRIBUTE with such name is not found in Dictionary
+    /// Name is resolved with [Dictionary::resolve_attribute], so it could also be given in
+    /// different case or qualified with vendor (ie `Cisco.AVPair`).
+    /// Returns None, if ATTRIBUTE with such name is not found in Dictionary or name is ambiguous
     pub fn create_by_name(dictionary: &Dictionary, attribute_name: &str, value: Vec<u8>) -> Option<RadiusAttribute> {
-        match dictionary.attributes().iter().find(|&attr| attr.name() == attribute_name) {
-            Some(attr) => Some(RadiusAttribute {
-                id:    attr.code().parse::<u8>().unwrap(),
-                name:  attr.name().to_string(),
-                value: value
-            }),
-            _          => None
-        }
+        RadiusAttribute::try_create_by_name(dictionary, attribute_name, value).ok()
+    }
+
+    /// Creates RadiusAttribute with given name
+    ///
+    /// Same as [create_by_name](RadiusAttribute::create_by_name), but returns an error,
+    /// explaining why attribute could not be created
+    pub fn try_create_by_name(dictionary: &Dictionary, attribute_name: &str, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
+        let attr = dictionary.resolve_attribute(attribute_name)?;
+
+        RadiusAttribute::from_dictionary_attribute(dictionary, attr, value).ok_or(RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute, its code does not fit into attribute type", attribute_name) })
     }
 
     /// Creates RadiusAttribute with given id
     ///
     /// Returns None, if ATTRIBUTE with such id is not found in Dictionary
     pub fn create_by_id(dictionary: &Dictionary, attribute_code: u8, value: Vec<u8>) -> Option<RadiusAttribute> {
-        match dictionary.attributes().iter().find(|&attr| attr.code() == attribute_code.to_string()) {
-            Some(attr) => Some(RadiusAttribute {
-                id:    attribute_code,
-                name:  attr.name().to_string(),
-                value: value
-            }),
-            _          => None
+        dictionary.attribute_by_code(attribute_code).and_then(|attr| RadiusAttribute::from_dictionary_attribute(dictionary, attr, value))
+    }
+
+    /// Creates TLV RadiusAttribute with given name, which holds given children
+    ///
+    /// Returns None, if ATTRIBUTE with such name is not found in Dictionary, is not of tlv data
+    /// type, any of the children is not defined as its child attribute in Dictionary or value of
+    /// any of the children does not fit into single TLV
+    pub fn create_tlv_by_name(dictionary: &Dictionary, attribute_name: &str, children: Vec<RadiusAttribute>) -> Option<RadiusAttribute> {
+        let attr = dictionary.resolve_attribute(attribute_name).ok()?;
+        if attr.code_type() != &Some(SupportedAttributeTypes::Tlv) {
+            return None
+        }
+
+        let mut oid = attr.oid();
+        oid.push(0);
+        for child in children.iter() {
+            *oid.last_mut()? = u32::from(child.id);
+            if dictionary.attribute_by_oid(attr.vendor_id(), &oid)?.name() != child.name {
+                return None
+            }
+        }
+
+        let tlv_value: Vec<Vec<u8>> = children.iter().map(|child| child.tlv_bytes()).collect::<Result<_, _>>().ok()?;
+        let mut tlv_attr            = RadiusAttribute::from_dictionary_attribute(dictionary, attr, tlv_value.concat())?;
+        tlv_attr.children = children;
+        Some(tlv_attr)
+    }
+
+    /// Creates RadiusAttribute with given name, which holds value of the VALUE with given name
+    /// (ie `Service-Type` & `Framed-User`)
+    ///
+    /// Returns None, if ATTRIBUTE or its VALUE with such name is not found in Dictionary, or
+    /// ATTRIBUTE is not of integer, byte, short or integer64 data type
+    pub fn create_by_value_name(dictionary: &Dictionary, attribute_name: &str, value_name: &str) -> Option<RadiusAttribute> {
+        let attr  = dictionary.resolve_attribute(attribute_name).ok()?;
+        let value = dictionary.value_by_name(attr.name(), value_name)?.value();
+
+        let value = match attr.code_type() {
+            Some(SupportedAttributeTypes::Integer)   => integer_to_bytes(u32::try_from(value).ok()?),
+            Some(SupportedAttributeTypes::Byte)      => byte_to_bytes(u8::try_from(value).ok()?),
+            Some(SupportedAttributeTypes::Short)     => short_to_bytes(u16::try_from(value).ok()?),
+            Some(SupportedAttributeTypes::Integer64) => integer64_to_bytes(value),
+            _                                        => return None
+        };
+        RadiusAttribute::from_dictionary_attribute(dictionary, attr, value)
+    }
+
+    /// Creates RadiusAttribute with given name & tag, for ATTRIBUTEs flagged with **has_tag**
+    /// (ie `Tunnel-Type` or `Tunnel-Private-Group-Id`), RFC 2868 section 3
+    ///
+    /// Tag of integer attribute takes the first byte of 4 bytes long value, so value has to fit
+    /// into 3 bytes. Tag of any other attribute (including Tunnel-Password, that is encrypted
+    /// when packet is converted into bytes) is put in front of the value
+    ///
+    /// Returns None, if ATTRIBUTE with such name is not found in Dictionary or is not flagged with
+    /// **has_tag**, tag is greater than 0x1F or value does not fit next to the tag
+    pub fn create_tagged_by_name(dictionary: &Dictionary, attribute_name: &str, tag: u8, value: Vec<u8>) -> Option<RadiusAttribute> {
+        let attr = dictionary.resolve_attribute(attribute_name).ok()?;
+        if !attr.flags().has_tag() || tag > MAX_TAG {
+            return None
+        }
+
+        let value = match (attr.code_type(), value.as_slice()) {
+            (Some(SupportedAttributeTypes::Integer), [0, rest @ ..]) if rest.len() == 3 => [ &[tag], rest ].concat(),
+            (Some(SupportedAttributeTypes::Integer), _)                                  => return None,
+            _                                                                            => [ &[tag], value.as_slice() ].concat()
+        };
+        RadiusAttribute::from_dictionary_attribute(dictionary, attr, value)
+    }
+
+    /// Creates struct RadiusAttribute with given name, which holds given MEMBERs (and STRUCT, that
+    /// is selected by key MEMBER, if any)
+    ///
+    /// MEMBERs should be given in the order they are defined in. Returns None, if ATTRIBUTE with
+    /// such name is not found in Dictionary, is not of struct data type or any of the members is
+    /// not defined inside it in Dictionary
+    pub fn create_struct_by_name(dictionary: &Dictionary, attribute_name: &str, members: Vec<RadiusAttribute>) -> Option<RadiusAttribute> {
+        let attr = dictionary.resolve_attribute(attribute_name).ok()?;
+        if attr.code_type() != &Some(SupportedAttributeTypes::Struct) {
+            return None
+        }
+
+        let oid = attr.oid();
+        for member in members.iter() {
+            let dict_member = dictionary.attribute_by_name(&member.name)?;
+            if dict_member.vendor_id() != attr.vendor_id() || !dict_member.parent_oid().starts_with(&oid) {
+                return None
+            }
+        }
+
+        let mut struct_attr = RadiusAttribute::from_dictionary_attribute(dictionary, attr, members.iter().flat_map(|member| member.value.to_vec()).collect())?;
+        struct_attr.children = members;
+        Some(struct_attr)
+    }
+
+    /// Creates RadiusAttribute with given id, that is not defined in dictionary
+    ///
+    /// Value is kept as raw octets and attribute is named after its id (ie `Attr-26`), so it could
+    /// still be inspected or sent back as is
+    pub fn create_unknown(attribute_code: u8, value: Vec<u8>) -> RadiusAttribute {
+        RadiusAttribute::create_unknown_in_space(AttributeSpace::Standard, attribute_code, None, u32::from(attribute_code), value)
+    }
+
+    /// Attribute, that is carried inside Vendor-Specific or Extended-Attribute and is not defined
+    /// in dictionary, is named after its numeric path (ie `Attr-26.9.1`, `Attr-241.1` or
+    /// `Attr-241.26.9.1`)
+    fn create_unknown_in_space(space: AttributeSpace, id: u8, vendor_id: Option<u32>, code: u32, value: Vec<u8>) -> RadiusAttribute {
+        let name = match (space, vendor_id) {
+            (AttributeSpace::Standard, _)          => format!("Attr-{}", id),
+            (AttributeSpace::Vendor(_), Some(vid)) => format!("Attr-{}.{}.{}", VENDOR_SPECIFIC_ID, vid, code),
+            (_, Some(vid))                         => format!("Attr-{}.{}.{}.{}", id, EVS_TYPE, vid, code),
+            (_, None)                              => format!("Attr-{}.{}", id, code)
+        };
+
+        RadiusAttribute {
+            id,
+            code,
+            vendor_id,
+            space,
+            name,
+            value,
+            flags:     AttributeFlags::default(),
+            children:  Vec::new(),
+            unknown:   true
         }
     }
 
+    fn from_dictionary_attribute(dictionary: &Dictionary, attr: &DictionaryAttribute, value: Vec<u8>) -> Option<RadiusAttribute> {
+        let space = attribute_space(dictionary, attr);
+        let id    = match space {
+            // Top-level vendor attributes are carried inside Vendor-Specific attribute
+            AttributeSpace::Vendor(format)                           => {
+                if !fits_vendor_type(&format, attr.code()) {
+                    return None
+                }
+                VENDOR_SPECIFIC_ID
+            },
+            // Extended attributes are carried inside their Extended-Attribute, Extended-Type (or
+            // Vendor-Type) is a single byte
+            AttributeSpace::Extended | AttributeSpace::LongExtended => {
+                u8::try_from(attr.code()).ok()?;
+                u8::try_from(*attr.parent_oid().first()?).ok()?
+            },
+            AttributeSpace::Standard                                 => u8::try_from(attr.code()).ok()?
+        };
+
+        Some(RadiusAttribute {
+            id,
+            code:      attr.code(),
+            vendor_id: attr.vendor_id(),
+            space,
+            name:      attr.name().to_string(),
+            value,
+            flags:     *attr.flags(),
+            children:  Vec::new(),
+            unknown:   false
+        })
+    }
+
     /// Overriddes RadiusAttribute value
     ///
     /// Mainly used when building Message-Authenticator
@@ -158,11 +380,29 @@ impl RadiusAttribute {
         self.value = new_value
     }
 
-    /// Returns RadiusAttribute id
+    /// Returns RadiusAttribute id (type of the attribute in RadiusPacket, which is 26 for vendor
+    /// attributes)
     pub fn id(&self) -> u8 {
         self.id
     }
 
+    /// Returns RadiusAttribute code, as defined in dictionary (vendor type for vendor attributes)
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns id of the vendor, RadiusAttribute belongs to (None for standard attributes)
+    pub fn vendor_id(&self) -> Option<u32> {
+        self.vendor_id
+    }
+
+    /// Returns path of top-level RadiusAttribute in dictionary, same as
+    /// [DictionaryAttribute::oid](crate::protocol::dictionary::DictionaryAttribute::oid) (ie
+    /// `[241, 1]` for Extended-Type 1 of Extended-Attribute-1)
+    pub fn oid(&self) -> Vec<u32> {
+        attribute_oid(self.space, self.id, self.vendor_id, self.code)
+    }
+
     /// Returns RadiusAttribute value
     pub fn value(&self) -> &[u8] {
         &self.value
@@ -173,108 +413,156 @@ impl RadiusAttribute {
         &self.name
     }
 
+    /// Returns children of TLV RadiusAttribute or MEMBERs of struct RadiusAttribute (empty for
+    /// other data types)
+    pub fn children(&self) -> &[RadiusAttribute] {
+        &self.children
+    }
+
+    /// Returns child of TLV RadiusAttribute with given name
+    pub fn child_by_name(&self, name: &str) -> Option<&RadiusAttribute> {
+        self.children.iter().find(|&child| child.name() == name)
+    }
+
+    /// Returns RadiusAttribute flags, as defined in dictionary
+    pub fn flags(&self) -> &AttributeFlags {
+        &self.flags
+    }
+
+    /// Returns true, if RadiusAttribute is not defined in dictionary (was kept by
+    /// [DecodeMode::Lenient] decoder)
+    pub fn is_unknown(&self) -> bool {
+        self.unknown
+    }
+
     /// Verifies RadiusAttribute value, based on the ATTRIBUTE code type
+    ///
+    /// Value of attribute flagged with **array** is verified element by element
     pub fn verify_original_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<(), RadiusError> {
-        match allowed_type {
-            Some(SupportedAttributeTypes::AsciiString) => {
-                match String::from_utf8(self.value().to_vec()) {
-                    Ok(_) => Ok(()),
-                    _     => Err( RadiusError::MalformedAttributeError {error: String::from("invalid ASCII bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv4Addr)    => {
-                match bytes_to_ipv4_string(self.value()) {
-                    Ok(_) => Ok(()),
-                    _     => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv4 bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv6Addr)    => {
-                match bytes_to_ipv6_string(self.value()) {
-                    Ok(_) => Ok(()),
-                    _     => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv6Prefix)  => {
-                match bytes_to_ipv6_string(self.value()) {
-                    Ok(_) => Ok(()),
-                    _     => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::Integer)     => {
-                match self.value().try_into() {
-                    Ok(value) => {
-                        bytes_to_integer(value);
-                        Ok(())
-                    },
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Integer bytes")} )
-                }
-            } ,
-            Some(SupportedAttributeTypes::Date)        => {
-                match self.value().try_into() {
-                    Ok(value) => {
-                        bytes_to_timestamp(value);
-                        Ok(())
-                    },
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Date bytes")} )
-                }
-            },
-            _                                          => Err( RadiusError::MalformedAttributeError {error: String::from("unsupported attribute code type")} )
-        }
+        array_elements(&self.value, allowed_type, self.flags.array()).iter().try_for_each(|element| verify_value(element, allowed_type))
     }
 
     /// Returns RadiusAttribute value, if the attribute is dictionary's ATTRIBUTE with code type string, ipaddr,
-    /// ipv6addr or aipv6prefix
+    /// ipv6addr, ipv6prefix, ipv4prefix, combo-ip, ether or ifid
     pub fn original_string_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<String, RadiusError> {
-        match allowed_type {
-            Some(SupportedAttributeTypes::AsciiString) => {
-                match String::from_utf8(self.value().to_vec()) {
-                    Ok(value) => Ok(value),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid ASCII bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv4Addr)    => {
-                match bytes_to_ipv4_string(self.value()) {
-                    Ok(value) => Ok(value),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv4 bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv6Addr)    => {
-                match bytes_to_ipv6_string(self.value()) {
-                    Ok(value) => Ok(value),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
-                }
-            },
-            Some(SupportedAttributeTypes::IPv6Prefix)  => {
-                match bytes_to_ipv6_string(self.value()) {
-                    Ok(value) => Ok(value),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
+        string_value(&self.value, allowed_type)
+    }
+
+    /// Returns RadiusAttribute value, if the attribute is dictionary's ATTRIBUTE with code type
+    /// integer, date, byte, short or integer64
+    pub fn original_integer_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<u64, RadiusError> {
+        integer_value(&self.value, allowed_type)
+    }
+
+    /// Returns name of the VALUE, that RadiusAttribute value represents (ie `Framed-User` for
+    /// `Service-Type = 2`)
+    ///
+    /// Returns None, if no VALUE is defined for the number, and error, if attribute is not found in
+    /// Dictionary or is not of integer, byte, short or integer64 data type
+    pub fn value_name<'a>(&self, dictionary: &'a Dictionary) -> Result<Option<&'a str>, RadiusError> {
+        let attr = dictionary.attribute_by_name(&self.name).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", self.name)} )?;
+        if attr.code_type() == &Some(SupportedAttributeTypes::Date) {
+            return Err( RadiusError::MalformedAttributeError {error: String::from("not an Integer data type")} )
+        }
+
+        // Tag of tagged integer attribute is not a part of its value, RFC 2868 section 3
+        let value = match self.tag(attr.code_type()) {
+            Some(_) if attr.code_type() == &Some(SupportedAttributeTypes::Integer) => self.original_tagged_integer_value(attr.code_type())?.1,
+            _                                                                       => self.original_integer_value(attr.code_type())?
+        };
+        Ok(dictionary.value_by_number(&self.name, value).map(|value| value.name()))
+    }
+
+    /// Returns RadiusAttribute value, if the attribute is dictionary's ATTRIBUTE with code type
+    /// signed
+    pub fn original_signed_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<i32, RadiusError> {
+        signed_value(&self.value, allowed_type)
+    }
+
+    /// Returns tag of RadiusAttribute, if it is flagged with **has_tag**, RFC 2868 section 3
+    ///
+    /// Integer attributes and Tunnel-Password always carry a tag, while string attributes only
+    /// carry it, if their first byte is not greater than 0x1F (otherwise that byte is the
+    /// first byte of the string and attribute has no tag)
+    pub fn tag(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Option<u8> {
+        if !self.flags.has_tag() {
+            return None
+        }
+
+        match (allowed_type, self.value.first()) {
+            (Some(SupportedAttributeTypes::Integer), Some(tag)) => Some(*tag),
+            (_, Some(tag)) if self.flags.encrypt().is_some()    => Some(*tag),
+            (_, Some(tag)) if *tag <= MAX_TAG                   => Some(*tag),
+            _                                                   => None
+        }
+    }
+
+    /// Returns tag & value of RadiusAttribute, if the attribute is dictionary's ATTRIBUTE with
+    /// code type integer, that is flagged with **has_tag**
+    pub fn original_tagged_integer_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<(u8, u64), RadiusError> {
+        match (allowed_type, self.tag(allowed_type)) {
+            (Some(SupportedAttributeTypes::Integer), Some(tag)) => Ok((tag, integer_value(&[ &[0], &self.value[1..] ].concat(), allowed_type)?)),
+            _                                                   => Err( RadiusError::MalformedAttributeError {error: String::from("not a tagged Integer data type")} )
+        }
+    }
+
+    /// Returns tag (if any) & value of RadiusAttribute as a String, if the attribute is
+    /// dictionary's ATTRIBUTE, that is flagged with **has_tag**
+    pub fn original_tagged_string_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<(Option<u8>, String), RadiusError> {
+        if !self.flags.has_tag() {
+            return Err( RadiusError::MalformedAttributeError {error: String::from("not a tagged attribute")} )
+        }
+
+        match self.tag(allowed_type) {
+            Some(tag) => Ok((Some(tag), string_value(&self.value[1..], allowed_type)?)),
+            None      => Ok((None, string_value(&self.value, allowed_type)?))
+        }
+    }
+
+    fn encrypt_value(&self, authenticator: &[u8], secret: &[u8], salt: [u8; 2]) -> Result<Vec<u8>, RadiusError> {
+        match self.flags.encrypt() {
+            Some(EncryptionType::UserPassword)   => Ok(encrypt_data(&self.value, authenticator, secret)),
+            Some(EncryptionType::AscendSecret)   => ascend_encrypt_data(&self.value, authenticator, secret),
+            Some(EncryptionType::TunnelPassword) => {
+                // Tag (if any) is sent in plain text in front of the salt, RFC 2868 section 3.5
+                if self.flags.has_tag() && !self.value.is_empty() {
+                    Ok([ &self.value[..1], &salt_encrypt_data(&self.value[1..], authenticator, &salt, secret) ].concat())
+                } else {
+                    Ok(salt_encrypt_data(&self.value, authenticator, &salt, secret))
                 }
             },
-            _                                          => Err( RadiusError::MalformedAttributeError {error: String::from("not a String data type")} )
+            None                                 => Ok(self.value.to_vec())
         }
     }
 
-    /// Returns RadiusAttribute value, if the attribute is dictionary's ATTRIBUTE with code type
-    /// integer of date
-    pub fn original_integer_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<u64, RadiusError> {
-        match allowed_type {
-            Some(SupportedAttributeTypes::Integer) => {
-                match self.value().try_into() {
-                    Ok(value) => Ok(bytes_to_integer(value) as u64),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Integer bytes")} )
+    fn decrypt_value(&mut self, authenticator: &[u8], secret: &[u8]) -> Result<(), RadiusError> {
+        let value = match self.flags.encrypt() {
+            Some(EncryptionType::UserPassword)   => {
+                if self.value.is_empty() || !self.value.chunks_exact(16).remainder().is_empty() {
+                    return Err( RadiusError::MalformedAttributeError {error: format!("invalid encrypted value of {} attribute", self.name)} )
                 }
-            } ,
-            Some(SupportedAttributeTypes::Date)    => {
-                match self.value().try_into() {
-                    Ok(value) => Ok(bytes_to_timestamp(value) as u64),
-                    _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Date bytes")} )
+                decrypt_data(&self.value, authenticator, secret)
+            },
+            Some(EncryptionType::AscendSecret)   => ascend_decrypt_data(&self.value, authenticator, secret)?,
+            Some(EncryptionType::TunnelPassword) => {
+                let (tag, encrypted) = if self.flags.has_tag() && !self.value.is_empty() { self.value.split_at(1) } else { self.value.split_at(0) };
+                // Salt is followed by one or more 16 bytes long blocks, RFC 2868 section 3.5
+                if encrypted.is_empty() {
+                    tag.to_vec()
+                } else if encrypted.len() < 18 || !encrypted[2..].chunks_exact(16).remainder().is_empty() {
+                    return Err( RadiusError::MalformedAttributeError {error: format!("invalid encrypted value of {} attribute", self.name)} )
+                } else {
+                    [ tag, &salt_decrypt_data(encrypted, authenticator, secret)? ].concat()
                 }
             },
-            _                                      => Err( RadiusError::MalformedAttributeError {error: String::from("not an Integer data type")} )
-        }
+            None                                 => return Ok(())
+        };
+
+        self.value = value;
+        Ok(())
     }
 
-    fn to_bytes(&self) -> Vec<u8> {
+    fn to_bytes(&self, value: &[u8]) -> Result<Vec<u8>, RadiusError> {
         /*
          *    
          *         0               1              2
@@ -284,136 +572,760 @@ impl RadiusAttribute {
            +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-
         *  Taken from https://tools.ietf.org/html/rfc2865#page-23 
         */
-        [ &[self.id], &[(2 + self.value.len()) as u8], self.value.as_slice() ].concat()
+        match (self.space, self.vendor_id) {
+            (AttributeSpace::Vendor(format), Some(vendor_id))                => self.vendor_specific_bytes(&format, vendor_id, value),
+            (AttributeSpace::Extended, _) | (AttributeSpace::LongExtended, _) => self.extended_bytes(value),
+            _                                                                 => {
+                Ok(self.value_chunks(value, MAX_ATTRIBUTE_LENGTH - 2, false)?.iter().flat_map(|chunk| [ &[self.id], &[(2 + chunk.len()) as u8], *chunk ].concat()).collect())
+            }
+        }
+    }
+
+    /// Splits value into parts, that fit into single attribute
+    ///
+    /// Only values, that are joined back when packet is decoded (attributes flagged with `concat`,
+    /// vendor attributes with continuation flag & Long-Extended attributes), are split across
+    /// consecutive attributes. Longer value of any other attribute is rejected with an error
+    fn value_chunks<'a>(&self, value: &'a [u8], max_length: usize, always_joined: bool) -> Result<Vec<&'a [u8]>, RadiusError> {
+        if value.len() > max_length && !(always_joined || self.flags.concat()) {
+            return Err( RadiusError::MalformedAttributeError {error: format!("value of {} attribute is {} bytes long, but at most {} bytes fit into attribute, which is not flagged with concat", self.name, value.len(), max_length)} )
+        }
+        Ok(value_chunks(value, max_length))
     }
-}
 
+    /// Wraps vendor attribute into Vendor-Specific attribute, RFC 2865 section 5.26
+    ///
+    /// Value, that does not fit into single Vendor-Specific attribute, is split across consecutive
+    /// ones, if vendor's format has continuation field (ie `format=1,1,c`) or attribute is flagged
+    /// with `concat`. Continuation flag is set on every part but the last one
+    fn vendor_specific_bytes(&self, format: &VendorFormat, vendor_id: u32, value: &[u8]) -> Result<Vec<u8>, RadiusError> {
+        let header_length = 6 + usize::from(format.type_width() + format.length_width()) + usize::from(format.continuation());
+        let chunks        = self.value_chunks(value, MAX_ATTRIBUTE_LENGTH - header_length, format.continuation())?;
+        let last_chunk    = chunks.len() - 1;
+
+        Ok(chunks.iter().enumerate().flat_map(|(index, chunk)| {
+            let vendor_attribute = vendor_attribute_bytes(format, self.code, chunk, index < last_chunk);
+            [ &[self.id, (6 + vendor_attribute.len()) as u8], &vendor_id.to_be_bytes()[..], vendor_attribute.as_slice() ].concat()
+        }).collect())
+    }
 
-#[derive(Debug, PartialEq)]
-/// Represents RADIUS packet
-pub struct RadiusPacket {
-    id:            u8,
-    code:          TypeCode,
-    authenticator: Vec<u8>,
-    attributes:    Vec<RadiusAttribute>
-}
+    /// Converts attribute of RFC 6929 extended space into bytes
+    ///
+    /// Value of Long-Extended attribute, that does not fit into single attribute, is split into
+    /// fragments with More flag set on every fragment but the last one, RFC 6929 section 2.2
+    fn extended_bytes(&self, value: &[u8]) -> Result<Vec<u8>, RadiusError> {
+        // Extended-Vendor-Specific attribute carries Vendor-Id & Vendor-Type in front of the value, RFC 6929 section 2.4
+        let (extended_type, vendor_header) = match self.vendor_id {
+            Some(vendor_id) => (EVS_TYPE, [ &vendor_id.to_be_bytes()[..], &[self.code as u8] ].concat()),
+            None            => (self.code as u8, Vec::new())
+        };
 
-impl RadiusPacket {
-    /// Initialises RADIUS packet with random ID and authenticator
-    pub fn initialise_packet(code: TypeCode) -> RadiusPacket {
-        RadiusPacket {
-            id:            RadiusPacket::create_id(),
-            code:          code,
-            authenticator: RadiusPacket::create_authenticator(),
-            attributes:    Vec::new()
+        if self.space == AttributeSpace::Extended {
+            let header_length = 3 + vendor_header.len();
+            return Ok(self.value_chunks(value, MAX_ATTRIBUTE_LENGTH - header_length, false)?.iter().flat_map(|chunk| {
+                [ &[self.id, (header_length + chunk.len()) as u8, extended_type], vendor_header.as_slice(), chunk ].concat()
+            }).collect())
         }
+
+        let header_length = 4 + vendor_header.len();
+        let fragments     = self.value_chunks(value, MAX_ATTRIBUTE_LENGTH - header_length, true)?;
+        let last_fragment = fragments.len() - 1;
+        Ok(fragments.iter().enumerate().flat_map(|(index, fragment)| {
+            let flags = if index < last_fragment { LONG_EXTENDED_MORE_FLAG } else { 0 };
+            [ &[self.id, (header_length + fragment.len()) as u8, extended_type, flags], vendor_header.as_slice(), fragment ].concat()
+        }).collect())
     }
 
-    /// Initialises RADIUS packet from raw bytes
-    pub fn initialise_packet_from_bytes(dictionary: &Dictionary, bytes: &[u8]) -> Result<RadiusPacket, RadiusError> {
-        let code           = TypeCode::from_u8(bytes[0])?;
-        let id             = bytes[1];
-        let authenticator  = bytes[4..20].to_vec();
-        let mut attributes = Vec::new();
+    /// Converts child of TLV RadiusAttribute into bytes, RFC 6929 section 2.3
+    ///
+    /// TLV could not be split, so value, that does not fit into single TLV, is rejected with an error
+    fn tlv_bytes(&self) -> Result<Vec<u8>, RadiusError> {
+        if self.value.len() > MAX_ATTRIBUTE_LENGTH - 2 {
+            return Err( RadiusError::MalformedAttributeError {error: format!("value of {} TLV is {} bytes long, but at most {} bytes fit into TLV", self.name, self.value.len(), MAX_ATTRIBUTE_LENGTH - 2)} )
+        }
+        Ok([ &[self.id], &[(2 + self.value.len()) as u8], self.value.as_slice() ].concat())
+    }
+}
 
-        let mut last_index = 20;
+/// Verifies attribute value, based on the ATTRIBUTE code type
+fn verify_value(value: &[u8], allowed_type: &Option<SupportedAttributeTypes>) -> Result<(), RadiusError> {
+    match allowed_type {
+        // Checked in place, so valid string does not have to be copied
+        Some(SupportedAttributeTypes::AsciiString)  => std::str::from_utf8(value).map(|_| ()).map_err(|_| RadiusError::MalformedAttributeError {error: String::from("invalid ASCII bytes")}),
+        Some(SupportedAttributeTypes::IPv4Addr)    |
+        Some(SupportedAttributeTypes::IPv6Addr)    |
+        Some(SupportedAttributeTypes::IPv6Prefix)  |
+        Some(SupportedAttributeTypes::IPv4Prefix)  |
+        Some(SupportedAttributeTypes::ComboIP)     |
+        Some(SupportedAttributeTypes::Ether)       |
+        Some(SupportedAttributeTypes::IfId)         => string_value(value, allowed_type).map(|_| ()),
+        Some(SupportedAttributeTypes::Integer)     |
+        Some(SupportedAttributeTypes::Date)        |
+        Some(SupportedAttributeTypes::Byte)        |
+        Some(SupportedAttributeTypes::Short)       |
+        Some(SupportedAttributeTypes::Integer64)    => integer_value(value, allowed_type).map(|_| ()),
+        Some(SupportedAttributeTypes::Signed)       => signed_value(value, allowed_type).map(|_| ()),
+        Some(SupportedAttributeTypes::Octets)      |
+        Some(SupportedAttributeTypes::ABinary)     |
+        Some(SupportedAttributeTypes::Struct)       => Ok(()),
+        Some(SupportedAttributeTypes::Tlv)          => verify_tlvs(value),
+        Some(SupportedAttributeTypes::Vsa)         |
+        Some(SupportedAttributeTypes::Evs)          => {
+            // Vendor-Id followed by at least one byte of vendor data, RFC 2865 section 5.26 & RFC 6929 section 2.4
+            if value.len() < 5 {
+                return Err( RadiusError::MalformedAttributeError {error: String::from("invalid Vendor-Specific bytes")} )
+            }
+            Ok(())
+        },
+        Some(SupportedAttributeTypes::Extended)     => {
+            // Extended-Type, RFC 6929 section 2.1
+            if value.is_empty() {
+                return Err( RadiusError::MalformedAttributeError {error: String::from("invalid Extended bytes")} )
+            }
+            Ok(())
+        },
+        Some(SupportedAttributeTypes::LongExtended) => {
+            // Extended-Type & flags byte, RFC 6929 section 2.2
+            if value.len() < 2 {
+                return Err( RadiusError::MalformedAttributeError {error: String::from("invalid Long-Extended bytes")} )
+            }
+            Ok(())
+        },
+        _                                           => Err( RadiusError::MalformedAttributeError {error: String::from("unsupported attribute code type")} )
+    }
+}
 
-        while last_index != bytes.len() {
-            let attr_id     = bytes[last_index];
-            let attr_length = bytes[last_index + 1] as usize;
-            let attr_value  = &bytes[(last_index + 2)..=(last_index + attr_length - 1)];
-
-            match RadiusAttribute::create_by_id(dictionary, attr_id, attr_value.to_vec()) {
-                Some(attr) => {
-                    attributes.push(attr);
-                    last_index += attr_length;
-                },
-                _          => return Err( RadiusError::MalformedPacketError {error:format!("attribute with ID: {} is not found in dictionary", attr_id)} )
+/// Converts attribute value into String, if ATTRIBUTE code type is string, ipaddr, ipv6addr,
+/// ipv6prefix, ipv4prefix, combo-ip, ether or ifid
+fn string_value(value: &[u8], allowed_type: &Option<SupportedAttributeTypes>) -> Result<String, RadiusError> {
+    match allowed_type {
+        Some(SupportedAttributeTypes::AsciiString) => {
+            match String::from_utf8(value.to_vec()) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid ASCII bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::IPv4Addr)    => {
+            match bytes_to_ipv4_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv4 bytes")} )
             }
+        },
+        Some(SupportedAttributeTypes::IPv6Addr)    => {
+            match bytes_to_ipv6_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::IPv6Prefix)  => {
+            match bytes_to_ipv6_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv6 bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::IPv4Prefix)  => {
+            match bytes_to_ipv4_prefix_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IPv4 prefix bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::ComboIP)     => {
+            match bytes_to_combo_ip_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid IP bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::Ether)       => {
+            match bytes_to_ether_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Ether bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::IfId)        => {
+            match bytes_to_ifid_string(value) {
+                Ok(value) => Ok(value),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Interface-Id bytes")} )
+            }
+        },
+        _                                          => Err( RadiusError::MalformedAttributeError {error: String::from("not a String data type")} )
+    }
+}
+
+/// Converts attribute value into number, if ATTRIBUTE code type is integer, date, byte, short
+/// or integer64
+fn integer_value(value: &[u8], allowed_type: &Option<SupportedAttributeTypes>) -> Result<u64, RadiusError> {
+    match allowed_type {
+        Some(SupportedAttributeTypes::Integer)   => {
+            match value.try_into() {
+                Ok(value) => Ok(bytes_to_integer(value) as u64),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Integer bytes")} )
+            }
+        } ,
+        Some(SupportedAttributeTypes::Date)      => {
+            match value.try_into() {
+                Ok(value) => Ok(bytes_to_timestamp(value)),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Date bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::Byte)      => {
+            match value {
+                [value] => Ok(u64::from(*value)),
+                _       => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Byte bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::Short)     => {
+            match value.try_into() {
+                Ok(value) => Ok(u64::from(bytes_to_short(value))),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Short bytes")} )
+            }
+        },
+        Some(SupportedAttributeTypes::Integer64) => {
+            match value.try_into() {
+                Ok(value) => Ok(bytes_to_integer64(value)),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Integer64 bytes")} )
+            }
+        },
+        _                                        => Err( RadiusError::MalformedAttributeError {error: String::from("not an Integer data type")} )
+    }
+}
+
+/// Converts attribute value into signed number, if ATTRIBUTE code type is signed
+fn signed_value(value: &[u8], allowed_type: &Option<SupportedAttributeTypes>) -> Result<i32, RadiusError> {
+    match allowed_type {
+        Some(SupportedAttributeTypes::Signed) => {
+            match value.try_into() {
+                Ok(value) => Ok(bytes_to_signed(value)),
+                _         => Err( RadiusError::MalformedAttributeError {error: String::from("invalid Signed bytes")} )
+            }
+        },
+        _                                     => Err( RadiusError::MalformedAttributeError {error: String::from("not a Signed data type")} )
+    }
+}
+
+/// Decodes TLV attribute value into child attributes (at any depth), based on parent's full code path
+///
+/// Children, that are not defined in dictionary, are treated according to [DecodeMode]
+fn decode_tlvs(dictionary: &Dictionary, vendor_id: Option<u32>, parent_oid: &[u32], bytes: &[u8], mode: DecodeMode) -> Result<Vec<RadiusAttribute>, RadiusError> {
+    verify_tlvs(bytes)?;
+
+    let mut children   = Vec::new();
+    let mut oid        = [ parent_oid, &[0] ].concat();
+    let mut last_index = 0;
+
+    while last_index < bytes.len() {
+        let tlv_length = bytes[last_index + 1] as usize;
+        let tlv_value  = &bytes[(last_index + 2)..(last_index + tlv_length)];
+        if let Some(code) = oid.last_mut() {
+            *code = u32::from(bytes[last_index]);
         }
 
-        let mut packet = RadiusPacket{
-            id:            id,
-            code:          code,
-            authenticator: authenticator,
-            attributes:    Vec::new()
+        let tlv_children = match (dictionary.attribute_by_oid(vendor_id, &oid), mode) {
+            (Some(dict_attr), _)        => {
+                let mut child  = RadiusAttribute::from_dictionary_attribute(dictionary, dict_attr, tlv_value.to_vec()).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("TLV attribute {:?} is not found in dictionary", oid)})?;
+                child.children = decode_children(dictionary, vendor_id, dict_attr, &oid, tlv_value, mode)?;
+                split_array(dictionary, dict_attr, child)
+            },
+            (None, DecodeMode::Lenient) => {
+                let mut child = RadiusAttribute::create_unknown_in_space(AttributeSpace::Standard, bytes[last_index], vendor_id, u32::from(bytes[last_index]), tlv_value.to_vec());
+                child.name    = format!("Attr-{}", oid.iter().map(|code| code.to_string()).collect::<Vec<String>>().join("."));
+                vec![child]
+            },
+            (None, DecodeMode::Strict)  => return Err( RadiusError::MalformedAttributeError {error: format!("TLV attribute {:?} is not found in dictionary", oid)} )
         };
-        packet.set_attributes(attributes);
 
-        Ok(packet)
+        children.extend(tlv_children);
+        last_index += tlv_length;
     }
+    Ok(children)
+}
 
-    /// Sets attrbiutes
-    pub fn set_attributes(&mut self, attributes: Vec<RadiusAttribute>) {
-        self.attributes = attributes;
+/// Splits attribute flagged with **array** into attributes, that hold single element of the
+/// array each, so their values could be read with the same getters, as values of any other
+/// attribute. Any other attribute is kept as is
+fn split_array(dictionary: &Dictionary, dict_attr: &DictionaryAttribute, attr: RadiusAttribute) -> Vec<RadiusAttribute> {
+    let elements = array_elements(&attr.value, dict_attr.code_type(), dict_attr.flags().array());
+    if elements.len() < 2 {
+        return vec![attr]
     }
+    elements.iter().filter_map(|element| RadiusAttribute::from_dictionary_attribute(dictionary, dict_attr, element.to_vec())).collect()
+}
 
-    /// Overrides RadiusPacket id
-    pub fn override_id(&mut self, new_id: u8) {
-        self.id = new_id
+/// Decodes value of TLV or struct attribute into its children (for other data types there is
+/// nothing to decode)
+///
+/// In [DecodeMode::Lenient] value, that could not be decoded, is kept as raw octets of the
+/// attribute itself (without children)
+fn decode_children(dictionary: &Dictionary, vendor_id: Option<u32>, dict_attr: &DictionaryAttribute, oid: &[u32], bytes: &[u8], mode: DecodeMode) -> Result<Vec<RadiusAttribute>, RadiusError> {
+    let children = match dict_attr.code_type() {
+        Some(SupportedAttributeTypes::Tlv)    => decode_tlvs(dictionary, vendor_id, oid, bytes, mode),
+        Some(SupportedAttributeTypes::Struct) => decode_struct(dictionary, vendor_id, oid, bytes, mode),
+        _                                     => Ok(Vec::new())
+    };
+
+    match (children, mode) {
+        (Err(_), DecodeMode::Lenient) => Ok(Vec::new()),
+        (children, _)                 => children
     }
+}
 
-    /// Overrides RadiusPacket authenticator
-    pub fn override_authenticator(&mut self, new_authenticator: Vec<u8>) {
-        self.authenticator = new_authenticator
-    }
+/// Decodes struct attribute value into its MEMBERs, based on struct's full code path
+///
+/// MEMBERs of fixed size data types take as many bytes as their data type requires, other MEMBERs
+/// take the rest of the value. Bytes left after the last MEMBER belong to STRUCT, that is selected
+/// by value of the MEMBER flagged with key
+fn decode_struct(dictionary: &Dictionary, vendor_id: Option<u32>, struct_oid: &[u32], bytes: &[u8], mode: DecodeMode) -> Result<Vec<RadiusAttribute>, RadiusError> {
+    let mut children   = Vec::new();
+    let mut oid        = [ struct_oid, &[0] ].concat();
+    let mut key_oid    = None;
+    let mut last_index = 0;
+
+    for code in 1..=u32::from(u8::MAX) {
+        if let Some(member_code) = oid.last_mut() {
+            *member_code = code;
+        }
+        let dict_member = match dictionary.attribute_by_oid(vendor_id, &oid) {
+            Some(dict_member) => dict_member,
+            None              => break
+        };
 
-    /// Overrides RadiusPacket Message-Authenticator
-    ///
-    /// Note: would fail if RadiusPacket has no Message-Authenticator attribute defined
-    pub fn override_message_authenticator(&mut self, new_message_authenticator: Vec<u8>) -> Result<(), RadiusError> {
-        match self.attributes.iter_mut().find(|attr| attr.name() == "Message-Authenticator") {
-            Some(attr) => {
-                attr.override_value(new_message_authenticator);
-                Ok(())
-            },
-            _          => Err( RadiusError::MalformedPacketError {error:String::from("Message-Authenticator attribute not found in packet")} )
+        let rest         = &bytes[last_index..];
+        let member_width = fixed_value_width(dict_member.code_type()).unwrap_or(rest.len());
+        if rest.len() < member_width {
+            return Err( RadiusError::MalformedAttributeError {error: format!("invalid struct bytes: {} member is truncated", dict_member.name())} )
         }
-    }
 
-    /// Returns Message-Authenticator value, if exists in RadiusPacket
-    pub fn message_authenticator(&self) -> Result<&[u8], RadiusError> {
-        match self.attributes.iter().find(|attr| attr.name() == "Message-Authenticator") {
-            Some(attr) => {
-                Ok(attr.value())
-            },
-            _          => Err( RadiusError::MalformedPacketError {error: String::from("Message-Authenticator attribute not found in packet")} )
+        let member_value = &rest[..member_width];
+        let mut member   = RadiusAttribute::from_dictionary_attribute(dictionary, dict_member, member_value.to_vec()).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("struct member {:?} is not found in dictionary", oid)})?;
+        member.children  = decode_children(dictionary, vendor_id, dict_member, &oid, member_value, mode)?;
+        if dict_member.flags().key() {
+            key_oid = Some([ oid.as_slice(), &[member.original_integer_value(dict_member.code_type())? as u32] ].concat());
         }
+
+        children.push(member);
+        last_index += member_width;
     }
 
-    /// Returns RadiusPacket id
-    pub fn id(&self) -> u8 {
-        self.id
+    if last_index < bytes.len() {
+        let sub_struct = key_oid.as_ref()
+            .and_then(|key_oid| dictionary.attribute_by_oid(vendor_id, key_oid))
+            .filter(|sub_struct| sub_struct.code_type() == &Some(SupportedAttributeTypes::Struct))
+            .ok_or_else(|| RadiusError::MalformedAttributeError {error: String::from("invalid struct bytes: no STRUCT defined for the rest of the value")})?;
+
+        let mut child  = RadiusAttribute::from_dictionary_attribute(dictionary, sub_struct, bytes[last_index..].to_vec()).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("STRUCT {} is not found in dictionary", sub_struct.name())})?;
+        child.children = decode_struct(dictionary, vendor_id, &sub_struct.oid(), &bytes[last_index..], mode)?;
+        children.push(child);
     }
+    Ok(children)
+}
 
-    /// Returns RadiusPacket authenticator
-    pub fn authenticator(&self) -> &[u8] {
-        &self.authenticator
+/// Splits value of attribute flagged with **array** into its elements, each of them as long as
+/// data type requires (value, that is not made of whole elements, and value of any other
+/// attribute are kept as single element)
+fn array_elements<'a>(value: &'a [u8], code_type: &Option<SupportedAttributeTypes>, array: bool) -> Vec<&'a [u8]> {
+    match fixed_value_width(code_type) {
+        Some(width) if array && !value.is_empty() && value.chunks_exact(width).remainder().is_empty() => value.chunks(width).collect(),
+        _                                                                                              => vec![value]
     }
+}
 
-    /// Returns RadiusPacket code
-    pub fn code(&self) -> &TypeCode {
-        &self.code
+/// Returns size of the value for data types, that always take the same number of bytes
+fn fixed_value_width(code_type: &Option<SupportedAttributeTypes>) -> Option<usize> {
+    match code_type {
+        Some(SupportedAttributeTypes::Byte)       => Some(1),
+        Some(SupportedAttributeTypes::Short)      => Some(2),
+        Some(SupportedAttributeTypes::Integer)   |
+        Some(SupportedAttributeTypes::Signed)    |
+        Some(SupportedAttributeTypes::IPv4Addr)   => Some(4),
+        Some(SupportedAttributeTypes::Ether)     |
+        Some(SupportedAttributeTypes::IPv4Prefix) => Some(6),
+        Some(SupportedAttributeTypes::Date)      |
+        Some(SupportedAttributeTypes::Integer64) |
+        Some(SupportedAttributeTypes::IfId)       => Some(8),
+        Some(SupportedAttributeTypes::IPv6Addr)   => Some(16),
+        _                                         => None
     }
+}
 
-    /// Returns RadiusPacket attributes
-    pub fn attributes(&self) -> &[RadiusAttribute] {
-        &self.attributes
+/// Verifies that bytes are a valid sequence of Type-Length-Value attributes, RFC 6929 section 2.3
+fn verify_tlvs(bytes: &[u8]) -> Result<(), RadiusError> {
+    let mut last_index = 0;
+
+    while last_index < bytes.len() {
+        if bytes.len() - last_index < 2 {
+            return Err( RadiusError::MalformedAttributeError {error: String::from("invalid TLV bytes: truncated header")} )
+        }
+
+        let tlv_length = bytes[last_index + 1] as usize;
+        if tlv_length < 2 || last_index + tlv_length > bytes.len() {
+            return Err( RadiusError::MalformedAttributeError {error: String::from("invalid TLV bytes: wrong length")} )
+        }
+        last_index += tlv_length;
     }
+    Ok(())
+}
 
-    /// Returns RadiusAttribute with given name
-    pub fn attribute_by_name(&self, name: &str) -> Option<&RadiusAttribute> {
-        self.attributes.iter().find(|&attr| attr.name() == name)
+
+/// Code of Vendor-Specific attribute, RFC 2865 section 5.26
+const VENDOR_SPECIFIC_ID: u8 = 26;
+/// Extended-Type of Extended-Vendor-Specific attribute, RFC 6929 section 2.4
+const EVS_TYPE: u8 = 26;
+/// More flag of Long-Extended attribute, RFC 6929 section 2.2
+const LONG_EXTENDED_MORE_FLAG: u8 = 0x80;
+/// More flag of continuation field of vendor attribute (ie WiMAX, `format=1,1,c`)
+const VENDOR_CONTINUATION_FLAG: u8 = 0x80;
+/// Largest possible attribute (including Type & Length fields), RFC 2865 section 5
+const MAX_ATTRIBUTE_LENGTH: usize = 255;
+/// Largest tag of tagged attribute, RFC 2868 section 3.1
+const MAX_TAG: u8 = 0x1F;
+/// Code of Message-Authenticator attribute, RFC 3579 section 3.2
+const MESSAGE_AUTHENTICATOR_ID: u8 = 80;
+/// Smallest possible RADIUS packet (header only), RFC 2865 section 3
+const MIN_PACKET_LENGTH: usize = 20;
+/// Largest possible RADIUS packet, RFC 2865 section 3
+const MAX_PACKET_LENGTH: usize = 4096;
+
+/// Verifies that bytes could hold RADIUS packet and returns packet length from its header
+pub(crate) fn verify_packet_length(bytes: &[u8]) -> Result<usize, RadiusError> {
+    if bytes.len() < MIN_PACKET_LENGTH {
+        return Err( RadiusError::MalformedPacketError {error: format!("packet is {} bytes long, but at least {} bytes are required", bytes.len(), MIN_PACKET_LENGTH)} )
+    }
+    if bytes.len() > MAX_PACKET_LENGTH {
+        return Err( RadiusError::MalformedPacketError {error: format!("packet is {} bytes long, but at most {} bytes are allowed", bytes.len(), MAX_PACKET_LENGTH)} )
     }
 
-    /// Returns RadiusAttribute with given id
-    pub fn attribute_by_id(&self, id: u8) -> Option<&RadiusAttribute> {
-        self.attributes.iter().find(|&attr| attr.id() == id)
+    let length = usize::from(u16::from_be_bytes([bytes[2], bytes[3]]));
+    if !(MIN_PACKET_LENGTH..=MAX_PACKET_LENGTH).contains(&length) {
+        return Err( RadiusError::MalformedPacketError {error: format!("packet Length field is {}, but it should be between {} and {}", length, MIN_PACKET_LENGTH, MAX_PACKET_LENGTH)} )
     }
+    if length > bytes.len() {
+        return Err( RadiusError::MalformedPacketError {error: format!("packet Length field is {}, but only {} bytes were received", length, bytes.len())} )
+    }
+    Ok(length)
+}
 
-    /// Converts RadiusPacket into ready-to-be-sent bytes vector
-    pub fn to_bytes(&mut self) -> Vec<u8> {
-        /* Prepare packet for a transmission to server/client
+/// Verifies that attribute, which starts at given index, is not truncated and returns its length
+fn verify_attribute_length(bytes: &[u8], index: usize) -> Result<usize, RadiusError> {
+    if bytes.len() - index < 2 {
+        return Err( RadiusError::MalformedPacketError {error: format!("attribute at offset {} is truncated: {} byte(s) left, but attribute header takes 2 bytes", index, bytes.len() - index)} )
+    }
+
+    let length = usize::from(bytes[index + 1]);
+    if length < 2 {
+        return Err( RadiusError::MalformedPacketError {error: format!("attribute with ID: {} at offset {} has invalid length {}, it should be at least 2", bytes[index], index, length)} )
+    }
+    if length > bytes.len() - index {
+        return Err( RadiusError::MalformedPacketError {error: format!("attribute with ID: {} at offset {} has length {}, which overflows packet by {} byte(s)", bytes[index], index, length, length - (bytes.len() - index))} )
+    }
+    Ok(length)
+}
+
+
+/// Vendor type & value of vendor attribute, that is carried inside Vendor-Specific attribute
+pub type VendorAttribute = (u32, Vec<u8>);
+
+/// Vendor type, continuation flag & value of vendor attribute, that borrows bytes of
+/// Vendor-Specific attribute
+type VendorAttributeSlice<'a> = (u32, bool, &'a [u8]);
+
+/// Converts vendor attributes (pairs of vendor type & value) into value of Vendor-Specific attribute
+///
+/// Vendor type & length widths follow vendor's format, RFC 2865 section 5.26
+pub fn vendor_attributes_to_bytes(vendor: &DictionaryVendor, vendor_attributes: &[VendorAttribute]) -> Result<Vec<u8>, RadiusError> {
+    let format    = vendor.format();
+    let mut bytes = vendor.id().to_be_bytes().to_vec();
+
+    if format.length_width() == 0 && vendor_attributes.len() > 1 {
+        return Err( RadiusError::MalformedAttributeError {error: format!("vendor {} attributes have no length, so only one fits into Vendor-Specific attribute", vendor.name())} )
+    }
+
+    for (vendor_type, value) in vendor_attributes {
+        if !fits_vendor_type(format, *vendor_type) {
+            return Err( RadiusError::MalformedAttributeError {error: format!("vendor type {} does not fit into {} byte(s)", vendor_type, format.type_width())} )
+        }
+
+        let length = usize::from(format.type_width() + format.length_width()) + usize::from(format.continuation()) + value.len();
+        if (format.length_width() == 1 && length > usize::from(u8::MAX)) || length > usize::from(u16::MAX) {
+            return Err( RadiusError::MalformedAttributeError {error: format!("vendor attribute {} is too long", vendor_type)} )
+        }
+        bytes.extend(vendor_attribute_bytes(format, *vendor_type, value, false));
+    }
+
+    if bytes.len() > 253 {
+        return Err( RadiusError::MalformedAttributeError {error: String::from("Vendor-Specific attribute value is too long")} )
+    }
+    Ok(bytes)
+}
+
+/// Splits value into parts, that fit into single attribute (empty value stays as is)
+fn value_chunks(value: &[u8], max_length: usize) -> Vec<&[u8]> {
+    if value.is_empty() {
+        return vec![value]
+    }
+    value.chunks(max_length).collect()
+}
+
+/// Returns true, if vendor type fits into vendor's type field
+fn fits_vendor_type(format: &VendorFormat, vendor_type: u32) -> bool {
+    format.type_width() >= 4 || vendor_type >> (8 * u32::from(format.type_width())) == 0
+}
+
+/// Converts single vendor attribute into bytes (type, length & continuation fields, that are
+/// sized according to vendor's format, followed by value)
+///
+/// Vendor type & length should be checked to fit into their fields beforehand. Continuation flag
+/// is only written, if vendor's format has continuation field
+fn vendor_attribute_bytes(format: &VendorFormat, vendor_type: u32, value: &[u8], more: bool) -> Vec<u8> {
+    let type_width   = usize::from(format.type_width());
+    let length_width = usize::from(format.length_width());
+    let length       = type_width + length_width + usize::from(format.continuation()) + value.len();
+
+    let mut bytes = Vec::with_capacity(length);
+    bytes.extend_from_slice(&vendor_type.to_be_bytes()[(4 - type_width)..]);
+    bytes.extend_from_slice(&(length as u16).to_be_bytes()[(2 - length_width)..]);
+    if format.continuation() {
+        bytes.push(if more { VENDOR_CONTINUATION_FLAG } else { 0 });
+    }
+    bytes.extend_from_slice(value);
+    bytes
+}
+
+/// Converts value of Vendor-Specific attribute into vendor id & vendor attributes (pairs of vendor type & value)
+///
+/// Vendor type & length widths follow vendor's format from dictionary. If vendor is not in
+/// dictionary, RFC 2865 format is assumed
+pub fn bytes_to_vendor_attributes(dictionary: &Dictionary, bytes: &[u8]) -> Result<(u32, Vec<VendorAttribute>), RadiusError> {
+    if bytes.len() < 5 {
+        return Err( RadiusError::MalformedAttributeError {error: String::from("invalid Vendor-Specific bytes")} )
+    }
+
+    let vendor_id = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let format    = dictionary.vendor_by_id(vendor_id).map(|vendor| *vendor.format()).unwrap_or_default();
+
+    let vendor_attributes = vendor_attribute_slices(vendor_id, &format, bytes)?;
+    Ok((vendor_id, vendor_attributes.into_iter().map(|(vendor_type, _, value)| (vendor_type, value.to_vec())).collect()))
+}
+
+/// Splits value of Vendor-Specific attribute into vendor types, continuation flags & values of
+/// vendor attributes, without copying the values
+fn vendor_attribute_slices<'a>(vendor_id: u32, format: &VendorFormat, bytes: &'a [u8]) -> Result<Vec<VendorAttributeSlice<'a>>, RadiusError> {
+    if bytes.len() < 5 {
+        return Err( RadiusError::MalformedAttributeError {error: String::from("invalid Vendor-Specific bytes")} )
+    }
+
+    let type_width    = usize::from(format.type_width());
+    let length_width  = usize::from(format.length_width());
+    let header_length = type_width + length_width + usize::from(format.continuation());
+
+    let mut vendor_attributes = Vec::new();
+    let mut last_index        = 4;
+
+    while last_index < bytes.len() {
+        if bytes.len() - last_index < header_length {
+            return Err( RadiusError::MalformedAttributeError {error: format!("vendor {} attribute header is truncated", vendor_id)} )
+        }
+
+        let type_bytes  = &bytes[last_index..(last_index + type_width)];
+        let vendor_type = type_bytes.iter().fold(0u32, |vendor_type, byte| (vendor_type << 8) | u32::from(*byte));
+
+        let length = match length_width {
+            0 => bytes.len() - last_index,
+            1 => usize::from(bytes[last_index + type_width]),
+            _ => usize::from(u16::from_be_bytes([bytes[last_index + type_width], bytes[last_index + type_width + 1]]))
+        };
+        if length < header_length || last_index + length > bytes.len() {
+            return Err( RadiusError::MalformedAttributeError {error: format!("vendor {} attribute {} has invalid length", vendor_id, vendor_type)} )
+        }
+
+        let more = format.continuation() && bytes[last_index + header_length - 1] & VENDOR_CONTINUATION_FLAG != 0;
+        vendor_attributes.push((vendor_type, more, &bytes[(last_index + header_length)..(last_index + length)]));
+        last_index += length;
+    }
+
+    Ok(vendor_attributes)
+}
+
+/// Finds out how ATTRIBUTE is carried inside RadiusPacket
+fn attribute_space(dictionary: &Dictionary, attr: &DictionaryAttribute) -> AttributeSpace {
+    let extended_oid = match (attr.vendor_id(), dictionary.evs_attribute(attr)) {
+        (Some(vendor_id), None) if attr.parent_oid().is_empty() => return AttributeSpace::Vendor(dictionary.vendor_by_id(vendor_id).map(|vendor| *vendor.format()).unwrap_or_default()),
+        (Some(_), Some(_))      if attr.parent_oid().len() == 2 => &attr.parent_oid()[..1],
+        (None, _)               if attr.parent_oid().len() == 1 => attr.parent_oid(),
+        _                                                       => return AttributeSpace::Standard
+    };
+
+    match dictionary.attribute_by_oid(None, extended_oid).and_then(|parent| *parent.code_type()) {
+        Some(SupportedAttributeTypes::Extended)     => AttributeSpace::Extended,
+        Some(SupportedAttributeTypes::LongExtended) => AttributeSpace::LongExtended,
+        _                                           => AttributeSpace::Standard
+    }
+}
+
+/// Returns path of top-level attribute in dictionary
+fn attribute_oid(space: AttributeSpace, id: u8, vendor_id: Option<u32>, code: u32) -> Vec<u32> {
+    match (space, vendor_id) {
+        (AttributeSpace::Extended, None)    | (AttributeSpace::LongExtended, None)    => vec![u32::from(id), code],
+        (AttributeSpace::Extended, Some(_)) | (AttributeSpace::LongExtended, Some(_)) => vec![u32::from(id), u32::from(EVS_TYPE), code],
+        _                                                                             => vec![code]
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents RADIUS packet
+pub struct RadiusPacket {
+    id:                    u8,
+    code:                  TypeCode,
+    authenticator:         Vec<u8>,
+    attributes:            Vec<RadiusAttribute>,
+    secret:                Vec<u8>,
+    request_authenticator: Vec<u8>
+}
+
+impl RadiusPacket {
+    /// Initialises RADIUS packet with random ID and authenticator
+    pub fn initialise_packet(code: TypeCode) -> RadiusPacket {
+        RadiusPacket {
+            id:                    RadiusPacket::create_id(),
+            code,
+            authenticator:         RadiusPacket::create_authenticator(),
+            attributes:            Vec::new(),
+            secret:                Vec::new(),
+            request_authenticator: Vec::new()
+        }
+    }
+
+    /// Initialises RADIUS packet from raw bytes
+    ///
+    /// Bytes are checked before anything is decoded: packet has to be 20 to 4096 bytes long, its
+    /// Length field has to fit into given bytes (bytes past Length are treated as padding and
+    /// ignored, RFC 2865 section 3) and every attribute has to be at least 2 bytes long and end
+    /// within the packet. Otherwise [MalformedPacketError](RadiusError::MalformedPacketError) is
+    /// returned
+    pub fn initialise_packet_from_bytes(dictionary: &Dictionary, bytes: &[u8]) -> Result<RadiusPacket, RadiusError> {
+        RadiusPacket::initialise_packet_from_bytes_with_mode(dictionary, bytes, DecodeMode::Strict)
+    }
+
+    /// Initialises RADIUS packet from raw bytes, treating attributes, that are not defined in
+    /// dictionary, according to [DecodeMode]
+    ///
+    /// Bytes are checked the same way, as in [initialise_packet_from_bytes](RadiusPacket::initialise_packet_from_bytes)
+    pub fn initialise_packet_from_bytes_with_mode(dictionary: &Dictionary, bytes: &[u8], mode: DecodeMode) -> Result<RadiusPacket, RadiusError> {
+        RadiusPacketRef::from_bytes(bytes)?.to_packet(dictionary, mode)
+    }
+
+    /// Initialises RADIUS packet from raw bytes and decrypts values of attributes, that are flagged
+    /// with **encrypt=** in dictionary
+    ///
+    /// Request packets are decrypted with their own authenticator, so *request_authenticator*
+    /// should only be set when decoding a reply
+    pub fn initialise_packet_from_bytes_with_secret(dictionary: &Dictionary, bytes: &[u8], secret: &str, request_authenticator: Option<&[u8]>, mode: DecodeMode) -> Result<RadiusPacket, RadiusError> {
+        let mut packet = RadiusPacket::initialise_packet_from_bytes_with_mode(dictionary, bytes, mode)?;
+        packet.set_secret(secret);
+        if let Some(request_authenticator) = request_authenticator {
+            packet.set_request_authenticator(request_authenticator.to_vec());
+        }
+
+        let authenticator = packet.encryption_authenticator().to_vec();
+        for attr in packet.attributes.iter_mut() {
+            attr.decrypt_value(&authenticator, secret.as_bytes())?;
+        }
+
+        Ok(packet)
+    }
+
+    /// Sets attrbiutes
+    pub fn set_attributes(&mut self, attributes: Vec<RadiusAttribute>) {
+        self.attributes = attributes;
+    }
+
+    /// Adds attribute to the end of RadiusPacket attributes
+    pub fn add_attribute(&mut self, attribute: RadiusAttribute) {
+        self.attributes.push(attribute);
+    }
+
+    /// Sets secret, which is used to encrypt values of attributes, that are flagged with
+    /// **encrypt=** in dictionary, when RadiusPacket is converted into bytes
+    pub fn set_secret(&mut self, secret: &str) {
+        self.secret = secret.as_bytes().to_vec();
+    }
+
+    /// Sets authenticator of the request, RadiusPacket is a reply to
+    ///
+    /// Reply attributes are encrypted with request's authenticator instead of their own
+    pub fn set_request_authenticator(&mut self, request_authenticator: Vec<u8>) {
+        self.request_authenticator = request_authenticator;
+    }
+
+    /// Overrides RadiusPacket id
+    pub fn override_id(&mut self, new_id: u8) {
+        self.id = new_id
+    }
+
+    /// Overrides RadiusPacket authenticator
+    pub fn override_authenticator(&mut self, new_authenticator: Vec<u8>) {
+        self.authenticator = new_authenticator
+    }
+
+    /// Overrides RadiusPacket Message-Authenticator
+    ///
+    /// Note: would fail if RadiusPacket has no Message-Authenticator attribute defined
+    pub fn override_message_authenticator(&mut self, new_message_authenticator: Vec<u8>) -> Result<(), RadiusError> {
+        match self.attributes.iter_mut().find(|attr| attr.name() == "Message-Authenticator") {
+            Some(attr) => {
+                attr.override_value(new_message_authenticator);
+                Ok(())
+            },
+            _          => Err( RadiusError::MalformedPacketError {error:String::from("Message-Authenticator attribute not found in packet")} )
+        }
+    }
+
+    /// Returns Message-Authenticator value, if exists in RadiusPacket
+    pub fn message_authenticator(&self) -> Result<&[u8], RadiusError> {
+        match self.attributes.iter().find(|attr| attr.name() == "Message-Authenticator") {
+            Some(attr) => {
+                Ok(attr.value())
+            },
+            _          => Err( RadiusError::MalformedPacketError {error: String::from("Message-Authenticator attribute not found in packet")} )
+        }
+    }
+
+    /// Returns RadiusPacket id
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns RadiusPacket authenticator
+    pub fn authenticator(&self) -> &[u8] {
+        &self.authenticator
+    }
+
+    /// Returns RadiusPacket code
+    pub fn code(&self) -> &TypeCode {
+        &self.code
+    }
+
+    /// Returns RadiusPacket attributes
+    pub fn attributes(&self) -> &[RadiusAttribute] {
+        &self.attributes
+    }
+
+    /// Returns RadiusAttribute with given name
+    pub fn attribute_by_name(&self, name: &str) -> Option<&RadiusAttribute> {
+        self.attributes.iter().find(|&attr| attr.name() == name)
+    }
+
+    /// Returns RadiusAttribute with given id
+    pub fn attribute_by_id(&self, id: u8) -> Option<&RadiusAttribute> {
+        self.attributes.iter().find(|&attr| attr.id() == id)
+    }
+
+    /// Converts RadiusPacket into ready-to-be-sent bytes vector
+    ///
+    /// Returns [MalformedPacketError](RadiusError::MalformedPacketError), if attributes do not fit
+    /// into 4096 bytes long packet, and [MalformedAttributeError](RadiusError::MalformedAttributeError),
+    /// if value of attribute, that is not flagged with `concat`, does not fit into single attribute
+    /// or attribute flagged with **encrypt=** is encoded without secret (see [RadiusPacket::set_secret])
+    pub fn to_bytes(&mut self) -> Result<Vec<u8>, RadiusError> {
+        /* Prepare packet for a transmission to server/client
          *
          *          0               1               2         3
             0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
@@ -438,17 +1350,46 @@ impl RadiusPacket {
             self.authenticator = Self::create_authenticator();
         }
 
-        for attr in self.attributes.iter() {
-            packet_attr.extend(&attr.to_bytes());
+        let authenticator = self.encryption_authenticator();
+        for (index, attr) in self.attributes.iter().enumerate() {
+            if attr.flags.encrypt().is_none() {
+                packet_attr.extend(&attr.to_bytes(&attr.value)?);
+            } else if self.secret.is_empty() {
+                // Value of encrypted attribute is never sent in plain text
+                return Err( RadiusError::MalformedAttributeError {error: format!("{} attribute has to be encrypted, but packet has no secret set", attr.name)} )
+            } else {
+                let salt = RadiusPacket::create_salt(authenticator, index);
+                packet_attr.extend(&attr.to_bytes(&attr.encrypt_value(authenticator, &self.secret, salt)?)?);
+            }
+        }
+
+        let packet_length = MIN_PACKET_LENGTH + packet_attr.len();
+        if packet_length > MAX_PACKET_LENGTH {
+            return Err( RadiusError::MalformedPacketError {error: format!("packet would be {} bytes long, but at most {} bytes are allowed", packet_length, MAX_PACKET_LENGTH)} )
         }
 
         packet_bytes.push(self.code.to_u8());
         packet_bytes.push(self.id);
-        packet_bytes.append(&mut Self::packet_length_to_bytes(((20 + packet_attr.len()) as u16).to_be()).to_vec());
+        packet_bytes.append(&mut Self::packet_length_to_bytes((packet_length as u16).to_be()).to_vec());
         packet_bytes.append(&mut self.authenticator.as_slice().to_vec());
         packet_bytes.append(&mut packet_attr);
 
-        packet_bytes
+        Ok(packet_bytes)
+    }
+
+    fn encryption_authenticator(&self) -> &[u8] {
+        if self.request_authenticator.is_empty() {
+            &self.authenticator
+        } else {
+            &self.request_authenticator
+        }
+    }
+
+    fn create_salt(authenticator: &[u8], index: usize) -> [u8; 2] {
+        // Salt has to be unique within the packet & have most significant bit set, RFC 2868 section 3.5
+        // It is derived from authenticator, so RadiusPacket encodes to the same bytes every time
+        let base = u16::from_be_bytes([ authenticator.first().copied().unwrap_or(0), authenticator.get(1).copied().unwrap_or(0) ]);
+        (0x8000 | (base.wrapping_add(index as u16) & 0x7fff)).to_be_bytes()
     }
 
     fn create_id() -> u8 {
@@ -473,9 +1414,357 @@ impl RadiusPacket {
     }
 }
 
+
+#[derive(Debug, Clone, PartialEq)]
+/// Read-only view of RADIUS packet, that borrows received bytes instead of copying them
+///
+/// Packet framing is checked once, when view is created, the same way as in
+/// [RadiusPacket::initialise_packet_from_bytes], so attributes could then be iterated over
+/// lazily and without allocations. Convert the view into [RadiusPacket] with
+/// [to_packet](RadiusPacketRef::to_packet), when owned packet is needed
+pub struct RadiusPacketRef<'a> {
+    code:  TypeCode,
+    bytes: &'a [u8]
+}
+
+impl<'a> RadiusPacketRef<'a> {
+    /// Creates view of RADIUS packet from raw bytes
+    ///
+    /// Bytes past packet Length are ignored as padding. Attributes are not checked against
+    /// dictionary
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<RadiusPacketRef<'a>, RadiusError> {
+        let bytes          = &bytes[..verify_packet_length(bytes)?];
+        let code           = TypeCode::from_u8(bytes[0])?;
+        let mut last_index = 20;
+
+        while last_index != bytes.len() {
+            last_index += verify_attribute_length(bytes, last_index)?;
+        }
+
+        Ok(RadiusPacketRef { code, bytes })
+    }
+
+    /// Returns RadiusPacketRef id
+    pub fn id(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// Returns RadiusPacketRef authenticator
+    pub fn authenticator(&self) -> &'a [u8] {
+        &self.bytes[4..20]
+    }
+
+    /// Returns RadiusPacketRef code
+    pub fn code(&self) -> &TypeCode {
+        &self.code
+    }
+
+    /// Returns bytes of the packet (up to its Length)
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns iterator over RadiusPacketRef attributes, in the order they were received
+    pub fn attributes(&self) -> RadiusAttributeRefIter<'a> {
+        RadiusAttributeRefIter { bytes: self.bytes, last_index: 20 }
+    }
+
+    /// Returns first attribute with given id
+    pub fn attribute_by_id(&self, id: u8) -> Option<RadiusAttributeRef<'a>> {
+        self.attributes().find(|attr| attr.id() == id)
+    }
+
+    /// Verifies that every attribute is defined in dictionary
+    ///
+    /// Vendor-Specific attributes of vendors, that are declared in dictionary, are checked
+    /// attribute by attribute, while attributes of RFC 6929 extended spaces are checked by their
+    /// Extended-Type (or Vendor-Id & Vendor-Type). Children of TLV (and struct) attributes are
+    /// checked as well, once fragments of the value are joined
+    pub fn verify_known_attributes(&self, dictionary: &Dictionary) -> Result<(), RadiusError> {
+        let mut slices = Vec::new();
+        for attr in self.attributes() {
+            slices.extend(attribute_slices(dictionary, attr)?);
+        }
+
+        let mut fragments = Vec::new();
+        for (index, slice) in slices.iter().enumerate() {
+            let dict_attr = slice.dictionary_attribute(dictionary).ok_or_else(|| slice.not_found_error())?;
+
+            // Fragments of the value are joined the same way, as they are joined by decoder
+            fragments.extend_from_slice(slice.value);
+            let next_is_part = matches!(slices.get(index + 1), Some(next) if (next.id, next.vendor_id, next.code) == (slice.id, slice.vendor_id, slice.code));
+            if slice.more || (dict_attr.flags().concat() && next_is_part) {
+                continue
+            }
+
+            let oid = attribute_oid(slice.space, slice.id, slice.vendor_id, slice.code);
+            decode_children(dictionary, slice.vendor_id, dict_attr, &oid, &fragments, DecodeMode::Strict)?;
+            fragments.clear();
+        }
+        Ok(())
+    }
+
+    /// Verifies that attribute values match their data types in dictionary
+    ///
+    /// Message-Authenticator, attributes flagged with **encrypt=** in dictionary and attributes,
+    /// that are not defined in dictionary, are skipped. Vendor-Specific attributes of vendors,
+    /// that are declared in dictionary, are checked attribute by attribute and fragments of
+    /// Long-Extended attributes (or vendor attributes with continuation flag) are checked once
+    /// they are joined
+    pub fn verify_attributes(&self, dictionary: &Dictionary) -> Result<(), RadiusError> {
+        let verify = |dict_attr: Option<&DictionaryAttribute>, value: &[u8]| {
+            match dict_attr {
+                Some(dict_attr) if dict_attr.flags().encrypt().is_none() => {
+                    array_elements(value, dict_attr.code_type(), dict_attr.flags().array()).iter()
+                        .try_for_each(|element| verify_value(element, dict_attr.code_type()))
+                        .map_err(|err| RadiusError::ValidationError {error: err.to_string()})
+                },
+                _                                                        => Ok(())
+            }
+        };
+
+        let mut fragments = Vec::new();
+        for attr in self.attributes().filter(|attr| attr.id() != MESSAGE_AUTHENTICATOR_ID) {
+            for slice in attribute_slices(dictionary, attr).map_err(|err| RadiusError::ValidationError {error: err.to_string()})? {
+                if slice.more {
+                    fragments.extend_from_slice(slice.value);
+                } else if fragments.is_empty() {
+                    verify(slice.dictionary_attribute(dictionary), slice.value)?;
+                } else {
+                    fragments.extend_from_slice(slice.value);
+                    verify(slice.dictionary_attribute(dictionary), &fragments)?;
+                    fragments.clear();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies Message-Authenticator value
+    pub fn verify_message_authenticator(&self, secret: &str) -> Result<(), RadiusError> {
+        let packet_msg_auth = self.attribute_by_id(MESSAGE_AUTHENTICATOR_ID)
+            .ok_or_else(|| RadiusError::MalformedPacketError {error: String::from("Message-Authenticator attribute not found in packet")})?;
+
+        let mut hash = Hmac::new(Md5::new(), secret.as_bytes());
+        hash.input(self.bytes);
+
+        if hash.result().code() == packet_msg_auth.value() {
+            Ok(())
+        } else {
+            Err( RadiusError::ValidationError {error: String::from("Packet Message-Authenticator mismatch")} )
+        }
+    }
+
+    /// Converts view into RadiusPacket, treating attributes, that are not defined in dictionary,
+    /// according to [DecodeMode]
+    pub fn to_packet(&self, dictionary: &Dictionary, mode: DecodeMode) -> Result<RadiusPacket, RadiusError> {
+        let mut attributes: Vec<RadiusAttribute> = Vec::new();
+        let mut more                             = false;
+
+        for attr_ref in self.attributes() {
+            for slice in attribute_slices(dictionary, attr_ref)? {
+                let attr = slice.to_attribute(dictionary, mode)?;
+                match attributes.last_mut() {
+                    // Fragments of Long-Extended attribute (or vendor attribute with continuation
+                    // flag) and consecutive attributes flagged with concat are joined back into
+                    // single value
+                    Some(last_attr) if (more || attr.flags.concat()) && (last_attr.id, last_attr.vendor_id, last_attr.code) == (attr.id, attr.vendor_id, attr.code) => last_attr.value.extend(attr.value),
+                    _ if more                                                                                                                                         => return Err( RadiusError::MalformedPacketError {error: format!("fragment with More flag set is followed by {} attribute instead of the next fragment", attr.name)} ),
+                    _                                                                                                                                                 => attributes.push(attr)
+                }
+                more = slice.more;
+            }
+        }
+        if more {
+            return Err( RadiusError::MalformedPacketError {error: String::from("last attribute of the packet has More flag set")} )
+        }
+
+        // Arrays are split into elements once fragments are joined
+        let mut attributes: Vec<RadiusAttribute> = attributes.into_iter().flat_map(|attr| {
+            match dictionary.attribute_by_oid(attr.vendor_id, &attr.oid()) {
+                Some(dict_attr) if !attr.unknown => split_array(dictionary, dict_attr, attr),
+                _                                => vec![attr]
+            }
+        }).collect();
+
+        // TLVs & structs are decoded once concat attributes are joined, so children could span several attributes
+        for attr in attributes.iter_mut().filter(|attr| !attr.unknown) {
+            let oid = attr.oid();
+            if let Some(dict_attr) = dictionary.attribute_by_oid(attr.vendor_id, &oid) {
+                attr.children = decode_children(dictionary, attr.vendor_id, dict_attr, &oid, &attr.value, mode)?;
+            }
+        }
+
+        let mut packet = RadiusPacket{
+            id:                    self.id(),
+            code:                  self.code.clone(),
+            authenticator:         self.authenticator().to_vec(),
+            attributes:            Vec::new(),
+            secret:                Vec::new(),
+            request_authenticator: Vec::new()
+        };
+        packet.set_attributes(attributes);
+
+        Ok(packet)
+    }
+}
+
+/// Attribute, that is carried inside attribute of received packet: attribute itself, vendor
+/// attribute of Vendor-Specific attribute or attribute of RFC 6929 extended space
+struct AttributeSlice<'a> {
+    space:     AttributeSpace,
+    id:        u8,
+    vendor_id: Option<u32>,
+    code:      u32,
+    more:      bool,
+    value:     &'a [u8]
+}
+
+impl<'a> AttributeSlice<'a> {
+    fn dictionary_attribute<'d>(&self, dictionary: &'d Dictionary) -> Option<&'d DictionaryAttribute> {
+        dictionary.attribute_by_oid(self.vendor_id, &attribute_oid(self.space, self.id, self.vendor_id, self.code))
+    }
+
+    fn not_found_error(&self) -> RadiusError {
+        let error = match (self.space, self.vendor_id) {
+            (AttributeSpace::Standard, _) => format!("attribute with ID: {} is not found in dictionary", self.id),
+            (_, Some(vendor_id))          => format!("vendor {} attribute with code: {} is not found in dictionary", vendor_id, self.code),
+            (_, None)                     => format!("extended attribute {}.{} is not found in dictionary", self.id, self.code)
+        };
+        RadiusError::MalformedPacketError { error }
+    }
+
+    /// Converts slice into RadiusAttribute, treating attribute, that is not defined in
+    /// dictionary, according to [DecodeMode]
+    fn to_attribute(&self, dictionary: &Dictionary, mode: DecodeMode) -> Result<RadiusAttribute, RadiusError> {
+        let attr = self.dictionary_attribute(dictionary).and_then(|dict_attr| RadiusAttribute::from_dictionary_attribute(dictionary, dict_attr, self.value.to_vec()));
+        match (attr, mode) {
+            (Some(attr), _)             => Ok(attr),
+            (None, DecodeMode::Lenient) => Ok(RadiusAttribute::create_unknown_in_space(self.space, self.id, self.vendor_id, self.code, self.value.to_vec())),
+            (None, DecodeMode::Strict)  => Err(self.not_found_error())
+        }
+    }
+}
+
+/// Splits attribute of received packet into attributes it carries
+///
+/// Vendor-Specific attribute of vendor, that is declared in dictionary, carries one or more vendor
+/// attributes, while attribute of RFC 6929 extended space (if dictionary defines its
+/// Extended-Attribute) carries single attribute. Any other attribute (including Vendor-Specific
+/// attribute of undeclared vendor) is kept as is
+fn attribute_slices<'a>(dictionary: &Dictionary, attr_ref: RadiusAttributeRef<'a>) -> Result<Vec<AttributeSlice<'a>>, RadiusError> {
+    let (id, value) = (attr_ref.id(), attr_ref.value());
+    let as_is       = AttributeSlice { space: AttributeSpace::Standard, id, vendor_id: None, code: u32::from(id), more: false, value };
+
+    if id == VENDOR_SPECIFIC_ID {
+        let vendor = match value {
+            [a, b, c, d, ..] => dictionary.vendor_by_id(u32::from_be_bytes([*a, *b, *c, *d])),
+            _                => None
+        };
+        return match vendor {
+            Some(vendor) => Ok(vendor_attribute_slices(vendor.id(), vendor.format(), value)?.into_iter()
+                .map(|(code, more, value)| AttributeSlice { space: AttributeSpace::Vendor(*vendor.format()), id, vendor_id: Some(vendor.id()), code, more, value })
+                .collect()),
+            None         => Ok(vec![as_is])
+        }
+    }
+
+    let space = match dictionary.attribute_by_code(id).and_then(|attr| *attr.code_type()) {
+        Some(SupportedAttributeTypes::Extended)     => AttributeSpace::Extended,
+        Some(SupportedAttributeTypes::LongExtended) => AttributeSpace::LongExtended,
+        _                                           => return Ok(vec![as_is])
+    };
+
+    // Extended-Type (and flags byte of Long-Extended attribute) go first, RFC 6929 sections 2.1 & 2.2
+    let header_length = if space == AttributeSpace::LongExtended { 2 } else { 1 };
+    if value.len() < header_length {
+        return Err( RadiusError::MalformedAttributeError {error: format!("extended attribute with ID: {} is truncated", id)} )
+    }
+    let extended_type = value[0];
+    let more          = space == AttributeSpace::LongExtended && value[1] & LONG_EXTENDED_MORE_FLAG != 0;
+    let value         = &value[header_length..];
+
+    // Extended-Vendor-Specific attribute carries Vendor-Id & Vendor-Type in front of the value, RFC 6929 section 2.4
+    let evs = extended_type == EVS_TYPE && dictionary.attribute_by_oid(None, &[u32::from(id), u32::from(EVS_TYPE)]).and_then(|attr| *attr.code_type()) == Some(SupportedAttributeTypes::Evs);
+    match value {
+        [a, b, c, d, vendor_type, vendor_value @ ..] if evs => Ok(vec![AttributeSlice { space, id, vendor_id: Some(u32::from_be_bytes([*a, *b, *c, *d])), code: u32::from(*vendor_type), more, value: vendor_value }]),
+        _ if evs                                            => Err( RadiusError::MalformedAttributeError {error: format!("Extended-Vendor-Specific attribute with ID: {} is truncated", id)} ),
+        _                                                   => Ok(vec![AttributeSlice { space, id, vendor_id: None, code: u32::from(extended_type), more, value }])
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Read-only view of an attribute of [RadiusPacketRef]
+pub struct RadiusAttributeRef<'a> {
+    id:    u8,
+    value: &'a [u8]
+}
+
+impl<'a> RadiusAttributeRef<'a> {
+    /// Returns RadiusAttributeRef id
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns RadiusAttributeRef value
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Returns name of the ATTRIBUTE in dictionary, if it is defined there
+    pub fn name<'d>(&self, dictionary: &'d Dictionary) -> Option<&'d str> {
+        dictionary.attribute_by_code(self.id).map(|attr| attr.name())
+    }
+
+    /// Verifies RadiusAttributeRef value, based on the ATTRIBUTE code type
+    pub fn verify_original_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<(), RadiusError> {
+        verify_value(self.value, allowed_type)
+    }
+
+    /// Same as [RadiusAttribute::original_string_value]
+    pub fn original_string_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<String, RadiusError> {
+        string_value(self.value, allowed_type)
+    }
+
+    /// Same as [RadiusAttribute::original_integer_value]
+    pub fn original_integer_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<u64, RadiusError> {
+        integer_value(self.value, allowed_type)
+    }
+
+    /// Same as [RadiusAttribute::original_signed_value]
+    pub fn original_signed_value(&self, allowed_type: &Option<SupportedAttributeTypes>) -> Result<i32, RadiusError> {
+        signed_value(self.value, allowed_type)
+    }
+}
+
+
+#[derive(Debug, Clone)]
+/// Iterator over attributes of [RadiusPacketRef]
+pub struct RadiusAttributeRefIter<'a> {
+    bytes:      &'a [u8],
+    last_index: usize
+}
+
+impl<'a> Iterator for RadiusAttributeRefIter<'a> {
+    type Item = RadiusAttributeRef<'a>;
+
+    fn next(&mut self) -> Option<RadiusAttributeRef<'a>> {
+        // Lengths are verified when RadiusPacketRef is created
+        if self.last_index >= self.bytes.len() {
+            return None
+        }
+
+        let attr_length  = usize::from(self.bytes[self.last_index + 1]);
+        let attr         = RadiusAttributeRef { id: self.bytes[self.last_index], value: &self.bytes[(self.last_index + 2)..(self.last_index + attr_length)] };
+        self.last_index += attr_length;
+        Some(attr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::tools::{ integer_to_bytes, ipv4_string_to_bytes};
+    use crate::tools::ipv4_string_to_bytes;
     use super::*;
 
     #[test]
@@ -484,9 +1773,15 @@ mod tests {
         let dict            = Dictionary::from_file(dictionary_path).unwrap();
 
         let expected = RadiusAttribute {
-            id:    1,
-            name:  String::from("User-Name"),
-            value: vec![1,2,3]
+            id:        1,
+            code:      1,
+            vendor_id: None,
+            space:     AttributeSpace::Standard,
+            name:      String::from("User-Name"),
+            value:     vec![1,2,3],
+            flags:     AttributeFlags::default(),
+            children:  Vec::new(),
+            unknown:   false
         };
 
         assert_eq!(Some(expected), RadiusAttribute::create_by_name(&dict, "User-Name", vec![1,2,3]));
@@ -497,15 +1792,58 @@ mod tests {
         let dict            = Dictionary::from_file(dictionary_path).unwrap();
         
         let expected = RadiusAttribute {
-            id:    5,
-            name:  String::from("NAS-Port-Id"),
-            value: vec![1,2,3]
+            id:        5,
+            code:      5,
+            vendor_id: None,
+            space:     AttributeSpace::Standard,
+            name:      String::from("NAS-Port-Id"),
+            value:     vec![1,2,3],
+            flags:     AttributeFlags::default(),
+            children:  Vec::new(),
+            unknown:   false
         };
 
         assert_eq!(Some(expected), RadiusAttribute::create_by_id(&dict, 5, vec![1,2,3]));
     }
+
+    #[test]
+    fn test_radius_attribute_value_names() {
+        let dict = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        let framed_protocol = RadiusAttribute::create_by_value_name(&dict, "Framed-Protocol", "PPP").unwrap();
+        assert_eq!(vec![0, 0, 0, 1], framed_protocol.value());
+        assert_eq!(Some("PPP"),      framed_protocol.value_name(&dict).unwrap());
+
+        let vendor_number = RadiusAttribute::create_by_value_name(&dict, "Somevendor-Number", "Two").unwrap();
+        assert_eq!(vec![0, 0, 0, 2], vendor_number.value());
+        assert_eq!(Some("Two"),      vendor_number.value_name(&dict).unwrap());
+
+        let unnamed = RadiusAttribute::create_by_name(&dict, "Framed-Protocol", integer_to_bytes(100)).unwrap();
+        assert_eq!(None, unnamed.value_name(&dict).unwrap());
+
+        assert_eq!(None, RadiusAttribute::create_by_value_name(&dict, "Framed-Protocol", "SLIP"));
+        assert_eq!(None, RadiusAttribute::create_by_value_name(&dict, "User-Name", "PPP"));
+        assert!(RadiusAttribute::create_by_name(&dict, "User-Name", vec![1]).unwrap().value_name(&dict).is_err());
+    }
     
 
+    #[test]
+    fn test_verify_original_value_new_types() {
+        let attribute = |value: Vec<u8>| RadiusAttribute { id: 1, code: 1, vendor_id: None, space: AttributeSpace::Standard, name: String::from("Test"), value: value, flags: AttributeFlags::default(), children: Vec::new(), unknown: false };
+
+        assert!(attribute(vec![0, 159, 1]).verify_original_value(&Some(SupportedAttributeTypes::Octets)).is_ok());
+        assert!(attribute(vec![1, 4, 0, 1, 2, 3, 10]).verify_original_value(&Some(SupportedAttributeTypes::Tlv)).is_ok());
+        assert!(attribute(vec![1, 5, 0, 1]).verify_original_value(&Some(SupportedAttributeTypes::Tlv)).is_err());
+        assert!(attribute(vec![0, 0, 0, 9, 1]).verify_original_value(&Some(SupportedAttributeTypes::Vsa)).is_ok());
+        assert!(attribute(vec![0, 0, 0, 9]).verify_original_value(&Some(SupportedAttributeTypes::Vsa)).is_err());
+        assert!(attribute(vec![1, 2, 3]).verify_original_value(&None).is_err());
+
+        assert_eq!("00:1b:21:3c:4d:5e", attribute(vec![0, 27, 33, 60, 77, 94]).original_string_value(&Some(SupportedAttributeTypes::Ether)).unwrap());
+        assert_eq!(-2,                  attribute(vec![255, 255, 255, 254]).original_signed_value(&Some(SupportedAttributeTypes::Signed)).unwrap());
+        assert_eq!(513,                 attribute(vec![2, 1]).original_integer_value(&Some(SupportedAttributeTypes::Short)).unwrap());
+        assert!(attribute(vec![2, 1, 0]).original_integer_value(&Some(SupportedAttributeTypes::Short)).is_err());
+    }
+
     #[test]
     fn test_initialise_packet_from_bytes() {
         let dictionary_path = "./dict_examples/integration_dict";
@@ -527,12 +1865,142 @@ mod tests {
         expected_packet.override_id(43);
         expected_packet.override_authenticator(authenticator);
 
-        let bytes             = [4, 43, 0, 83, 215, 189, 213, 172, 57, 94, 141, 70, 134, 121, 101, 57, 187, 220, 227, 73, 4, 6, 192, 168, 1, 10, 5, 6, 0, 0, 0, 0, 32, 10, 116, 114, 105, 108, 108, 105, 97, 110, 30, 19, 48, 48, 45, 48, 52, 45, 53, 70, 45, 48, 48, 45, 48, 70, 45, 68, 49, 31, 19, 48, 48, 45, 48, 49, 45, 50, 52, 45, 56, 48, 45, 66, 51, 45, 57, 67, 8, 6, 10, 0, 0, 100];
+        let bytes             = [4, 43, 0, 86, 215, 189, 213, 172, 57, 94, 141, 70, 134, 121, 101, 57, 187, 220, 227, 73, 4, 6, 192, 168, 1, 10, 5, 6, 0, 0, 0, 0, 32, 10, 116, 114, 105, 108, 108, 105, 97, 110, 30, 19, 48, 48, 45, 48, 52, 45, 53, 70, 45, 48, 48, 45, 48, 70, 45, 68, 49, 31, 19, 48, 48, 45, 48, 49, 45, 50, 52, 45, 56, 48, 45, 66, 51, 45, 57, 67, 8, 6, 10, 0, 0, 100];
         let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &bytes).unwrap();
 
         assert_eq!(expected_packet, packet_from_bytes);
     }
 
+    #[test]
+    fn test_initialise_packet_from_malformed_bytes() {
+        let dict = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+
+        // Access-Request with User-Name = "test"
+        let header = vec![1, 1, 0, 26, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let bytes  = [ header.as_slice(), &[1, 6, 116, 101, 115, 116] ].concat();
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &bytes).is_ok());
+
+        let expect_error = |bytes: &[u8], expected: &str| {
+            match RadiusPacket::initialise_packet_from_bytes(&dict, bytes) {
+                Err(RadiusError::MalformedPacketError { error }) => assert_eq!(expected, error),
+                other                                            => panic!("Unexpected result: {:?}", other)
+            }
+        };
+
+        expect_error(&[],          "packet is 0 bytes long, but at least 20 bytes are required");
+        expect_error(&header[..4], "packet is 4 bytes long, but at least 20 bytes are required");
+        expect_error(&[ bytes.as_slice(), &[0; 4080] ].concat(), "packet is 4106 bytes long, but at most 4096 bytes are allowed");
+
+        let mut broken_bytes = bytes.to_vec();
+        broken_bytes[3]      = 19;
+        expect_error(&broken_bytes, "packet Length field is 19, but it should be between 20 and 4096");
+        broken_bytes[2]      = 16;
+        broken_bytes[3]      = 1;
+        expect_error(&broken_bytes, "packet Length field is 4097, but it should be between 20 and 4096");
+        broken_bytes[2]      = 0;
+        broken_bytes[3]      = 27;
+        expect_error(&broken_bytes, "packet Length field is 27, but only 26 bytes were received");
+
+        let mut broken_bytes = bytes.to_vec();
+        broken_bytes[21]     = 0;
+        expect_error(&broken_bytes, "attribute with ID: 1 at offset 20 has invalid length 0, it should be at least 2");
+        broken_bytes[21]     = 1;
+        expect_error(&broken_bytes, "attribute with ID: 1 at offset 20 has invalid length 1, it should be at least 2");
+        broken_bytes[21]     = 255;
+        expect_error(&broken_bytes, "attribute with ID: 1 at offset 20 has length 255, which overflows packet by 249 byte(s)");
+
+        let mut broken_bytes = [ bytes.as_slice(), &[1] ].concat();
+        broken_bytes[3]      = 27;
+        expect_error(&broken_bytes, "attribute at offset 26 is truncated: 1 byte(s) left, but attribute header takes 2 bytes");
+
+        // Bytes past packet Length are padding
+        let padded_bytes = [ bytes.as_slice(), &[0, 0, 0] ].concat();
+        assert_eq!(RadiusPacket::initialise_packet_from_bytes(&dict, &bytes).unwrap(), RadiusPacket::initialise_packet_from_bytes(&dict, &padded_bytes).unwrap());
+
+        // Encrypted attributes of any length are either decrypted or rejected
+        for attribute_id in [2, 69] {
+            for value_length in 0..=40 {
+                let attribute_bytes = [ &[attribute_id, 2 + value_length as u8], &vec![0x80; value_length][..] ].concat();
+                let mut bytes       = [ &header[..20], attribute_bytes.as_slice() ].concat();
+                bytes[3]            = bytes.len() as u8;
+
+                for mode in [DecodeMode::Strict, DecodeMode::Lenient] {
+                    let _ = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &bytes, "secret", None, mode);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_initialise_packet_with_unknown_attributes() {
+        let dict = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+
+        // Access-Request with User-Name = "test" & unknown attribute 200
+        let bytes = [1, 1, 0, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 1, 6, 116, 101, 115, 116, 200, 5, 1, 2, 3];
+
+        match RadiusPacket::initialise_packet_from_bytes(&dict, &bytes) {
+            Err(RadiusError::MalformedPacketError { error }) => assert_eq!("attribute with ID: 200 is not found in dictionary", error),
+            other                                            => panic!("Unexpected result: {:?}", other)
+        }
+        assert!(RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &bytes, DecodeMode::Strict).is_err());
+
+        let mut packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &bytes, DecodeMode::Lenient).unwrap();
+        let unknown    = packet.attribute_by_id(200).unwrap();
+        assert_eq!("Attr-200",    unknown.name());
+        assert_eq!(&[1, 2, 3],    unknown.value());
+        assert!(unknown.is_unknown());
+        assert!(!packet.attribute_by_name("User-Name").unwrap().is_unknown());
+        assert_eq!(&RadiusAttribute::create_unknown(200, vec![1, 2, 3]), unknown);
+
+        // Unknown attributes are sent back as they were received
+        assert_eq!(bytes.to_vec(), packet.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_radius_packet_ref() {
+        let dict  = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let bytes = [4, 43, 0, 86, 215, 189, 213, 172, 57, 94, 141, 70, 134, 121, 101, 57, 187, 220, 227, 73, 4, 6, 192, 168, 1, 10, 5, 6, 0, 0, 0, 0, 32, 10, 116, 114, 105, 108, 108, 105, 97, 110, 30, 19, 48, 48, 45, 48, 52, 45, 53, 70, 45, 48, 48, 45, 48, 70, 45, 68, 49, 31, 19, 48, 48, 45, 48, 49, 45, 50, 52, 45, 56, 48, 45, 66, 51, 45, 57, 67, 8, 6, 10, 0, 0, 100];
+
+        let packet_ref = RadiusPacketRef::from_bytes(&bytes).unwrap();
+        assert_eq!(&TypeCode::AccountingRequest, packet_ref.code());
+        assert_eq!(43,                           packet_ref.id());
+        assert_eq!(&bytes[4..20],                packet_ref.authenticator());
+        assert_eq!(&bytes[..],                   packet_ref.as_bytes());
+        assert_eq!(vec![4, 5, 32, 30, 31, 8],    packet_ref.attributes().map(|attr| attr.id()).collect::<Vec<u8>>());
+
+        let nas_identifier = packet_ref.attribute_by_id(32).unwrap();
+        assert_eq!(b"trillian",                  nas_identifier.value());
+        assert_eq!(Some("NAS-Identifier"),       nas_identifier.name(&dict));
+        assert_eq!("trillian",                   nas_identifier.original_string_value(&Some(SupportedAttributeTypes::AsciiString)).unwrap());
+        assert_eq!(None,                         packet_ref.attribute_by_id(1));
+
+        assert!(packet_ref.verify_known_attributes(&dict).is_ok());
+        assert!(packet_ref.verify_attributes(&dict).is_ok());
+        assert_eq!(RadiusPacket::initialise_packet_from_bytes(&dict, &bytes).unwrap(), packet_ref.to_packet(&dict, DecodeMode::Strict).unwrap());
+
+        // Framing is checked when view is created
+        let mut broken_bytes = bytes.to_vec();
+        broken_bytes[21]     = 1;
+        assert!(RadiusPacketRef::from_bytes(&broken_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&bytes[..19]).is_err());
+
+        // NAS-IP-Address with 3 bytes value
+        let mut broken_bytes = bytes.to_vec();
+        broken_bytes[3]      = 85;
+        broken_bytes[21]     = 5;
+        broken_bytes.remove(25);
+        let broken_ref       = RadiusPacketRef::from_bytes(&broken_bytes).unwrap();
+        assert!(broken_ref.verify_attributes(&dict).is_err());
+
+        // Unknown attribute
+        let unknown_bytes = [1, 1, 0, 25, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 200, 5, 1, 2, 3];
+        let unknown_ref   = RadiusPacketRef::from_bytes(&unknown_bytes).unwrap();
+        assert!(unknown_ref.verify_known_attributes(&dict).is_err());
+        assert!(unknown_ref.verify_attributes(&dict).is_ok());
+        assert!(unknown_ref.to_packet(&dict, DecodeMode::Strict).is_err());
+        assert!(unknown_ref.to_packet(&dict, DecodeMode::Lenient).unwrap().attribute_by_id(200).unwrap().is_unknown());
+    }
+
     #[test]
     fn test_radius_packet_override_id() {
         let attributes: Vec<RadiusAttribute> = Vec::with_capacity(1);
@@ -567,7 +2035,24 @@ mod tests {
         packet.override_id(new_id);
         packet.override_authenticator(new_authenticator);
         
-        assert_eq!(exepcted_bytes, packet.to_bytes());
+        assert_eq!(exepcted_bytes, packet.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_radius_packet_to_bytes_too_long() {
+        let dict = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessAccept);
+        for _ in 0..15 {
+            packet.add_attribute(RadiusAttribute::create_by_name(&dict, "Class", vec![1; 253]).unwrap());
+        }
+        assert_eq!(3845, packet.to_bytes().unwrap().len());
+
+        packet.add_attribute(RadiusAttribute::create_by_name(&dict, "Class", vec![1; 253]).unwrap());
+        match packet.to_bytes() {
+            Err(RadiusError::MalformedPacketError { error }) => assert_eq!("packet would be 4100 bytes long, but at most 4096 bytes are allowed", error),
+            other                                            => panic!("Unexpected result: {:?}", other)
+        }
     }
 
     #[test]
@@ -619,7 +2104,561 @@ mod tests {
 
         match packet.override_message_authenticator(new_message_authenticator) {
             Err(_) => assert!(false),
-            _      => assert_eq!(expected_packet_bytes, packet.to_bytes())
+            _      => assert_eq!(expected_packet_bytes, packet.to_bytes().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_encrypted_attributes_round_trip() {
+        let dictionary_path = "./dict_examples/integration_dict";
+        let dict            = Dictionary::from_file(dictionary_path).unwrap();
+
+        let attributes = vec![
+            RadiusAttribute::create_by_name(&dict, "User-Name",       String::from("testing").into_bytes()).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Password",        String::from("very secure password").into_bytes()).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Tunnel-Password", [ &[1], "tunnel password".as_bytes() ].concat()).unwrap()
+        ];
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(attributes);
+        packet.set_secret("secret");
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(packet_bytes, packet.to_bytes().unwrap());
+
+        // Without secret values are left encrypted
+        let encrypted_packet = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(32, encrypted_packet.attribute_by_name("Password").unwrap().value().len());
+        assert_eq!(1,  encrypted_packet.attribute_by_name("Tunnel-Password").unwrap().value()[0]);
+        assert!(encrypted_packet.attribute_by_name("Tunnel-Password").unwrap().value()[1] & 0x80 != 0);
+
+        let decrypted_packet = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", None, DecodeMode::Strict).unwrap();
+        assert_eq!(packet, decrypted_packet);
+    }
+
+    #[test]
+    fn test_encrypted_attributes_truncated() {
+        let dict = Dictionary::from_file("./dictionaries/dictionary").unwrap();
+
+        // Tunnel-Password, which encrypted value is shorter than salt & single block
+        let mut packet_bytes = vec![1, 1, 0, 34, 0, 25, 100, 56, 13, 0, 67, 34, 39, 12, 88, 153, 0, 1, 2, 3, 69, 14, 1, 128, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        for mode in [DecodeMode::Strict, DecodeMode::Lenient] {
+            assert!(RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", None, mode).is_err());
+        }
+
+        // Encrypted value, that is not made of whole blocks
+        packet_bytes.extend_from_slice(&[0; 10]);
+        packet_bytes[3]  = 44;
+        packet_bytes[21] = 24;
+        assert!(RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", None, DecodeMode::Strict).is_err());
+
+        // Tunnel-Password, that carries only a tag, holds empty password
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessAccept);
+        packet.set_attributes(vec![RadiusAttribute::create_by_name(&dict, "Tunnel-Password", vec![1]).unwrap()]);
+        packet.set_secret("secret");
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(vec![69, 3, 1], packet_bytes[20..].to_vec());
+        assert_eq!(packet, RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", None, DecodeMode::Strict).unwrap());
+
+        // Ascend-Send-Secret, that does not fit into single block, is not truncated
+        let legacy_dict = Dictionary::from_file("./dict_examples/legacy_dict").unwrap();
+        let mut packet  = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![RadiusAttribute::create_by_name(&legacy_dict, "Ascend-Send-Secret", vec![1; 17]).unwrap()]);
+        packet.set_secret("secret");
+        assert!(packet.to_bytes().is_err());
+    }
+
+    #[test]
+    fn test_encrypted_attributes_without_secret() {
+        let dict = Dictionary::from_file("./dictionaries/dictionary").unwrap();
+
+        for (name, value) in [("User-Password", String::from("password").into_bytes()), ("Tunnel-Password", [ &[1], "tunnel password".as_bytes() ].concat())] {
+            let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+            packet.set_attributes(vec![RadiusAttribute::create_by_name(&dict, name, value.to_vec()).unwrap()]);
+
+            // Encrypted attribute is not sent in plain text, if packet has no secret
+            assert!(packet.to_bytes().is_err());
+
+            packet.set_secret("secret");
+            let packet_bytes = packet.to_bytes().unwrap();
+            assert!(!packet_bytes.windows(value.len()).any(|window| window == value.as_slice()));
+        }
+    }
+
+    #[test]
+    fn test_encrypted_attributes_reply() {
+        let dictionary_path = "./dict_examples/integration_dict";
+        let dict            = Dictionary::from_file(dictionary_path).unwrap();
+
+        let request_authenticator = vec![0, 25, 100, 56, 13, 0, 67, 34, 39, 12, 88, 153, 0, 1, 2, 3];
+        let mut packet            = RadiusPacket::initialise_packet(TypeCode::AccessAccept);
+        packet.set_attributes(vec![RadiusAttribute::create_by_name(&dict, "Tunnel-Password", [ &[1], "tunnel password".as_bytes() ].concat()).unwrap()]);
+        packet.set_secret("secret");
+        packet.set_request_authenticator(request_authenticator.to_vec());
+
+        let packet_bytes = packet.to_bytes().unwrap();
+
+        let wrong_packet = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", None, DecodeMode::Strict);
+        assert_ne!(Some(&packet), wrong_packet.as_ref().ok());
+
+        let reply_packet = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", Some(&request_authenticator), DecodeMode::Strict).unwrap();
+        assert_eq!(packet, reply_packet);
+    }
+
+    #[test]
+    fn test_concat_attributes() {
+        let dictionary_path = "./dict_examples/integration_dict";
+        let dict            = Dictionary::from_file(dictionary_path).unwrap();
+
+        let eap_message = vec![7; 300];
+        let mut packet  = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![RadiusAttribute::create_by_name(&dict, "EAP-Message", eap_message.to_vec()).unwrap()]);
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(20 + 255 + 49, packet_bytes.len());
+        assert_eq!(&[79, 255],    &packet_bytes[20..22]);
+        assert_eq!(&[79, 49],     &packet_bytes[275..277]);
+
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(1,           packet_from_bytes.attributes().len());
+        assert_eq!(eap_message, packet_from_bytes.attribute_by_name("EAP-Message").unwrap().value());
+    }
+
+    #[test]
+    fn test_long_values() {
+        let dict_str = "ATTRIBUTE Class 25 octets\n\
+                        ATTRIBUTE Extended-Attribute-1 241 extended\n\
+                        ATTRIBUTE Ext-Data 241.5 octets concat\n\
+                        VENDOR Somevendor 10\n\
+                        VENDOR WiMAX 24757 format=1,1,c\n\
+                        BEGIN-VENDOR Somevendor\n\
+                        ATTRIBUTE Somevendor-Data 1 octets concat\n\
+                        END-VENDOR Somevendor\n\
+                        BEGIN-VENDOR WiMAX\n\
+                        ATTRIBUTE WiMAX-Data 2 octets\n\
+                        END-VENDOR WiMAX\n";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![
+            RadiusAttribute::create_by_name(&dict, "Somevendor-Data", vec![2; 300]).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "WiMAX-Data", vec![3; 300]).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Ext-Data", vec![4; 300]).unwrap()
+        ]);
+
+        // Values longer than a single attribute could carry are split across consecutive attributes
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(960,                                          packet_bytes.len());
+        assert_eq!(vec![26, 255, 0, 0, 0, 10, 1, 249],           packet_bytes[20..28].to_vec());
+        assert_eq!(vec![26, 61, 0, 0, 0, 10, 1, 55],             packet_bytes[275..283].to_vec());
+        assert_eq!(vec![26, 255, 0, 0, 96, 181, 2, 249, 128],    packet_bytes[336..345].to_vec());
+        assert_eq!(vec![26, 63, 0, 0, 96, 181, 2, 57, 0],        packet_bytes[591..600].to_vec());
+        assert_eq!(vec![241, 255, 5],                            packet_bytes[654..657].to_vec());
+        assert_eq!(vec![241, 51, 5],                             packet_bytes[909..912].to_vec());
+
+        // Parts are joined back for attributes flagged with concat and vendor attributes with
+        // continuation flag
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(3,               packet_from_bytes.attributes().len());
+        assert_eq!(vec![2; 300],    packet_from_bytes.attribute_by_name("Somevendor-Data").unwrap().value());
+        assert_eq!(vec![3; 300],    packet_from_bytes.attribute_by_name("WiMAX-Data").unwrap().value());
+        assert_eq!(vec![4; 300],    packet_from_bytes.attribute_by_name("Ext-Data").unwrap().value());
+        assert!(RadiusPacketRef::from_bytes(&packet_bytes).unwrap().verify_attributes(&dict).is_ok());
+
+        // Vendor attribute with continuation flag has to be followed by the rest of its value
+        let broken_bytes = [ &[1, 0, 0, 29], &packet_bytes[4..20], &[26, 9, 0, 0, 96, 181, 2, 3, 128] ].concat();
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &broken_bytes).is_err());
+    }
+
+    #[test]
+    fn test_long_values_not_concat() {
+        let dict_str = "ATTRIBUTE Class 25 octets\n\
+                        ATTRIBUTE Extended-Attribute-1 241 extended\n\
+                        ATTRIBUTE Ext-Info 241.6 octets\n\
+                        VENDOR Somevendor 10\n\
+                        BEGIN-VENDOR Somevendor\n\
+                        ATTRIBUTE Somevendor-Info 2 octets\n\
+                        END-VENDOR Somevendor\n";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        // Value, that fills single attribute, round-trips as is
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![
+            RadiusAttribute::create_by_name(&dict, "Class", vec![1; 253]).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Somevendor-Info", vec![2; 247]).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Ext-Info", vec![3; 252]).unwrap()
+        ]);
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(20 + 3 * 255, packet_bytes.len());
+        assert_eq!(packet.attributes(), RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap().attributes());
+
+        // Longer value would not be joined back when packet is decoded, so it is rejected instead
+        // of being split
+        for (name, length) in [("Class", 254), ("Somevendor-Info", 248), ("Ext-Info", 253)] {
+            let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+            packet.set_attributes(vec![RadiusAttribute::create_by_name(&dict, name, vec![1; length]).unwrap()]);
+            assert!(packet.to_bytes().is_err());
+        }
+    }
+
+    #[test]
+    fn test_vendor_attributes_formats() {
+        let dict = Dictionary::from_str("VENDOR Somevendor 10\nVENDOR USR 429 format=4,0\nVENDOR Lucent 4846 format=2,1\nVENDOR Starent 8164 format=2,2\nVENDOR WiMAX 24757 format=1,1,c").unwrap();
+
+        let vendor_attributes = vec![(1, vec![116, 101, 115, 116])];
+        let expected_bytes    = vec![
+            (10,    vec![0, 0, 0, 10,    1, 6, 116, 101, 115, 116]),
+            (429,   vec![0, 0, 1, 173,   0, 0, 0, 1, 116, 101, 115, 116]),
+            (4846,  vec![0, 0, 18, 238,  0, 1, 7, 116, 101, 115, 116]),
+            (8164,  vec![0, 0, 31, 228,  0, 1, 0, 8, 116, 101, 115, 116]),
+            (24757, vec![0, 0, 96, 181,  1, 7, 0, 116, 101, 115, 116])
+        ];
+
+        for (vendor_id, bytes) in expected_bytes {
+            let vendor = dict.vendor_by_id(vendor_id).unwrap();
+
+            assert_eq!(bytes,                                  vendor_attributes_to_bytes(vendor, &vendor_attributes).unwrap());
+            assert_eq!((vendor_id, vendor_attributes.to_vec()), bytes_to_vendor_attributes(&dict, &bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_vendor_attributes_errors() {
+        let dict = Dictionary::from_str("VENDOR Somevendor 10\nVENDOR USR 429 format=4,0").unwrap();
+
+        let somevendor = dict.vendor_by_name("Somevendor").unwrap();
+        assert_eq!(vec![0, 0, 0, 10, 1, 3, 7, 2, 4, 8, 9], vendor_attributes_to_bytes(somevendor, &[(1, vec![7]), (2, vec![8, 9])]).unwrap());
+        assert!(vendor_attributes_to_bytes(somevendor, &[(256, vec![7])]).is_err());
+        assert!(vendor_attributes_to_bytes(somevendor, &[(1, vec![7; 250])]).is_err());
+
+        let usr = dict.vendor_by_name("USR").unwrap();
+        assert!(vendor_attributes_to_bytes(usr, &[(1, vec![7]), (2, vec![8])]).is_err());
+
+        assert!(bytes_to_vendor_attributes(&dict, &[0, 0, 0, 10]).is_err());
+        assert!(bytes_to_vendor_attributes(&dict, &[0, 0, 0, 10, 1, 9, 7]).is_err());
+        assert!(bytes_to_vendor_attributes(&dict, &[0, 0, 0, 10, 1, 1, 7]).is_err());
+        // Unknown vendors are decoded with RFC 2865 format
+        assert_eq!((11, vec![(1, vec![7])]), bytes_to_vendor_attributes(&dict, &[0, 0, 0, 11, 1, 3, 7]).unwrap());
+    }
+
+    #[test]
+    fn test_vendor_specific_attributes() {
+        let dict_str = "ATTRIBUTE User-Name 1 string\nATTRIBUTE Vendor-Specific 26 vsa\nVENDOR Somevendor 10\nVENDOR Lucent 4846 format=2,1\nBEGIN-VENDOR Somevendor\nATTRIBUTE Somevendor-Name 1 string\nATTRIBUTE Somevendor-Number 2 integer\nEND-VENDOR Somevendor\nBEGIN-VENDOR Lucent\nATTRIBUTE Lucent-Max-Shared-Users 300 integer\nEND-VENDOR Lucent";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let vendor_attr = RadiusAttribute::create_by_name(&dict, "Somevendor-Name", String::from("test").into_bytes()).unwrap();
+        assert_eq!(26,       vendor_attr.id());
+        assert_eq!(1,        vendor_attr.code());
+        assert_eq!(Some(10), vendor_attr.vendor_id());
+
+        let user_name = RadiusAttribute::create_by_name(&dict, "User-Name", String::from("user").into_bytes()).unwrap();
+        assert_eq!(1,    user_name.id());
+        assert_eq!(None, user_name.vendor_id());
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![
+            user_name,
+            vendor_attr,
+            RadiusAttribute::create_by_name(&dict, "Lucent-Max-Shared-Users", integer_to_bytes(3)).unwrap()
+        ]);
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(vec![26, 12, 0, 0, 0, 10, 1, 6, 116, 101, 115, 116], packet_bytes[26..38].to_vec());
+        assert_eq!(vec![26, 13, 0, 0, 18, 238, 1, 44, 7, 0, 0, 0, 3],   packet_bytes[38..51].to_vec());
+
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(packet, packet_from_bytes);
+        assert_eq!(Some(1),  packet_from_bytes.attribute_by_name("User-Name").map(|attr| attr.id()));
+        assert_eq!(Some(26), packet_from_bytes.attribute_by_name("Somevendor-Name").map(|attr| attr.id()));
+
+        // Single Vendor-Specific attribute could carry several vendor attributes
+        let vsa_bytes  = [ &[1, 0, 0, 35], &packet_bytes[4..20], &[26, 15, 0, 0, 0, 10, 1, 3, 97, 2, 6, 0, 0, 0, 2] ].concat();
+        let vsa_packet = RadiusPacket::initialise_packet_from_bytes(&dict, &vsa_bytes).unwrap();
+        assert_eq!(2,                vsa_packet.attributes().len());
+        assert_eq!(vec![97],         vsa_packet.attribute_by_name("Somevendor-Name").unwrap().value());
+        assert_eq!(vec![0, 0, 0, 2], vsa_packet.attribute_by_name("Somevendor-Number").unwrap().value());
+        assert!(RadiusPacketRef::from_bytes(&vsa_bytes).unwrap().verify_attributes(&dict).is_ok());
+
+        // Vendor attributes, that are not in dictionary, are reported or kept as unknown
+        let mut unknown_bytes = vsa_bytes.to_vec();
+        unknown_bytes[29]     = 9;
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &unknown_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&unknown_bytes).unwrap().verify_known_attributes(&dict).is_err());
+
+        let mut lenient_packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &unknown_bytes, DecodeMode::Lenient).unwrap();
+        let unknown_attr       = lenient_packet.attribute_by_name("Attr-26.10.9").unwrap();
+        assert!(unknown_attr.is_unknown());
+        assert_eq!((26, 9, Some(10)), (unknown_attr.id(), unknown_attr.code(), unknown_attr.vendor_id()));
+        // Each vendor attribute is sent back in its own Vendor-Specific attribute
+        let lenient_bytes = lenient_packet.to_bytes().unwrap();
+        assert_eq!(vec![26, 12, 0, 0, 0, 10, 9, 6, 0, 0, 0, 2], lenient_bytes[29..41].to_vec());
+        assert_eq!(lenient_packet, RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &lenient_bytes, DecodeMode::Lenient).unwrap());
+
+        // Vendor-Specific attribute of undeclared vendor is kept as is
+        let undeclared_bytes = [ &[1, 0, 0, 29], &packet_bytes[4..20], &[26, 9, 0, 0, 0, 11, 1, 3, 97] ].concat();
+        let undeclared       = RadiusPacket::initialise_packet_from_bytes(&dict, &undeclared_bytes).unwrap();
+        assert_eq!(vec![0, 0, 0, 11, 1, 3, 97], undeclared.attribute_by_name("Vendor-Specific").unwrap().value());
+    }
+
+    #[test]
+    fn test_extended_attributes() {
+        let dict_str = "ATTRIBUTE User-Name 1 string\n\
+                        ATTRIBUTE Extended-Attribute-1 241 extended\n\
+                        ATTRIBUTE Frag-Status 241.1 integer\n\
+                        ATTRIBUTE Extended-Vendor-Specific-1 241.26 evs\n\
+                        ATTRIBUTE Extended-Attribute-5 245 long-extended\n\
+                        ATTRIBUTE Long-Test 245.4 octets\n\
+                        ATTRIBUTE Extended-Vendor-Specific-5 245.26 evs\n\
+                        VENDOR Somevendor 10\n\
+                        BEGIN-VENDOR Somevendor format=Extended-Vendor-Specific-1\n\
+                        ATTRIBUTE Somevendor-Ext-Name 1 string\n\
+                        END-VENDOR Somevendor\n\
+                        BEGIN-VENDOR Somevendor format=Extended-Vendor-Specific-5\n\
+                        ATTRIBUTE Somevendor-Long-Data 2 octets\n\
+                        END-VENDOR Somevendor\n";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let frag_status = RadiusAttribute::create_by_name(&dict, "Frag-Status", integer_to_bytes(2)).unwrap();
+        assert_eq!((241, 1, None),         (frag_status.id(), frag_status.code(), frag_status.vendor_id()));
+        assert_eq!(vec![241, 1],           frag_status.oid());
+        let evs_attr    = RadiusAttribute::create_by_name(&dict, "Somevendor-Ext-Name", String::from("test").into_bytes()).unwrap();
+        assert_eq!((241, 1, Some(10)),     (evs_attr.id(), evs_attr.code(), evs_attr.vendor_id()));
+        assert_eq!(vec![241, 26, 1],       evs_attr.oid());
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![
+            frag_status,
+            evs_attr,
+            RadiusAttribute::create_by_name(&dict, "Long-Test", vec![7; 300]).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Somevendor-Long-Data", vec![8; 300]).unwrap()
+        ]);
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        assert_eq!(665,                                                   packet_bytes.len());
+        assert_eq!(vec![241, 7, 1, 0, 0, 0, 2],                           packet_bytes[20..27].to_vec());
+        assert_eq!(vec![241, 12, 26, 0, 0, 0, 10, 1, 116, 101, 115, 116], packet_bytes[27..39].to_vec());
+        // Long-Extended values are split into fragments, all but the last one have More flag set
+        assert_eq!(vec![245, 255, 4, 128],                                packet_bytes[39..43].to_vec());
+        assert_eq!(vec![245, 53, 4, 0],                                   packet_bytes[294..298].to_vec());
+        assert_eq!(vec![245, 255, 26, 128, 0, 0, 0, 10, 2],               packet_bytes[347..356].to_vec());
+        assert_eq!(vec![245, 63, 26, 0, 0, 0, 0, 10, 2],                  packet_bytes[602..611].to_vec());
+
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(packet, packet_from_bytes);
+        assert_eq!(vec![8; 300], packet_from_bytes.attribute_by_name("Somevendor-Long-Data").unwrap().value());
+
+        let packet_ref = RadiusPacketRef::from_bytes(&packet_bytes).unwrap();
+        assert!(packet_ref.verify_known_attributes(&dict).is_ok());
+        assert!(packet_ref.verify_attributes(&dict).is_ok());
+
+        // Fragment with More flag set has to be followed by the rest of the value
+        let header = &packet_bytes[4..20];
+        for broken_bytes in &[[ &[1, 0, 0, 26], header, &[245, 6, 4, 128, 1, 2] ].concat(), [ &[1, 0, 0, 29], header, &[245, 6, 4, 128, 1, 2, 1, 3, 97] ].concat(), [ &[1, 0, 0, 22], header, &[245, 2] ].concat()] {
+            assert!(RadiusPacket::initialise_packet_from_bytes(&dict, broken_bytes).is_err());
+        }
+
+        // Extended-Types, that are not in dictionary, are reported or kept as unknown
+        let unknown_bytes = [ &[1, 0, 0, 24], header, &[241, 4, 9, 1] ].concat();
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &unknown_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&unknown_bytes).unwrap().verify_known_attributes(&dict).is_err());
+
+        let mut lenient_packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &unknown_bytes, DecodeMode::Lenient).unwrap();
+        assert!(lenient_packet.attribute_by_name("Attr-241.9").unwrap().is_unknown());
+        assert_eq!(unknown_bytes, lenient_packet.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_tlv_attributes() {
+        let dict_str = "ATTRIBUTE User-Name 1 string\nATTRIBUTE Test-TLV 200 tlv\nBEGIN-TLV Test-TLV\nATTRIBUTE Test-TLV-Name 1 string\nATTRIBUTE Test-TLV-Nested 2 tlv\nEND-TLV Test-TLV\nATTRIBUTE Test-TLV-Nested-Number 200.2.1 integer";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        let nested_attr = RadiusAttribute::create_tlv_by_name(&dict, "Test-TLV-Nested", vec![
+            RadiusAttribute::create_by_name(&dict, "Test-TLV-Nested-Number", integer_to_bytes(5)).unwrap()
+        ]).unwrap();
+        let tlv_attr    = RadiusAttribute::create_tlv_by_name(&dict, "Test-TLV", vec![
+            RadiusAttribute::create_by_name(&dict, "Test-TLV-Name", String::from("test").into_bytes()).unwrap(),
+            nested_attr
+        ]).unwrap();
+        assert_eq!(vec![1, 6, 116, 101, 115, 116, 2, 8, 1, 6, 0, 0, 0, 5], tlv_attr.value());
+
+        // Children have to be defined as children of TLV in dictionary
+        assert_eq!(None, RadiusAttribute::create_tlv_by_name(&dict, "Test-TLV", vec![RadiusAttribute::create_by_name(&dict, "User-Name", vec![1]).unwrap()]));
+
+        // Child, that does not fit into single TLV, is rejected instead of wrapping its length
+        assert_eq!(None, RadiusAttribute::create_tlv_by_name(&dict, "Test-TLV", vec![RadiusAttribute::create_by_name(&dict, "Test-TLV-Name", vec![116; 300]).unwrap()]));
+        assert!(RadiusAttribute::create_tlv_by_name(&dict, "Test-TLV", vec![RadiusAttribute::create_by_name(&dict, "Test-TLV-Name", vec![116; 253]).unwrap()]).is_some());
+        assert_eq!(None, RadiusAttribute::create_tlv_by_name(&dict, "User-Name", Vec::new()));
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![tlv_attr]);
+
+        let packet_bytes      = packet.to_bytes().unwrap();
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(packet, packet_from_bytes);
+
+        let nested_number = packet_from_bytes.attribute_by_name("Test-TLV").unwrap().child_by_name("Test-TLV-Nested").unwrap().child_by_name("Test-TLV-Nested-Number").unwrap();
+        assert_eq!(5, nested_number.original_integer_value(&Some(SupportedAttributeTypes::Integer)).unwrap());
+
+        // Unknown TLV child is reported in Strict mode and kept as unknown attribute in Lenient mode
+        let mut broken_bytes = packet_bytes.to_vec();
+        broken_bytes[22]     = 9;
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &broken_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&packet_bytes).unwrap().verify_known_attributes(&dict).is_ok());
+        assert!(RadiusPacketRef::from_bytes(&broken_bytes).unwrap().verify_known_attributes(&dict).is_err());
+
+        let mut lenient_packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &broken_bytes, DecodeMode::Lenient).unwrap();
+        let unknown_child      = lenient_packet.attribute_by_name("Test-TLV").unwrap().child_by_name("Attr-200.9").unwrap();
+        assert!(unknown_child.is_unknown());
+        assert_eq!(vec![116, 101, 115, 116], unknown_child.value());
+        assert_eq!(1,                        lenient_packet.attribute_by_name("Test-TLV").unwrap().child_by_name("Test-TLV-Nested").unwrap().children().len());
+        assert_eq!(broken_bytes,             lenient_packet.to_bytes().unwrap());
+
+        // Malformed TLV value is kept as raw octets in Lenient mode
+        let mut malformed_bytes = packet_bytes.to_vec();
+        malformed_bytes[23]     = 40;
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &malformed_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&malformed_bytes).unwrap().verify_known_attributes(&dict).is_err());
+
+        let lenient_packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &malformed_bytes, DecodeMode::Lenient).unwrap();
+        assert!(lenient_packet.attribute_by_name("Test-TLV").unwrap().children().is_empty());
+        assert_eq!(&malformed_bytes[22..], lenient_packet.attribute_by_name("Test-TLV").unwrap().value());
+    }
+
+    #[test]
+    fn test_array_attributes() {
+        let dict_str = "ATTRIBUTE Some-Addresses 200 ipaddr array\nATTRIBUTE Test-TLV 201 tlv\nBEGIN-TLV Test-TLV\nATTRIBUTE Test-TLV-Numbers 1 short array\nEND-TLV Test-TLV";
+        let dict     = Dictionary::from_str(dict_str).unwrap();
+
+        // Array is decoded into attributes, that hold single element each
+        let packet_bytes = [ &[1, 0, 0, 38], &[0; 16][..], &[200, 10, 192, 168, 0, 1, 10, 0, 0, 1, 201, 8, 1, 6, 0, 1, 0, 2] ].concat();
+        assert!(RadiusPacketRef::from_bytes(&packet_bytes).unwrap().verify_attributes(&dict).is_ok());
+
+        let packet    = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        let addresses: Vec<String> = packet.attributes().iter().filter(|attr| attr.name() == "Some-Addresses").map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)).unwrap()).collect();
+        assert_eq!(vec!["192.168.0.1", "10.0.0.1"], addresses);
+
+        let numbers: Vec<u64> = packet.attribute_by_name("Test-TLV").unwrap().children().iter().map(|child| child.original_integer_value(&Some(SupportedAttributeTypes::Short)).unwrap()).collect();
+        assert_eq!(vec![1, 2], numbers);
+
+        // Elements are sent back in their own attributes
+        let mut packet = packet;
+        assert_eq!(vec![200, 6, 192, 168, 0, 1, 200, 6, 10, 0, 0, 1], packet.to_bytes().unwrap()[20..32].to_vec());
+
+        // Value, that is not made of whole elements, is reported
+        let broken_bytes = [ &[1, 0, 0, 27], &[0; 16][..], &[200, 7, 192, 168, 0, 1, 10] ].concat();
+        assert!(RadiusPacketRef::from_bytes(&broken_bytes).unwrap().verify_attributes(&dict).is_err());
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &broken_bytes).unwrap().attributes()[0].verify_original_value(&Some(SupportedAttributeTypes::IPv4Addr)).is_err());
+    }
+
+    #[test]
+    fn test_struct_attributes() {
+        let dict = Dictionary::from_file("./dict_examples/v4_dict").unwrap();
+
+        let civic_attr    = RadiusAttribute::create_struct_by_name(&dict, "Location-Civic", vec![
+            RadiusAttribute::create_by_name(&dict, "Location-Civic-Country", short_to_bytes(49)).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Location-Civic-Address", String::from("Main St").into_bytes()).unwrap()
+        ]).unwrap();
+        let location_attr = RadiusAttribute::create_struct_by_name(&dict, "Location", vec![
+            RadiusAttribute::create_by_name(&dict, "Location-Version", byte_to_bytes(1)).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Location-Kind", byte_to_bytes(1)).unwrap(),
+            RadiusAttribute::create_by_name(&dict, "Location-Floor", short_to_bytes(3)).unwrap(),
+            civic_attr
+        ]).unwrap();
+        assert_eq!(vec![1, 1, 0, 3, 0, 49, 77, 97, 105, 110, 32, 83, 116], location_attr.value());
+
+        // Members have to be defined inside struct in dictionary
+        assert_eq!(None, RadiusAttribute::create_struct_by_name(&dict, "Location-Civic", vec![RadiusAttribute::create_by_name(&dict, "User-Name", vec![1]).unwrap()]));
+        assert_eq!(None, RadiusAttribute::create_struct_by_name(&dict, "User-Name", Vec::new()));
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+        packet.set_attributes(vec![location_attr]);
+
+        let packet_bytes      = packet.to_bytes().unwrap();
+        let packet_from_bytes = RadiusPacket::initialise_packet_from_bytes(&dict, &packet_bytes).unwrap();
+        assert_eq!(packet, packet_from_bytes);
+
+        let location = packet_from_bytes.attribute_by_name("Location").unwrap();
+        assert_eq!(3,         location.child_by_name("Location-Floor").unwrap().original_integer_value(&Some(SupportedAttributeTypes::Short)).unwrap());
+        assert_eq!("Main St", location.child_by_name("Location-Civic").unwrap().child_by_name("Location-Civic-Address").unwrap().original_string_value(&Some(SupportedAttributeTypes::AsciiString)).unwrap());
+
+        // Truncated member and unknown key value are reported
+        let truncated_bytes = [ &[1, 0, 0, 25], &packet_bytes[4..20], &[200, 5, 1, 1, 0] ].concat();
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &truncated_bytes).is_err());
+        let mut broken_bytes = packet_bytes.to_vec();
+        broken_bytes[23]     = 9;
+        assert!(RadiusPacket::initialise_packet_from_bytes(&dict, &broken_bytes).is_err());
+        assert!(RadiusPacketRef::from_bytes(&broken_bytes).unwrap().verify_known_attributes(&dict).is_err());
+
+        // Struct, that could not be decoded, is kept as raw octets in Lenient mode
+        for bytes in [truncated_bytes, broken_bytes] {
+            let lenient_packet = RadiusPacket::initialise_packet_from_bytes_with_mode(&dict, &bytes, DecodeMode::Lenient).unwrap();
+            assert!(lenient_packet.attribute_by_name("Location").unwrap().children().is_empty());
+            assert_eq!(&bytes[22..], lenient_packet.attribute_by_name("Location").unwrap().value());
+        }
+    }
+
+    #[test]
+    fn test_tagged_attributes() {
+        let dict          = Dictionary::from_file("./dictionaries/dictionary.rfc2868").unwrap();
+        let authenticator = vec![0, 25, 100, 56, 13, 0, 67, 34, 39, 12, 88, 153, 0, 1, 2, 3];
+
+        let tunnel_type     = RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Type", 1, integer_to_bytes(13)).unwrap();
+        let medium_type     = RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Medium-Type", 1, integer_to_bytes(6)).unwrap();
+        let group_id        = RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Private-Group-Id", 1, String::from("100").into_bytes()).unwrap();
+        let tunnel_password = RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Password", 2, String::from("tunnel password").into_bytes()).unwrap();
+        assert_eq!(vec![1, 0, 0, 13],   tunnel_type.value());
+        assert_eq!(vec![1, 49, 48, 48], group_id.value());
+        assert_eq!(Some("VLAN"),        tunnel_type.value_name(&dict).unwrap());
+        assert_eq!(Some("IEEE-802"),    medium_type.value_name(&dict).unwrap());
+
+        // Tag has to be in 0x00-0x1F range, integer value has to fit next to the tag and
+        // attribute has to be flagged with has_tag
+        assert_eq!(None, RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Type", 0x20, integer_to_bytes(13)));
+        assert_eq!(None, RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Type", 1, integer_to_bytes(0x01000000)));
+        assert_eq!(None, RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Type", 1, vec![13]));
+        assert_eq!(None, RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Server-Auth-Id", 0x20, String::from("server").into_bytes()));
+
+        let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessAccept);
+        packet.set_attributes(vec![tunnel_type, medium_type, group_id, tunnel_password]);
+        packet.set_secret("secret");
+        packet.set_request_authenticator(authenticator.to_vec());
+
+        let packet_bytes = packet.to_bytes().unwrap();
+        let reply_packet = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", Some(&authenticator), DecodeMode::Strict).unwrap();
+        assert_eq!(packet, reply_packet);
+
+        let integer_type = Some(SupportedAttributeTypes::Integer);
+        let string_type  = Some(SupportedAttributeTypes::AsciiString);
+        assert_eq!((1, 13),                                    reply_packet.attribute_by_name("Tunnel-Type").unwrap().original_tagged_integer_value(&integer_type).unwrap());
+        assert_eq!((Some(1), String::from("100")),             reply_packet.attribute_by_name("Tunnel-Private-Group-Id").unwrap().original_tagged_string_value(&string_type).unwrap());
+        assert_eq!((Some(2), String::from("tunnel password")), reply_packet.attribute_by_name("Tunnel-Password").unwrap().original_tagged_string_value(&string_type).unwrap());
+        assert!(reply_packet.attribute_by_name("Tunnel-Private-Group-Id").unwrap().original_tagged_integer_value(&string_type).is_err());
+
+        // String attribute, which first byte is greater than 0x1F, carries no tag
+        let untagged = RadiusAttribute::create_by_name(&dict, "Tunnel-Private-Group-Id", String::from("100").into_bytes()).unwrap();
+        assert_eq!(None,                        untagged.tag(&string_type));
+        assert_eq!((None, String::from("100")), untagged.original_tagged_string_value(&string_type).unwrap());
+
+        // Tunnel-Password, that is short or empty after the tag, round-trips
+        for password in ["", "a"] {
+            let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessAccept);
+            packet.set_attributes(vec![RadiusAttribute::create_tagged_by_name(&dict, "Tunnel-Password", 3, String::from(password).into_bytes()).unwrap()]);
+            packet.set_secret("secret");
+            packet.set_request_authenticator(authenticator.to_vec());
+
+            let packet_bytes = packet.to_bytes().unwrap();
+            assert_eq!(if password.is_empty() { 23 } else { 20 + 3 + 2 + 16 }, packet_bytes.len());
+
+            let reply_packet = RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", Some(&authenticator), DecodeMode::Strict).unwrap();
+            assert_eq!((Some(3), String::from(password)), reply_packet.attribute_by_name("Tunnel-Password").unwrap().original_tagged_string_value(&string_type).unwrap());
+        }
+
+        // Tagged Tunnel-Password, which encrypted value is cut short after the tag, is rejected
+        for value in [vec![3, 128], vec![3, 128, 1], vec![3, 128, 1, 2, 3, 4, 5]] {
+            let packet_bytes = [ &[2, 1, 0, 20 + 2 + value.len() as u8], authenticator.as_slice(), &[69, 2 + value.len() as u8], value.as_slice() ].concat();
+            assert!(RadiusPacket::initialise_packet_from_bytes_with_secret(&dict, &packet_bytes, "secret", Some(&authenticator), DecodeMode::Lenient).is_err());
         }
     }
 }
@@ -0,0 +1,142 @@
+//! Standard (IETF) RADIUS dictionaries, that are embedded into the crate
+//!
+//! Dictionary files live in `dictionaries/` folder of the crate, so they could also be loaded
+//! from disk with [Dictionary::from_file](crate::protocol::dictionary::Dictionary::from_file)
+//!
+//! Each file holds attributes, that are allocated by its RFC, and VALUEs only for enumerations,
+//! that the RFC itself defines. Attributes, that later RFCs place into RFC 6929 extended spaces,
+//! are not included
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Represents a list of embedded RFC dictionaries
+pub enum StandardDictionary {
+    /// RFC 2865 - Remote Authentication Dial In User Service (RADIUS)
+    Rfc2865,
+    /// RFC 2866 - RADIUS Accounting
+    Rfc2866,
+    /// RFC 2867 - RADIUS Accounting Modifications for Tunnel Protocol Support
+    Rfc2867,
+    /// RFC 2868 - RADIUS Attributes for Tunnel Protocol Support
+    Rfc2868,
+    /// RFC 2869 - RADIUS Extensions
+    Rfc2869,
+    /// RFC 3162 - RADIUS and IPv6
+    Rfc3162,
+    /// RFC 4372 - Chargeable User Identity
+    Rfc4372,
+    /// RFC 4675 - RADIUS Attributes for Virtual LAN and Priority Support
+    Rfc4675,
+    /// RFC 5176 - Dynamic Authorization Extensions to RADIUS
+    Rfc5176,
+    /// RFC 5580 - Carrying Location Objects in RADIUS and Diameter
+    Rfc5580,
+    /// RFC 6572 - RADIUS Support for Proxy Mobile IPv6
+    Rfc6572,
+    /// RFC 6911 - RADIUS Attributes for IPv6 Access Networks
+    Rfc6911,
+    /// RFC 6929 - Remote Authentication Dial In User Service (RADIUS) Protocol Extensions
+    Rfc6929,
+    /// RFC 7268 - RADIUS Attributes for IEEE 802 Networks
+    Rfc7268
+}
+
+impl StandardDictionary {
+    /// Returns every embedded RFC dictionary, in the order they should be loaded in
+    pub fn all() -> &'static [StandardDictionary] {
+        &[
+            StandardDictionary::Rfc2865,
+            StandardDictionary::Rfc2866,
+            StandardDictionary::Rfc2867,
+            StandardDictionary::Rfc2868,
+            StandardDictionary::Rfc2869,
+            StandardDictionary::Rfc3162,
+            StandardDictionary::Rfc4372,
+            StandardDictionary::Rfc4675,
+            StandardDictionary::Rfc5176,
+            StandardDictionary::Rfc5580,
+            StandardDictionary::Rfc6572,
+            StandardDictionary::Rfc6911,
+            StandardDictionary::Rfc6929,
+            StandardDictionary::Rfc7268
+        ]
+    }
+
+    /// Returns file name of the dictionary, ie `dictionary.rfc2865`
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            StandardDictionary::Rfc2865 => "dictionary.rfc2865",
+            StandardDictionary::Rfc2866 => "dictionary.rfc2866",
+            StandardDictionary::Rfc2867 => "dictionary.rfc2867",
+            StandardDictionary::Rfc2868 => "dictionary.rfc2868",
+            StandardDictionary::Rfc2869 => "dictionary.rfc2869",
+            StandardDictionary::Rfc3162 => "dictionary.rfc3162",
+            StandardDictionary::Rfc4372 => "dictionary.rfc4372",
+            StandardDictionary::Rfc4675 => "dictionary.rfc4675",
+            StandardDictionary::Rfc5176 => "dictionary.rfc5176",
+            StandardDictionary::Rfc5580 => "dictionary.rfc5580",
+            StandardDictionary::Rfc6572 => "dictionary.rfc6572",
+            StandardDictionary::Rfc6911 => "dictionary.rfc6911",
+            StandardDictionary::Rfc6929 => "dictionary.rfc6929",
+            StandardDictionary::Rfc7268 => "dictionary.rfc7268"
+        }
+    }
+
+    /// Returns content of the dictionary file
+    pub fn content(&self) -> &'static str {
+        match self {
+            StandardDictionary::Rfc2865 => include_str!("../../dictionaries/dictionary.rfc2865"),
+            StandardDictionary::Rfc2866 => include_str!("../../dictionaries/dictionary.rfc2866"),
+            StandardDictionary::Rfc2867 => include_str!("../../dictionaries/dictionary.rfc2867"),
+            StandardDictionary::Rfc2868 => include_str!("../../dictionaries/dictionary.rfc2868"),
+            StandardDictionary::Rfc2869 => include_str!("../../dictionaries/dictionary.rfc2869"),
+            StandardDictionary::Rfc3162 => include_str!("../../dictionaries/dictionary.rfc3162"),
+            StandardDictionary::Rfc4372 => include_str!("../../dictionaries/dictionary.rfc4372"),
+            StandardDictionary::Rfc4675 => include_str!("../../dictionaries/dictionary.rfc4675"),
+            StandardDictionary::Rfc5176 => include_str!("../../dictionaries/dictionary.rfc5176"),
+            StandardDictionary::Rfc5580 => include_str!("../../dictionaries/dictionary.rfc5580"),
+            StandardDictionary::Rfc6572 => include_str!("../../dictionaries/dictionary.rfc6572"),
+            StandardDictionary::Rfc6911 => include_str!("../../dictionaries/dictionary.rfc6911"),
+            StandardDictionary::Rfc6929 => include_str!("../../dictionaries/dictionary.rfc6929"),
+            StandardDictionary::Rfc7268 => include_str!("../../dictionaries/dictionary.rfc7268")
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::dictionary::{ Dictionary, DictionaryParser, EncryptionType, ParseMode };
+
+    #[test]
+    fn test_standard_dictionaries_are_valid() {
+        for rfc in StandardDictionary::all() {
+            let mut parser = DictionaryParser::new(ParseMode::Strict);
+            parser.parse_str(rfc.content()).unwrap();
+
+            assert!(parser.warnings().is_empty(), "{} has warnings", rfc.file_name());
+        }
+    }
+
+    #[test]
+    fn test_standard_dictionaries_match_files() {
+        let dict = Dictionary::from_file("./dictionaries/dictionary").unwrap();
+
+        assert_eq!(dict, Dictionary::rfc_standard());
+        assert!(dict.validate().is_empty());
+    }
+
+    #[test]
+    fn test_from_rfcs() {
+        let dict = Dictionary::from_rfcs(&[StandardDictionary::Rfc2865, StandardDictionary::Rfc2868]);
+
+        assert!(dict.attribute_by_name("User-Name").is_some());
+        assert!(dict.attribute_by_name("Acct-Status-Type").is_none());
+
+        let tunnel_password = dict.attribute_by_name("Tunnel-Password").unwrap();
+        assert_eq!(69,   tunnel_password.code());
+        assert!(tunnel_password.flags().has_tag());
+        assert_eq!(Some(EncryptionType::TunnelPassword), tunnel_password.flags().encrypt());
+    }
+}
@@ -4,9 +4,9 @@
 use crate::protocol::error::RadiusError;
 
 
-#[cfg(all(feature = "async-radius"))]
+#[cfg(feature = "async-radius")]
 use async_trait::async_trait;
-#[cfg(all(feature = "async-radius"))]
+#[cfg(feature = "async-radius")]
 #[async_trait]
 /// This trait is to be implemented by user, if they are planning to resolve AUTH, ACCT or CoA
 /// RADIUS requests for Async RADIUS Server
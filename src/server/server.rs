@@ -2,12 +2,13 @@
 
 
 use crate::protocol::host::Host;
-use crate::protocol::radius_packet::{ RadiusAttribute, RadiusMsgType, RadiusPacket, TypeCode };
-use crate::protocol::dictionary::Dictionary;
+use crate::protocol::radius_packet::{ verify_packet_length, DecodeMode, RadiusAttribute, RadiusMsgType, RadiusPacket, RadiusPacketRef, TypeCode };
+use crate::protocol::dictionary::{ Dictionary, SharedDictionary };
 use crate::protocol::error::RadiusError;
 
 use crypto::digest::Digest;
 use crypto::md5::Md5;
+use std::sync::Arc;
 
 
 #[derive(Debug)]
@@ -27,10 +28,20 @@ impl Server {
     ///
     /// To be called **first** when creating RADIUS Server instance
     pub fn with_dictionary(dictionary: Dictionary) -> Server {
-        let host = Host::with_dictionary(dictionary);
+        Server::with_host(Host::with_dictionary(dictionary))
+    }
 
+    /// Initialise Server instance with SharedDictionary (other fields would be set to default values)
+    ///
+    /// Dictionary could then be reloaded through any clone of the handle, without rebuilding
+    /// Server instance. To be called **first** when creating RADIUS Server instance
+    pub fn with_shared_dictionary(dictionary: SharedDictionary) -> Server {
+        Server::with_host(Host::with_shared_dictionary(dictionary))
+    }
+
+    fn with_host(host: Host) -> Server {
         Server {
-            host:          host,
+            host,
             allowed_hosts: Vec::new(),
             server:        String::from(""),
             secret:        String::from(""),
@@ -86,6 +97,15 @@ impl Server {
         self.timeout = timeout;
         self
     }
+
+    /// **Optional**
+    ///
+    /// Sets how attributes, that are not defined in dictionary, are treated when requests are
+    /// decoded, otherwise you would have a default value of [DecodeMode::Strict]
+    pub fn set_decode_mode(mut self, decode_mode: DecodeMode) -> Server {
+        self.host.set_decode_mode(decode_mode);
+        self
+    }
     // ===================
 
     /// Returns port of RADIUS server, that receives given type of RADIUS message/packet
@@ -93,6 +113,22 @@ impl Server {
         self.host.port(code)
     }
 
+    /// Returns snapshot of the dictionary, that is currently in use
+    ///
+    /// Snapshot is not affected by later reloads, so it is safe to hold on to it while
+    /// processing a single request. Other Server functions take their own snapshot on every
+    /// call, so to decode request & create reply attributes with the same dictionary, pass this
+    /// snapshot to [initialise_packet_from_bytes_with_dictionary](Server::initialise_packet_from_bytes_with_dictionary)
+    /// and [RadiusAttribute] constructors
+    pub fn dictionary_snapshot(&self) -> Arc<Dictionary> {
+        self.host.dictionary_snapshot()
+    }
+
+    /// Returns dictionary handle, which could be used to reload dictionary (see [SharedDictionary::reload])
+    pub fn dictionary(&self) -> &SharedDictionary {
+        self.host.dictionary()
+    }
+
     /// Returns hostname/FQDN of RADIUS Server
     pub fn server(&self) -> &str {
         &self.server
@@ -127,20 +163,41 @@ impl Server {
         self.host.create_attribute_by_id(attribute_id, value)
     }
 
+    /// Creates RADIUS packet attribute by name, that holds VALUE with given name (ie `Service-Type`
+    /// & `Framed-User`), both are defined in dictionary file
+    pub fn create_attribute_by_value_name(&self, attribute_name: &str, value_name: &str) -> Result<RadiusAttribute, RadiusError> {
+        self.host.create_attribute_by_value_name(attribute_name, value_name)
+    }
+
+    /// Creates tagged RADIUS packet attribute by name (ie `Tunnel-Type`), that is defined in
+    /// dictionary file and flagged with **has_tag**
+    ///
+    /// For example, see [Client](crate::client::client::Client::create_tagged_attribute_by_name)
+    pub fn create_tagged_attribute_by_name(&self, attribute_name: &str, tag: u8, value: Vec<u8>) -> Result<RadiusAttribute, RadiusError> {
+        self.host.create_tagged_attribute_by_name(attribute_name, tag, value)
+    }
+
     /// Creates reply RADIUS packet
     ///
     /// Similar to [Client's create_packet()](crate::client::client::Client::create_packet), however also sets correct packet ID and authenticator
-    pub fn create_reply_packet(&self, reply_code: TypeCode, attributes: Vec<RadiusAttribute>, request: &mut [u8]) -> RadiusPacket {
+    ///
+    /// Returns an error, if request is not a valid RADIUS packet or reply attributes do not fit
+    /// into RADIUS packet
+    pub fn create_reply_packet(&self, reply_code: TypeCode, attributes: Vec<RadiusAttribute>, request: &mut [u8]) -> Result<RadiusPacket, RadiusError> {
+        verify_packet_length(request)?;
+
         let mut reply_packet = RadiusPacket::initialise_packet(reply_code);
         reply_packet.set_attributes(attributes);
+        reply_packet.set_secret(&self.secret);
+        reply_packet.set_request_authenticator(request[4..20].to_vec());
 
         // We can only create new authenticator after we set reply packet ID to the request's ID
         reply_packet.override_id(request[1]);
 
-        let authenticator = self.create_reply_authenticator(&reply_packet.to_bytes(), &request[4..20]);
+        let authenticator = self.create_reply_authenticator(&reply_packet.to_bytes()?, &request[4..20]);
         reply_packet.override_authenticator(authenticator);
 
-        reply_packet
+        Ok(reply_packet)
     }
 
     fn create_reply_authenticator(&self, raw_reply_packet: &[u8], request_authenticator: &[u8]) -> Vec<u8> {
@@ -149,9 +206,9 @@ impl Server {
         let mut authenticator = [0; 16];
 
         md5_hasher.input(&raw_reply_packet[0..4]); // Append reply's   type code, reply ID and reply length
-        md5_hasher.input(&request_authenticator);  // Append request's authenticator
+        md5_hasher.input(request_authenticator);   // Append request's authenticator
         md5_hasher.input(&raw_reply_packet[20..]); // Append reply's   attributes
-        md5_hasher.input(&self.secret.as_bytes()); // Append server's  secret. Possibly it should be client's secret, which sould be stored together with allowed hostnames ?
+        md5_hasher.input(self.secret.as_bytes());  // Append server's  secret. Possibly it should be client's secret, which sould be stored together with allowed hostnames ?
         
         md5_hasher.result(&mut authenticator);
         // ----------------
@@ -161,13 +218,20 @@ impl Server {
 
     /// Verifies incoming RADIUS packet:
     ///
-    /// Server would try to build RadiusPacket from raw bytes, and if it succeeds then packet is
-    /// valid, otherwise would return RadiusError
+    /// Server would check packet & attribute lengths and (unless [DecodeMode::Lenient] is set)
+    /// that every attribute is defined in dictionary, and if it succeeds then packet is valid,
+    /// otherwise would return RadiusError
     pub fn verify_request(&self, request: &[u8]) -> Result<(), RadiusError> {
-        match RadiusPacket::initialise_packet_from_bytes(&self.host.dictionary(), request) {
-            Err(err) => Err(err),
-            _        => Ok(())
-        }
+        self.parse_request(request).map(|_| ())
+    }
+
+    /// Verifies incoming RADIUS packet the same way, as [verify_request](Server::verify_request),
+    /// and on success returns its view, that borrows given bytes
+    ///
+    /// View could then be checked further (ie with [RadiusPacketRef::verify_attributes] and
+    /// [RadiusPacketRef::verify_message_authenticator]) without parsing packet again
+    pub fn parse_request<'a>(&self, request: &'a [u8]) -> Result<RadiusPacketRef<'a>, RadiusError> {
+        self.host.parse_packet(request)
     }
 
     /// Verifies RadiusAttributes's values of incoming RADIUS packet:
@@ -175,15 +239,51 @@ impl Server {
     /// Server would try to build RadiusPacket from raw bytes, and then it would try to restore
     /// RadiusAttribute original value from bytes, based on the attribute data type, see [SupportedAttributeTypes](crate::protocol::dictionary::SupportedAttributeTypes)
     pub fn verify_request_attributes(&self, request: &[u8]) -> Result<(), RadiusError> {
-        self.host.verify_packet_attributes(&request)
+        self.host.verify_packet_attributes(request)
+    }
+
+    /// Gets the tag & original value as an Integer
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute of type integer, that is flagged
+    /// with **has_tag** (ie `Tunnel-Type`)
+    pub fn radius_attr_original_tagged_integer_value(&self, attribute: &RadiusAttribute) -> Result<(u8, u64), RadiusError> {
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
+        attribute.original_tagged_integer_value(dict_attr.code_type())
+    }
+
+    /// Gets the tag (if attribute carries one) & original value as a String
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute, that is flagged with **has_tag**
+    /// (ie `Tunnel-Private-Group-Id`)
+    pub fn radius_attr_original_tagged_string_value(&self, attribute: &RadiusAttribute) -> Result<(Option<u8>, String), RadiusError> {
+        let dict_attr = self.host.dictionary_attribute(attribute).ok_or_else(|| RadiusError::MalformedAttributeError {error: format!("No attribute with name: {} found in dictionary", attribute.name())} )?;
+        attribute.original_tagged_string_value(dict_attr.code_type())
+    }
+
+    /// Gets the name of the VALUE, that RadiusAttribute value represents (ie `Framed-User` for
+    /// `Service-Type = 2`)
+    ///
+    /// If the RadiusAttribute respresents dictionary attribute of type: integer, byte, short or integer64
+    pub fn radius_attr_value_name(&self, attribute: &RadiusAttribute) -> Result<Option<String>, RadiusError> {
+        self.host.attribute_value_name(attribute)
     }
 
     /// Initialises RadiusPacket from bytes
     ///
     /// Unlike [verify_request](Server::verify_request), on success this function would return
     /// RadiusPacket
+    ///
+    /// Values of attributes, that are flagged with **encrypt=** in dictionary (ie User-Password),
+    /// are decrypted with server's secret
     pub fn initialise_packet_from_bytes(&self, request: &[u8]) -> Result<RadiusPacket, RadiusError> {
-        self.host.initialise_packet_from_bytes(request)
+        self.host.initialise_packet_from_bytes_with_secret(request, &self.secret, None)
+    }
+
+    /// Initialises RadiusPacket from bytes the same way, as [initialise_packet_from_bytes](Server::initialise_packet_from_bytes),
+    /// but with given dictionary snapshot (see [dictionary](Server::dictionary)) instead of the
+    /// one, that is currently in use
+    pub fn initialise_packet_from_bytes_with_dictionary(&self, dictionary: &Dictionary, request: &[u8]) -> Result<RadiusPacket, RadiusError> {
+        self.host.initialise_packet_from_bytes_with_dictionary(dictionary, request, &self.secret, None)
     }
 
     /// Checks if host from where Server received RADIUS request is allowed host, meaning RADIUS
@@ -199,6 +299,7 @@ impl Server {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tools::integer_to_bytes;
 
     #[test]
     fn test_add_allowed_hosts_and_add_request_handler() {
@@ -210,4 +311,98 @@ mod tests {
 
         assert_eq!(server.allowed_hosts().len(), 1);
     }
+
+    #[test]
+    fn test_reload_shared_dictionary() {
+        let dictionary = SharedDictionary::new(Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap());
+        let server     = Server::with_shared_dictionary(dictionary.clone())
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"));
+
+        let attribute  = server.create_attribute_by_name("Test-IP", vec![192, 168, 0, 1]).unwrap();
+        let snapshot   = server.dictionary_snapshot();
+        dictionary.reload_from_file("./dict_examples/integration_dict").unwrap();
+
+        assert_eq!(25, attribute.id());
+        assert!(snapshot.attribute_by_name("Test-IP").is_some());
+        assert!(server.create_attribute_by_name("Test-IP", vec![192, 168, 0, 1]).is_err());
+        assert!(server.create_attribute_by_name("Acct-Session-Time", vec![0, 0, 0, 1]).is_ok());
+    }
+
+    #[test]
+    fn test_dictionary_snapshot_across_request_and_reply() {
+        let dictionary = SharedDictionary::new(Dictionary::from_file("./dict_examples/integration_dict").unwrap());
+        let server     = Server::with_shared_dictionary(dictionary.clone())
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"));
+
+        // Access-Request with User-Name = "test"
+        let mut request = [1, 1, 0, 26, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 1, 6, 116, 101, 115, 116];
+
+        // Dictionary is reloaded while request is being processed
+        let snapshot     = server.dictionary_snapshot();
+        let request_data = server.initialise_packet_from_bytes_with_dictionary(&snapshot, &request).unwrap();
+        dictionary.reload_from_file("./dict_examples/test_dictionary_dict").unwrap();
+
+        let reply_attribute = RadiusAttribute::try_create_by_name(&snapshot, "Reply-Message", String::from("hello").into_bytes()).unwrap();
+        let reply_packet    = server.create_reply_packet(TypeCode::AccessAccept, vec![reply_attribute], &mut request).unwrap();
+
+        assert_eq!(vec![116, 101, 115, 116], request_data.attribute_by_name("User-Name").unwrap().value());
+        assert_eq!(1,                        reply_packet.attributes().len());
+        assert!(server.create_attribute_by_name("Reply-Message", vec![1]).is_err());
+        assert!(server.initialise_packet_from_bytes_with_dictionary(&snapshot, &request).is_ok());
+    }
+
+    #[test]
+    fn test_parse_request() {
+        let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let server     = Server::with_dictionary(dictionary)
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"));
+
+        // Access-Request with User-Name = "test" (and unknown attribute 200)
+        let request         = [1, 1, 0, 26, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 1, 6, 116, 101, 115, 116];
+        let unknown_request = [1, 1, 0, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 1, 6, 116, 101, 115, 116, 200, 5, 1, 2, 3];
+
+        let request_ref = server.parse_request(&request).unwrap();
+        assert_eq!(1, request_ref.id());
+        assert!(request_ref.verify_attributes(&server.dictionary_snapshot()).is_ok());
+        assert!(request_ref.verify_message_authenticator("secret").is_err());
+        assert!(server.parse_request(&unknown_request).is_err());
+    }
+
+    #[test]
+    fn test_lenient_decode_mode() {
+        let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+        let strict     = Server::with_dictionary(dictionary.clone())
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"));
+        let lenient    = Server::with_dictionary(dictionary)
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"))
+            .set_decode_mode(DecodeMode::Lenient);
+
+        // Access-Request with User-Name = "test" & unknown attribute 200
+        let request = [1, 1, 0, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 1, 6, 116, 101, 115, 116, 200, 5, 1, 2, 3];
+
+        assert!(strict.verify_request(&request).is_err());
+        assert!(strict.initialise_packet_from_bytes(&request).is_err());
+        assert!(lenient.verify_request(&request).is_ok());
+        assert!(lenient.verify_request_attributes(&request).is_ok());
+        assert!(lenient.initialise_packet_from_bytes(&request).unwrap().attribute_by_id(200).unwrap().is_unknown());
+    }
+
+    #[test]
+    fn test_tagged_attributes() {
+        let dictionary = Dictionary::from_file("./dictionaries/dictionary.rfc2868").unwrap();
+        let server     = Server::with_dictionary(dictionary)
+            .set_server(String::from("0.0.0.0"))
+            .set_secret(String::from("secret"));
+
+        let tunnel_type = server.create_tagged_attribute_by_name("Tunnel-Type", 1, integer_to_bytes(13)).unwrap();
+        let group_id    = server.create_tagged_attribute_by_name("Tunnel-Private-Group-Id", 1, String::from("100").into_bytes()).unwrap();
+        assert_eq!((1, 13),                        server.radius_attr_original_tagged_integer_value(&tunnel_type).unwrap());
+        assert_eq!((Some(1), String::from("100")), server.radius_attr_original_tagged_string_value(&group_id).unwrap());
+        assert!(server.radius_attr_original_tagged_integer_value(&group_id).is_err());
+    }
 }
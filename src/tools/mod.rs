@@ -7,7 +7,7 @@ use crypto::digest::Digest;
 use crypto::md5::Md5;
 
 use std::str::FromStr;
-use std::net::Ipv6Addr;
+use std::net::{ IpAddr, Ipv4Addr, Ipv6Addr };
 use std::convert::TryInto;
 
 use crate::protocol::error::RadiusError;
@@ -30,6 +30,10 @@ pub fn ipv6_string_to_bytes(ipv6: &str) -> Result<Vec<u8>, RadiusError> {
 
 /// Converts IPv6 bytes into IPv6 string
 pub fn bytes_to_ipv6_string(ipv6: &[u8]) -> Result<String, RadiusError> {
+    if ipv6.len() != 16 && ipv6.len() != 18 {
+        return Err( RadiusError::MalformedIpAddrError { error: format!("Malformed IPv6: {:?}", ipv6) } )
+    }
+
     if ipv6.len() == 18 {
         // Case with subnet
         let subnet = u16_from_be_bytes(&ipv6[0..2]);
@@ -85,6 +89,143 @@ pub fn bytes_to_ipv4_string(ipv4: &[u8]) -> Result<String, RadiusError> {
     Ok(ipv4_string.join("."))
 }
 
+/// Converts IPv4 prefix string (ie 10.0.0.0/8) into vector of bytes
+///
+/// Should be used for any Attribute of type **ipv4prefix** to ensure value is encoded correctly
+pub fn ipv4_prefix_string_to_bytes(ipv4_prefix: &str) -> Result<Vec<u8>, RadiusError> {
+    let parsed_prefix: Vec<&str> = ipv4_prefix.trim().split('/').collect();
+    if parsed_prefix.len() != 2 {
+        return Err( RadiusError::MalformedIpAddrError { error: format!("IPv4 prefix requires prefix length: {}", ipv4_prefix) } )
+    }
+
+    let ipv4_address  = Ipv4Addr::from_str(parsed_prefix[0]).map_err(|error| RadiusError::MalformedIpAddrError { error: error.to_string() })?;
+    let prefix_length = match parsed_prefix[1].parse::<u8>() {
+        Ok(prefix_length) if prefix_length <= 32 => prefix_length,
+        _                                        => return Err( RadiusError::MalformedIpAddrError { error: format!("Invalid IPv4 prefix length: {}", ipv4_prefix) } )
+    };
+
+    // RFC 8044: reserved byte, prefix length & 4 bytes of address
+    let mut bytes: Vec<u8> = Vec::with_capacity(6);
+    bytes.push(0);
+    bytes.push(prefix_length);
+    bytes.extend_from_slice(&ipv4_address.octets());
+    Ok(bytes)
+}
+
+/// Converts IPv4 prefix bytes into IPv4 prefix string
+pub fn bytes_to_ipv4_prefix_string(ipv4_prefix: &[u8]) -> Result<String, RadiusError> {
+    if ipv4_prefix.len() != 6 || ipv4_prefix[0] != 0 || ipv4_prefix[1] > 32 {
+        return Err( RadiusError::MalformedIpAddrError { error: format!("Malformed IPv4 prefix: {:?}", ipv4_prefix) } )
+    }
+
+    Ok(format!("{}/{}", bytes_to_ipv4_string(&ipv4_prefix[2..])?, ipv4_prefix[1]))
+}
+
+/// Converts IPv4 or IPv6 Address string into vector of bytes
+///
+/// Should be used for any Attribute of type **combo-ip** to ensure value is encoded correctly
+pub fn combo_ip_string_to_bytes(ip: &str) -> Result<Vec<u8>, RadiusError> {
+    match IpAddr::from_str(ip.trim()).map_err(|error| RadiusError::MalformedIpAddrError { error: error.to_string() })? {
+        IpAddr::V4(ipv4_address) => Ok(ipv4_address.octets().to_vec()),
+        IpAddr::V6(ipv6_address) => Ok(ipv6_address.octets().to_vec())
+    }
+}
+
+/// Converts IPv4 or IPv6 bytes into IP Address string
+pub fn bytes_to_combo_ip_string(ip: &[u8]) -> Result<String, RadiusError> {
+    match ip.len() {
+        4  => bytes_to_ipv4_string(ip),
+        16 => bytes_to_ipv6_string(ip),
+        _  => Err( RadiusError::MalformedIpAddrError { error: format!("Malformed combo-ip: {:?}", ip) } )
+    }
+}
+
+/// Converts MAC Address string (ie 00:01:24:80:b3:9c) into vector of bytes
+///
+/// Should be used for any Attribute of type **ether** to ensure value is encoded correctly.
+/// Both `:` and `-` are accepted as separators
+pub fn ether_string_to_bytes(ether: &str) -> Result<Vec<u8>, RadiusError> {
+    let bytes = hex_groups_to_bytes(ether, &[':', '-'], 1);
+    match bytes {
+        Some(bytes) if bytes.len() == 6 => Ok(bytes),
+        _                               => Err( RadiusError::MalformedAttributeError { error: format!("Malformed MAC address: {}", ether) } )
+    }
+}
+
+/// Converts MAC Address bytes into MAC Address string
+pub fn bytes_to_ether_string(ether: &[u8]) -> Result<String, RadiusError> {
+    if ether.len() != 6 {
+        return Err( RadiusError::MalformedAttributeError { error: format!("Malformed MAC address: {:?}", ether) } )
+    }
+
+    let ether_string: Vec<String> = ether.iter().map(|group| format!("{:02x}", group)).collect();
+    Ok(ether_string.join(":"))
+}
+
+/// Converts Interface-Id string (ie 0000:0000:0000:0001) into vector of bytes
+///
+/// Should be used for any Attribute of type **ifid** to ensure value is encoded correctly
+pub fn ifid_string_to_bytes(ifid: &str) -> Result<Vec<u8>, RadiusError> {
+    let bytes = hex_groups_to_bytes(ifid, &[':'], 2);
+    match bytes {
+        Some(bytes) if bytes.len() == 8 => Ok(bytes),
+        _                               => Err( RadiusError::MalformedAttributeError { error: format!("Malformed Interface-Id: {}", ifid) } )
+    }
+}
+
+/// Converts Interface-Id bytes into Interface-Id string
+pub fn bytes_to_ifid_string(ifid: &[u8]) -> Result<String, RadiusError> {
+    if ifid.len() != 8 {
+        return Err( RadiusError::MalformedAttributeError { error: format!("Malformed Interface-Id: {:?}", ifid) } )
+    }
+
+    let ifid_string: Vec<String> = ifid.chunks_exact(2).map(|group| format!("{:02x}{:02x}", group[0], group[1])).collect();
+    Ok(ifid_string.join(":"))
+}
+
+/// Converts u8 into vector of bytes
+///
+/// Should be used for any Attribute of type **byte** to ensure value is encoded correctly
+pub fn byte_to_bytes(byte: u8) -> Vec<u8> {
+    vec![byte]
+}
+
+/// Converts u16 into vector of bytes
+///
+/// Should be used for any Attribute of type **short** to ensure value is encoded correctly
+pub fn short_to_bytes(short: u16) -> Vec<u8> {
+    short.to_be_bytes().to_vec()
+}
+
+/// Converts short bytes into u16
+pub fn bytes_to_short(short: &[u8; 2]) -> u16 {
+    u16::from_be_bytes(*short)
+}
+
+/// Converts i32 into vector of bytes
+///
+/// Should be used for any Attribute of type **signed** to ensure value is encoded correctly
+pub fn signed_to_bytes(signed: i32) -> Vec<u8> {
+    signed.to_be_bytes().to_vec()
+}
+
+/// Converts signed bytes into i32
+pub fn bytes_to_signed(signed: &[u8; 4]) -> i32 {
+    i32::from_be_bytes(*signed)
+}
+
+/// Converts u64 into vector of bytes
+///
+/// Should be used for any Attribute of type **integer64** to ensure value is encoded correctly
+pub fn integer64_to_bytes(integer64: u64) -> Vec<u8> {
+    integer64.to_be_bytes().to_vec()
+}
+
+/// Converts integer64 bytes into u64
+pub fn bytes_to_integer64(integer64: &[u8; 8]) -> u64 {
+    u64::from_be_bytes(*integer64)
+}
+
 /// Converts u32 into vector of bytes
 ///
 /// Should be used for any Attribute of type **integer** to ensure value is encoded correctly
@@ -172,7 +313,7 @@ pub fn decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Vec<u8>
         prev_result = data_chunk;
     }
 
-    while result[result.len()-1] == 0 {
+    while result.last() == Some(&0) {
         result.pop();
     }
 
@@ -183,7 +324,7 @@ pub fn decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Vec<u8>
 ///
 /// Should be used for RADIUS Tunnel-Password Attribute
 pub fn salt_encrypt_data(data: &[u8], authenticator: &[u8], salt: &[u8], secret: &[u8]) -> Vec<u8> {
-    if data.len() == 0 {
+    if data.is_empty() {
         return Vec::new();
     }
 
@@ -224,6 +365,9 @@ pub fn salt_decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Re
         // There is a Salt or there is a salt & data.len(): Both cases mean "Password is empty"
         return Ok(Vec::new());
     }
+    if data.len() < 18 || !data[2..].chunks_exact(16).remainder().is_empty() {
+        return Err(RadiusError::MalformedAttributeError {error: "salt encrypted attribute is not made of 16 bytes long blocks".to_string()});
+    }
 
     let salted_authenticator = &mut [0u8; 18];
     salted_authenticator[..16].copy_from_slice(authenticator);
@@ -233,7 +377,7 @@ pub fn salt_decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Re
     let mut result      = Vec::with_capacity(data.len()-2);
     let mut prev_result = &salted_authenticator[..];
 
-    for data_chunk in (&data[2..]).chunks_exact(16) {
+    for data_chunk in data[2..].chunks_exact(16) {
         let mut md5 = Md5::new();
         md5.input(secret);
         md5.input(prev_result);
@@ -258,13 +402,49 @@ pub fn salt_decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Re
     Ok(result)
 }
 
+/// Encrypts data with Ascend-Send-Secret method since RADIUS packet is sent in plain text
+///
+/// Should be used for attributes flagged with **encrypt=3** in dictionary. Method allows for
+/// single MD5 block, so data longer than 16 bytes is rejected with an error
+pub fn ascend_encrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Result<Vec<u8>, RadiusError> {
+    if data.len() > 16 {
+        return Err(RadiusError::MalformedAttributeError {error: format!("Ascend-Send-Secret encrypted value could be at most 16 bytes long, but {} bytes were given", data.len())});
+    }
+
+    let mut hash = [0u8; 16];
+    let mut md5  = Md5::new();
+    md5.input(authenticator);
+    md5.input(secret);
+    md5.result(&mut hash);
+
+    for (_hash, _data) in hash.iter_mut().zip(data.iter()) {
+        *_hash ^= _data
+    }
+
+    Ok(hash.to_vec())
+}
+
+/// Decrypts data with Ascend-Send-Secret method since RADIUS packet is sent in plain text
+///
+/// Should be used for attributes flagged with **encrypt=3** in dictionary. Data longer than 16
+/// bytes is rejected with an error
+pub fn ascend_decrypt_data(data: &[u8], authenticator: &[u8], secret: &[u8]) -> Result<Vec<u8>, RadiusError> {
+    let mut result = ascend_encrypt_data(data, authenticator, secret)?;
+
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+
+    Ok(result)
+}
+
 // -----------------------------------------
-fn encrypt_helper<'a:'b, 'b>(mut data: &'a mut [u8], mut result: &'b [u8], mut hash: &mut[u8], secret: &[u8]) {
+fn encrypt_helper<'a:'b, 'b>(mut data: &'a mut [u8], mut result: &'b [u8], hash: &mut[u8], secret: &[u8]) {
     loop {
         let mut md5 = Md5::new();
         md5.input(secret);
         md5.input(result);
-        md5.result(&mut hash);
+        md5.result(hash);
 
         for (_data, _hash) in data.iter_mut().zip(hash.iter()) {
             *_data ^= _hash
@@ -274,7 +454,7 @@ fn encrypt_helper<'a:'b, 'b>(mut data: &'a mut [u8], mut result: &'b [u8], mut h
         result = _prev;
         data   = _current;
 
-        if data.len() == 0 { break }
+        if data.is_empty() { break }
     }
 }
 
@@ -295,6 +475,20 @@ fn encrypt_helper<'a:'b, 'b>(mut data: &'a mut [u8], mut result: &'b [u8], mut h
 //     }
 // }
 
+fn hex_groups_to_bytes(hex_string: &str, separators: &[char], group_bytes: usize) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    for group in hex_string.trim().split(|c| separators.contains(&c)) {
+        if group.len() != group_bytes * 2 || !group.is_ascii() {
+            return None;
+        }
+        for index in 0..group_bytes {
+            bytes.push(u8::from_str_radix(&group[index * 2..index * 2 + 2], 16).ok()?);
+        }
+    }
+    Some(bytes)
+}
+
 fn u16_to_be_bytes(u16_data: u16) -> [u8;2] {
     u16_data.to_be_bytes()
 }
@@ -350,12 +544,70 @@ mod tests {
         assert_eq!(ipv4_string, "192.1.10.1".to_string());
     }
 
+    #[test]
+    fn test_ipv6_bytes_to_string_invalid_length() {
+        assert!(bytes_to_ipv6_string(&[252, 102, 0]).is_err());
+    }
+
+    #[test]
+    fn test_ipv4_prefix_string_to_bytes() {
+        assert_eq!(vec![0, 8, 10, 0, 0, 0], ipv4_prefix_string_to_bytes("10.0.0.0/8").unwrap());
+        assert!(ipv4_prefix_string_to_bytes("10.0.0.0").is_err());
+        assert!(ipv4_prefix_string_to_bytes("10.0.0.0/33").is_err());
+    }
+
+    #[test]
+    fn test_bytes_to_ipv4_prefix_string() {
+        assert_eq!("192.168.0.0/16", bytes_to_ipv4_prefix_string(&[0, 16, 192, 168, 0, 0]).unwrap());
+        assert!(bytes_to_ipv4_prefix_string(&[0, 40, 192, 168, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_combo_ip() {
+        assert_eq!(vec![192, 1, 10, 1], combo_ip_string_to_bytes("192.1.10.1").unwrap());
+        assert_eq!(vec![252, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], combo_ip_string_to_bytes("fc66::1").unwrap());
+
+        assert_eq!("192.1.10.1", bytes_to_combo_ip_string(&[192, 1, 10, 1]).unwrap());
+        assert_eq!("fc66::1",    bytes_to_combo_ip_string(&[252, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]).unwrap());
+        assert!(bytes_to_combo_ip_string(&[192, 1, 10]).is_err());
+    }
+
+    #[test]
+    fn test_ether() {
+        let ether_bytes = vec![0, 1, 36, 128, 179, 156];
+
+        assert_eq!(ether_bytes, ether_string_to_bytes("00:01:24:80:B3:9C").unwrap());
+        assert_eq!(ether_bytes, ether_string_to_bytes("00-01-24-80-b3-9c").unwrap());
+        assert_eq!("00:01:24:80:b3:9c", bytes_to_ether_string(&ether_bytes).unwrap());
+        assert!(ether_string_to_bytes("00:01:24:80:B3").is_err());
+    }
+
+    #[test]
+    fn test_ifid() {
+        let ifid_bytes = vec![0, 0, 0, 0, 0, 0, 0xab, 0x01];
+
+        assert_eq!(ifid_bytes, ifid_string_to_bytes("0000:0000:0000:ab01").unwrap());
+        assert_eq!("0000:0000:0000:ab01", bytes_to_ifid_string(&ifid_bytes).unwrap());
+        assert!(ifid_string_to_bytes("0000:0000:0000").is_err());
+    }
+
+    #[test]
+    fn test_fixed_width_integers() {
+        assert_eq!(vec![7],                         byte_to_bytes(7));
+        assert_eq!(vec![39, 16],                    short_to_bytes(10000));
+        assert_eq!(10000,                           bytes_to_short(&[39, 16]));
+        assert_eq!(vec![255, 255, 255, 254],        signed_to_bytes(-2));
+        assert_eq!(-2,                              bytes_to_signed(&[255, 255, 255, 254]));
+        assert_eq!(vec![0, 0, 0, 1, 0, 0, 0, 0],    integer64_to_bytes(1 << 32));
+        assert_eq!(1 << 32,                         bytes_to_integer64(&[0, 0, 0, 1, 0, 0, 0, 0]));
+    }
+
     #[test]
     fn test_encrypt_data() {
         let secret        = String::from("secret");
         let authenticator = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
 
-        let encrypted_bytes = encrypt_data("password".as_bytes(), &authenticator, &secret.as_bytes());
+        let encrypted_bytes = encrypt_data("password".as_bytes(), &authenticator, secret.as_bytes());
 
         assert_eq!(encrypted_bytes, vec![135, 116, 155, 239, 226, 89, 90, 221, 62, 29, 218, 130, 102, 174, 191, 250]);
     }
@@ -365,7 +617,7 @@ mod tests {
         let secret        = String::from("secret");
         let authenticator = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
 
-        let encrypted_bytes = encrypt_data("a very long password, which will need multiple iterations".as_bytes(), &authenticator, &secret.as_bytes());
+        let encrypted_bytes = encrypt_data("a very long password, which will need multiple iterations".as_bytes(), &authenticator, secret.as_bytes());
         assert_eq!(encrypted_bytes, vec![150, 53, 158, 249, 231, 79, 8, 213, 81, 115, 189, 162, 22, 207, 204, 137, 193,
                    149, 82, 147, 72, 149, 79, 48, 187, 199, 194, 200, 246, 6, 186, 182, 220, 19, 227, 32, 26, 20, 9, 152,
                    63, 40, 41, 91, 212, 22, 158, 54, 91, 247, 151, 67, 250,170, 105, 94, 20, 105, 120, 196, 237, 191, 99, 69]
@@ -378,7 +630,7 @@ mod tests {
         let authenticator = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
         let data          = "a very long password, which will need multiple iterations. a very long password, which will need multiple iterations. a very long password, which will need multiple iterations. a very long password, which will need multiple iterations. a very long passw";
 
-        let encrypted_bytes = encrypt_data(data.as_bytes(), &authenticator, &secret.as_bytes());
+        let encrypted_bytes = encrypt_data(data.as_bytes(), &authenticator, secret.as_bytes());
         assert_eq!(encrypted_bytes, vec![150, 53, 158, 249, 231, 79, 8, 213, 81, 115, 189, 162, 22, 207, 204, 137, 193, 149, 82, 147, 72, 149, 79, 48, 187, 199, 194, 200,
                                          246, 6, 186, 182, 220, 19, 227, 32, 26, 20, 9, 152, 63, 40, 41, 91, 212, 22, 158, 54, 91, 247, 151, 67, 250, 170, 105, 94, 20, 71,
                                          88, 165, 205, 201, 6, 55, 222, 205, 192, 227, 172, 93, 166, 15, 33, 86, 56, 181, 52, 4, 49, 190, 186, 17, 125, 50, 140, 52, 130, 194,
@@ -398,7 +650,7 @@ mod tests {
         let expected_data  = String::from("password");
         let encrypted_data = vec![135, 116, 155, 239, 226, 89, 90, 221, 62, 29, 218, 130, 102, 174, 191, 250];
 
-        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, &secret.as_bytes());
+        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, secret.as_bytes());
 
         assert_eq!(expected_data.as_bytes().to_vec(), decrypted_data);
     }
@@ -413,7 +665,7 @@ mod tests {
         149, 82, 147, 72, 149, 79, 48, 187, 199, 194, 200, 246, 6, 186, 182, 220, 19, 227, 32, 26, 20, 9, 152, 63,
         40, 41, 91, 212, 22, 158, 54, 91, 247, 151, 67, 250,170, 105, 94, 20, 105, 120, 196, 237, 191, 99, 69];
 
-        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, &secret.as_bytes());
+        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, secret.as_bytes());
         assert_eq!(expected_data.as_bytes().to_vec(), decrypted_data);
     }
 
@@ -433,7 +685,7 @@ mod tests {
                                   253, 224, 114, 62, 23, 11, 242, 186, 91, 132, 14, 76, 171, 26, 1, 51, 78, 144, 50, 228, 212, 47, 104, 98, 60, 245, 1, 103, 217, 49, 105,
                                   38, 108, 93, 85, 224, 227, 33, 50, 144, 0, 233, 54, 174, 67, 174, 101, 189, 41];
 
-        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, &secret.as_bytes());
+        let decrypted_data = decrypt_data(&encrypted_data, &authenticator, secret.as_bytes());
         assert_eq!(expected_data.as_bytes().to_vec(), decrypted_data);
     }
 
@@ -491,6 +743,42 @@ mod tests {
         assert_eq!(plaintext_long.to_vec(), salt_decrypt_data(encrypted_data_long, authenticator, secret).unwrap());
     }
 
+    #[test]
+    fn test_salt_decrypt_data_truncated() {
+        let secret               = b"secret";
+        let authenticator: &[u8] = &[0u8; 16];
+
+        let encrypted_data: &[u8] = &[0x85, 0x9a, 0xe3, 0x88, 0x34, 0x49, 0xf2, 0x1e, 0x14, 0x4c, 0x76, 0xc8, 0xb2, 0x1a, 0x1d, 0x4f, 0x0c, 0xdc];
+
+        assert!(salt_decrypt_data(&encrypted_data[..4], authenticator, secret).is_err());
+        assert!(salt_decrypt_data(&encrypted_data[..17], authenticator, secret).is_err());
+        assert!(salt_decrypt_data(&[encrypted_data, &[0x01]].concat(), authenticator, secret).is_err());
+    }
+
+    #[test]
+    fn test_ascend_encrypt_decrypt_data() {
+        let secret               = b"secret";
+        let authenticator: &[u8] = &[0u8; 16];
+        let plaintext: &[u8]     = b"password";
+
+        let encrypted_data = ascend_encrypt_data(plaintext, authenticator, secret).unwrap();
+
+        assert_eq!(16,        encrypted_data.len());
+        assert_eq!(plaintext, ascend_decrypt_data(&encrypted_data, authenticator, secret).unwrap().as_slice());
+
+        // Secret, that does not fit into single block, is not truncated
+        assert!(ascend_encrypt_data(&[1; 17], authenticator, secret).is_err());
+        assert!(ascend_decrypt_data(&[1; 17], authenticator, secret).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_data_malformed() {
+        let secret               = b"secret";
+        let authenticator: &[u8] = &[0u8; 16];
+
+        assert!(decrypt_data(&[0u8; 4], authenticator, secret).is_empty());
+    }
+
     #[test]
     fn test_integer_to_bytes() {
         let integer: u32 = 10000;
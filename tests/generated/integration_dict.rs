@@ -0,0 +1,3648 @@
+// Generated by radius-rust from RADIUS dictionary, do not edit
+
+/// User-Name attribute (1, AsciiString)
+#[allow(dead_code)]
+pub mod user_name {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "User-Name";
+    /// Attribute code
+    pub const CODE: u32 = 1;
+
+    /// Creates User-Name RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds User-Name RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first User-Name RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Password attribute (2, AsciiString)
+#[allow(dead_code)]
+pub mod password {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Password";
+    /// Attribute code
+    pub const CODE: u32 = 2;
+
+    /// Creates Password RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Password RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Password RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// CHAP-Password attribute (3, Octets)
+#[allow(dead_code)]
+pub mod chap_password {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "CHAP-Password";
+    /// Attribute code
+    pub const CODE: u32 = 3;
+
+    /// Creates CHAP-Password RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds CHAP-Password RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first CHAP-Password RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// NAS-IP-Address attribute (4, IPv4Addr)
+#[allow(dead_code)]
+pub mod nas_ip_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-IP-Address";
+    /// Attribute code
+    pub const CODE: u32 = 4;
+
+    /// Creates NAS-IP-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-IP-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-IP-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// NAS-Port-Id attribute (5, Integer)
+#[allow(dead_code)]
+pub mod nas_port_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Port-Id";
+    /// Attribute code
+    pub const CODE: u32 = 5;
+
+    /// Creates NAS-Port-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Port-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Port-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Values of Service-Type attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceType {
+    /// Login-User (1)
+    LoginUser,
+    /// Framed-User (2)
+    FramedUser,
+    /// Callback-Login-User (3)
+    CallbackLoginUser,
+    /// Callback-Framed-User (4)
+    CallbackFramedUser,
+    /// Outbound-User (5)
+    OutboundUser,
+    /// Administrative-User (6)
+    AdministrativeUser,
+    /// NAS-Prompt-User (7)
+    NasPromptUser,
+    /// Authenticate-Only (8)
+    AuthenticateOnly,
+    /// Callback-NAS-Prompt (9)
+    CallbackNasPrompt,
+    /// Call-Check (10)
+    CallCheck,
+    /// Callback-Administrative (11)
+    CallbackAdministrative
+}
+
+#[allow(dead_code)]
+impl ServiceType {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            ServiceType::LoginUser => 1,
+            ServiceType::FramedUser => 2,
+            ServiceType::CallbackLoginUser => 3,
+            ServiceType::CallbackFramedUser => 4,
+            ServiceType::OutboundUser => 5,
+            ServiceType::AdministrativeUser => 6,
+            ServiceType::NasPromptUser => 7,
+            ServiceType::AuthenticateOnly => 8,
+            ServiceType::CallbackNasPrompt => 9,
+            ServiceType::CallCheck => 10,
+            ServiceType::CallbackAdministrative => 11
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<ServiceType> {
+        match value {
+            1 => Some(ServiceType::LoginUser),
+            2 => Some(ServiceType::FramedUser),
+            3 => Some(ServiceType::CallbackLoginUser),
+            4 => Some(ServiceType::CallbackFramedUser),
+            5 => Some(ServiceType::OutboundUser),
+            6 => Some(ServiceType::AdministrativeUser),
+            7 => Some(ServiceType::NasPromptUser),
+            8 => Some(ServiceType::AuthenticateOnly),
+            9 => Some(ServiceType::CallbackNasPrompt),
+            10 => Some(ServiceType::CallCheck),
+            11 => Some(ServiceType::CallbackAdministrative),
+            _ => None
+        }
+    }
+}
+
+/// Service-Type attribute (6, Integer)
+#[allow(dead_code)]
+pub mod service_type {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::ServiceType;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Service-Type";
+    /// Attribute code
+    pub const CODE: u32 = 6;
+
+    /// Creates Service-Type RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: ServiceType) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Service-Type RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: ServiceType) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Service-Type RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<ServiceType, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            ServiceType::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Values of Framed-Protocol attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramedProtocol {
+    /// PPP (1)
+    Ppp,
+    /// SLIP (2)
+    Slip,
+    /// ARAP (3)
+    Arap,
+    /// GANDALF-SLMLP (4)
+    GandalfSlmlp,
+    /// XYLOGICS-IPX-SLIP (5)
+    XylogicsIpxSlip,
+    /// X75 (6)
+    X75
+}
+
+#[allow(dead_code)]
+impl FramedProtocol {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            FramedProtocol::Ppp => 1,
+            FramedProtocol::Slip => 2,
+            FramedProtocol::Arap => 3,
+            FramedProtocol::GandalfSlmlp => 4,
+            FramedProtocol::XylogicsIpxSlip => 5,
+            FramedProtocol::X75 => 6
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<FramedProtocol> {
+        match value {
+            1 => Some(FramedProtocol::Ppp),
+            2 => Some(FramedProtocol::Slip),
+            3 => Some(FramedProtocol::Arap),
+            4 => Some(FramedProtocol::GandalfSlmlp),
+            5 => Some(FramedProtocol::XylogicsIpxSlip),
+            6 => Some(FramedProtocol::X75),
+            _ => None
+        }
+    }
+}
+
+/// Framed-Protocol attribute (7, Integer)
+#[allow(dead_code)]
+pub mod framed_protocol {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::FramedProtocol;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Protocol";
+    /// Attribute code
+    pub const CODE: u32 = 7;
+
+    /// Creates Framed-Protocol RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: FramedProtocol) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Protocol RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: FramedProtocol) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Protocol RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<FramedProtocol, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            FramedProtocol::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Framed-IP-Address attribute (8, IPv4Addr)
+#[allow(dead_code)]
+pub mod framed_ip_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IP-Address";
+    /// Attribute code
+    pub const CODE: u32 = 8;
+
+    /// Creates Framed-IP-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IP-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IP-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// Framed-IP-Netmask attribute (9, IPv4Addr)
+#[allow(dead_code)]
+pub mod framed_ip_netmask {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IP-Netmask";
+    /// Attribute code
+    pub const CODE: u32 = 9;
+
+    /// Creates Framed-IP-Netmask RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IP-Netmask RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IP-Netmask RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// Values of Framed-Routing attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramedRouting {
+    /// None (0)
+    None,
+    /// Broadcast (1)
+    Broadcast,
+    /// Listen (2)
+    Listen,
+    /// Broadcast-Listen (3)
+    BroadcastListen
+}
+
+#[allow(dead_code)]
+impl FramedRouting {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            FramedRouting::None => 0,
+            FramedRouting::Broadcast => 1,
+            FramedRouting::Listen => 2,
+            FramedRouting::BroadcastListen => 3
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<FramedRouting> {
+        match value {
+            0 => Some(FramedRouting::None),
+            1 => Some(FramedRouting::Broadcast),
+            2 => Some(FramedRouting::Listen),
+            3 => Some(FramedRouting::BroadcastListen),
+            _ => None
+        }
+    }
+}
+
+/// Framed-Routing attribute (10, Integer)
+#[allow(dead_code)]
+pub mod framed_routing {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::FramedRouting;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Routing";
+    /// Attribute code
+    pub const CODE: u32 = 10;
+
+    /// Creates Framed-Routing RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: FramedRouting) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Routing RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: FramedRouting) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Routing RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<FramedRouting, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            FramedRouting::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Filter-Id attribute (11, AsciiString)
+#[allow(dead_code)]
+pub mod filter_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Filter-Id";
+    /// Attribute code
+    pub const CODE: u32 = 11;
+
+    /// Creates Filter-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Filter-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Filter-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-MTU attribute (12, Integer)
+#[allow(dead_code)]
+pub mod framed_mtu {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-MTU";
+    /// Attribute code
+    pub const CODE: u32 = 12;
+
+    /// Creates Framed-MTU RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-MTU RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-MTU RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Values of Framed-Compression attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramedCompression {
+    /// None (0)
+    None,
+    /// Van-Jacobson-TCP-IP (1)
+    VanJacobsonTcpIp,
+    /// IPX-Header (2)
+    IpxHeader,
+    /// Stac-LZS (3)
+    StacLzs
+}
+
+#[allow(dead_code)]
+impl FramedCompression {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            FramedCompression::None => 0,
+            FramedCompression::VanJacobsonTcpIp => 1,
+            FramedCompression::IpxHeader => 2,
+            FramedCompression::StacLzs => 3
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<FramedCompression> {
+        match value {
+            0 => Some(FramedCompression::None),
+            1 => Some(FramedCompression::VanJacobsonTcpIp),
+            2 => Some(FramedCompression::IpxHeader),
+            3 => Some(FramedCompression::StacLzs),
+            _ => None
+        }
+    }
+}
+
+/// Framed-Compression attribute (13, Integer)
+#[allow(dead_code)]
+pub mod framed_compression {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::FramedCompression;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Compression";
+    /// Attribute code
+    pub const CODE: u32 = 13;
+
+    /// Creates Framed-Compression RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: FramedCompression) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Compression RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: FramedCompression) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Compression RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<FramedCompression, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            FramedCompression::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Login-IP-Host attribute (14, IPv4Addr)
+#[allow(dead_code)]
+pub mod login_ip_host {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-IP-Host";
+    /// Attribute code
+    pub const CODE: u32 = 14;
+
+    /// Creates Login-IP-Host RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-IP-Host RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-IP-Host RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// Values of Login-Service attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoginService {
+    /// Telnet (0)
+    Telnet,
+    /// Rlogin (1)
+    Rlogin,
+    /// TCP-Clear (2)
+    TcpClear,
+    /// PortMaster (3)
+    Portmaster,
+    /// LAT (4)
+    Lat,
+    /// X.25-PAD (5)
+    X25Pad,
+    /// X.25-T3POS (6)
+    X25T3pos,
+    /// TCP-Clear-Quiet (8)
+    TcpClearQuiet
+}
+
+#[allow(dead_code)]
+impl LoginService {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            LoginService::Telnet => 0,
+            LoginService::Rlogin => 1,
+            LoginService::TcpClear => 2,
+            LoginService::Portmaster => 3,
+            LoginService::Lat => 4,
+            LoginService::X25Pad => 5,
+            LoginService::X25T3pos => 6,
+            LoginService::TcpClearQuiet => 8
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<LoginService> {
+        match value {
+            0 => Some(LoginService::Telnet),
+            1 => Some(LoginService::Rlogin),
+            2 => Some(LoginService::TcpClear),
+            3 => Some(LoginService::Portmaster),
+            4 => Some(LoginService::Lat),
+            5 => Some(LoginService::X25Pad),
+            6 => Some(LoginService::X25T3pos),
+            8 => Some(LoginService::TcpClearQuiet),
+            _ => None
+        }
+    }
+}
+
+/// Login-Service attribute (15, Integer)
+#[allow(dead_code)]
+pub mod login_service {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::LoginService;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-Service";
+    /// Attribute code
+    pub const CODE: u32 = 15;
+
+    /// Creates Login-Service RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: LoginService) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-Service RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: LoginService) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-Service RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<LoginService, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            LoginService::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Login-TCP-Port attribute (16, Integer)
+#[allow(dead_code)]
+pub mod login_tcp_port {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-TCP-Port";
+    /// Attribute code
+    pub const CODE: u32 = 16;
+
+    /// Creates Login-TCP-Port RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-TCP-Port RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-TCP-Port RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Reply-Message attribute (18, AsciiString)
+#[allow(dead_code)]
+pub mod reply_message {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Reply-Message";
+    /// Attribute code
+    pub const CODE: u32 = 18;
+
+    /// Creates Reply-Message RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Reply-Message RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Reply-Message RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Callback-Number attribute (19, AsciiString)
+#[allow(dead_code)]
+pub mod callback_number {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Callback-Number";
+    /// Attribute code
+    pub const CODE: u32 = 19;
+
+    /// Creates Callback-Number RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Callback-Number RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Callback-Number RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Callback-Id attribute (20, AsciiString)
+#[allow(dead_code)]
+pub mod callback_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Callback-Id";
+    /// Attribute code
+    pub const CODE: u32 = 20;
+
+    /// Creates Callback-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Callback-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Callback-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-Route attribute (22, AsciiString)
+#[allow(dead_code)]
+pub mod framed_route {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Route";
+    /// Attribute code
+    pub const CODE: u32 = 22;
+
+    /// Creates Framed-Route RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Route RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Route RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-IPX-Network attribute (23, IPv4Addr)
+#[allow(dead_code)]
+pub mod framed_ipx_network {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IPX-Network";
+    /// Attribute code
+    pub const CODE: u32 = 23;
+
+    /// Creates Framed-IPX-Network RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IPX-Network RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IPX-Network RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// State attribute (24, Octets)
+#[allow(dead_code)]
+pub mod state {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "State";
+    /// Attribute code
+    pub const CODE: u32 = 24;
+
+    /// Creates State RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds State RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first State RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Class attribute (25, Octets)
+#[allow(dead_code)]
+pub mod class {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Class";
+    /// Attribute code
+    pub const CODE: u32 = 25;
+
+    /// Creates Class RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Class RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Class RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Vendor-Specific attribute (26, Vsa)
+#[allow(dead_code)]
+pub mod vendor_specific {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Vendor-Specific";
+    /// Attribute code
+    pub const CODE: u32 = 26;
+
+    /// Creates Vendor-Specific RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Vendor-Specific RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Vendor-Specific RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Session-Timeout attribute (27, Integer)
+#[allow(dead_code)]
+pub mod session_timeout {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Session-Timeout";
+    /// Attribute code
+    pub const CODE: u32 = 27;
+
+    /// Creates Session-Timeout RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Session-Timeout RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Session-Timeout RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Idle-Timeout attribute (28, Integer)
+#[allow(dead_code)]
+pub mod idle_timeout {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Idle-Timeout";
+    /// Attribute code
+    pub const CODE: u32 = 28;
+
+    /// Creates Idle-Timeout RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Idle-Timeout RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Idle-Timeout RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Values of Termination-Action attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminationAction {
+    /// Default (0)
+    Default,
+    /// RADIUS-Request (1)
+    RadiusRequest
+}
+
+#[allow(dead_code)]
+impl TerminationAction {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            TerminationAction::Default => 0,
+            TerminationAction::RadiusRequest => 1
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<TerminationAction> {
+        match value {
+            0 => Some(TerminationAction::Default),
+            1 => Some(TerminationAction::RadiusRequest),
+            _ => None
+        }
+    }
+}
+
+/// Termination-Action attribute (29, Integer)
+#[allow(dead_code)]
+pub mod termination_action {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::TerminationAction;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Termination-Action";
+    /// Attribute code
+    pub const CODE: u32 = 29;
+
+    /// Creates Termination-Action RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: TerminationAction) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Termination-Action RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: TerminationAction) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Termination-Action RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<TerminationAction, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            TerminationAction::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Called-Station-Id attribute (30, AsciiString)
+#[allow(dead_code)]
+pub mod called_station_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Called-Station-Id";
+    /// Attribute code
+    pub const CODE: u32 = 30;
+
+    /// Creates Called-Station-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Called-Station-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Called-Station-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Calling-Station-Id attribute (31, AsciiString)
+#[allow(dead_code)]
+pub mod calling_station_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Calling-Station-Id";
+    /// Attribute code
+    pub const CODE: u32 = 31;
+
+    /// Creates Calling-Station-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Calling-Station-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Calling-Station-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// NAS-Identifier attribute (32, AsciiString)
+#[allow(dead_code)]
+pub mod nas_identifier {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Identifier";
+    /// Attribute code
+    pub const CODE: u32 = 32;
+
+    /// Creates NAS-Identifier RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Identifier RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Identifier RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Proxy-State attribute (33, Octets)
+#[allow(dead_code)]
+pub mod proxy_state {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Proxy-State";
+    /// Attribute code
+    pub const CODE: u32 = 33;
+
+    /// Creates Proxy-State RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Proxy-State RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Proxy-State RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Login-LAT-Service attribute (34, AsciiString)
+#[allow(dead_code)]
+pub mod login_lat_service {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-LAT-Service";
+    /// Attribute code
+    pub const CODE: u32 = 34;
+
+    /// Creates Login-LAT-Service RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-LAT-Service RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-LAT-Service RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Login-LAT-Node attribute (35, AsciiString)
+#[allow(dead_code)]
+pub mod login_lat_node {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-LAT-Node";
+    /// Attribute code
+    pub const CODE: u32 = 35;
+
+    /// Creates Login-LAT-Node RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-LAT-Node RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-LAT-Node RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Login-LAT-Group attribute (36, AsciiString)
+#[allow(dead_code)]
+pub mod login_lat_group {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-LAT-Group";
+    /// Attribute code
+    pub const CODE: u32 = 36;
+
+    /// Creates Login-LAT-Group RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-LAT-Group RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-LAT-Group RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-AppleTalk-Link attribute (37, Integer)
+#[allow(dead_code)]
+pub mod framed_appletalk_link {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-AppleTalk-Link";
+    /// Attribute code
+    pub const CODE: u32 = 37;
+
+    /// Creates Framed-AppleTalk-Link RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-AppleTalk-Link RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-AppleTalk-Link RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Framed-AppleTalk-Network attribute (38, Integer)
+#[allow(dead_code)]
+pub mod framed_appletalk_network {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-AppleTalk-Network";
+    /// Attribute code
+    pub const CODE: u32 = 38;
+
+    /// Creates Framed-AppleTalk-Network RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-AppleTalk-Network RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-AppleTalk-Network RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Framed-AppleTalk-Zone attribute (39, AsciiString)
+#[allow(dead_code)]
+pub mod framed_appletalk_zone {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-AppleTalk-Zone";
+    /// Attribute code
+    pub const CODE: u32 = 39;
+
+    /// Creates Framed-AppleTalk-Zone RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-AppleTalk-Zone RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-AppleTalk-Zone RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Values of Acct-Status-Type attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcctStatusType {
+    /// Start (1)
+    Start,
+    /// Stop (2)
+    Stop,
+    /// Alive (3)
+    Alive,
+    /// Accounting-On (7)
+    AccountingOn,
+    /// Accounting-Off (8)
+    AccountingOff
+}
+
+#[allow(dead_code)]
+impl AcctStatusType {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            AcctStatusType::Start => 1,
+            AcctStatusType::Stop => 2,
+            AcctStatusType::Alive => 3,
+            AcctStatusType::AccountingOn => 7,
+            AcctStatusType::AccountingOff => 8
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<AcctStatusType> {
+        match value {
+            1 => Some(AcctStatusType::Start),
+            2 => Some(AcctStatusType::Stop),
+            3 => Some(AcctStatusType::Alive),
+            7 => Some(AcctStatusType::AccountingOn),
+            8 => Some(AcctStatusType::AccountingOff),
+            _ => None
+        }
+    }
+}
+
+/// Acct-Status-Type attribute (40, Integer)
+#[allow(dead_code)]
+pub mod acct_status_type {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::AcctStatusType;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Status-Type";
+    /// Attribute code
+    pub const CODE: u32 = 40;
+
+    /// Creates Acct-Status-Type RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: AcctStatusType) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Status-Type RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: AcctStatusType) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Status-Type RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<AcctStatusType, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            AcctStatusType::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Acct-Delay-Time attribute (41, Integer)
+#[allow(dead_code)]
+pub mod acct_delay_time {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Delay-Time";
+    /// Attribute code
+    pub const CODE: u32 = 41;
+
+    /// Creates Acct-Delay-Time RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Delay-Time RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Delay-Time RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Input-Octets attribute (42, Integer)
+#[allow(dead_code)]
+pub mod acct_input_octets {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Input-Octets";
+    /// Attribute code
+    pub const CODE: u32 = 42;
+
+    /// Creates Acct-Input-Octets RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Input-Octets RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Input-Octets RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Output-Octets attribute (43, Integer)
+#[allow(dead_code)]
+pub mod acct_output_octets {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Output-Octets";
+    /// Attribute code
+    pub const CODE: u32 = 43;
+
+    /// Creates Acct-Output-Octets RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Output-Octets RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Output-Octets RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Session-Id attribute (44, AsciiString)
+#[allow(dead_code)]
+pub mod acct_session_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Session-Id";
+    /// Attribute code
+    pub const CODE: u32 = 44;
+
+    /// Creates Acct-Session-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Session-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Session-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Values of Acct-Authentic attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcctAuthentic {
+    /// RADIUS (1)
+    Radius,
+    /// Local (2)
+    Local,
+    /// Remote (3)
+    Remote
+}
+
+#[allow(dead_code)]
+impl AcctAuthentic {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            AcctAuthentic::Radius => 1,
+            AcctAuthentic::Local => 2,
+            AcctAuthentic::Remote => 3
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<AcctAuthentic> {
+        match value {
+            1 => Some(AcctAuthentic::Radius),
+            2 => Some(AcctAuthentic::Local),
+            3 => Some(AcctAuthentic::Remote),
+            _ => None
+        }
+    }
+}
+
+/// Acct-Authentic attribute (45, Integer)
+#[allow(dead_code)]
+pub mod acct_authentic {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::AcctAuthentic;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Authentic";
+    /// Attribute code
+    pub const CODE: u32 = 45;
+
+    /// Creates Acct-Authentic RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: AcctAuthentic) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Authentic RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: AcctAuthentic) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Authentic RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<AcctAuthentic, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            AcctAuthentic::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Acct-Session-Time attribute (46, Integer)
+#[allow(dead_code)]
+pub mod acct_session_time {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Session-Time";
+    /// Attribute code
+    pub const CODE: u32 = 46;
+
+    /// Creates Acct-Session-Time RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Session-Time RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Session-Time RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Input-Packets attribute (47, Integer)
+#[allow(dead_code)]
+pub mod acct_input_packets {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Input-Packets";
+    /// Attribute code
+    pub const CODE: u32 = 47;
+
+    /// Creates Acct-Input-Packets RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Input-Packets RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Input-Packets RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Output-Packets attribute (48, Integer)
+#[allow(dead_code)]
+pub mod acct_output_packets {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Output-Packets";
+    /// Attribute code
+    pub const CODE: u32 = 48;
+
+    /// Creates Acct-Output-Packets RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Output-Packets RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Output-Packets RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Values of Acct-Terminate-Cause attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcctTerminateCause {
+    /// User-Request (1)
+    UserRequest,
+    /// Lost-Carrier (2)
+    LostCarrier,
+    /// Lost-Service (3)
+    LostService,
+    /// Idle-Timeout (4)
+    IdleTimeout,
+    /// Session-Timeout (5)
+    SessionTimeout,
+    /// Admin-Reset (6)
+    AdminReset,
+    /// Admin-Reboot (7)
+    AdminReboot,
+    /// Port-Error (8)
+    PortError,
+    /// NAS-Error (9)
+    NasError,
+    /// NAS-Request (10)
+    NasRequest,
+    /// NAS-Reboot (11)
+    NasReboot,
+    /// Port-Unneeded (12)
+    PortUnneeded,
+    /// Port-Preempted (13)
+    PortPreempted,
+    /// Port-Suspended (14)
+    PortSuspended,
+    /// Service-Unavailable (15)
+    ServiceUnavailable,
+    /// Callback (16)
+    Callback,
+    /// User-Error (17)
+    UserError,
+    /// Host-Request (18)
+    HostRequest
+}
+
+#[allow(dead_code)]
+impl AcctTerminateCause {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            AcctTerminateCause::UserRequest => 1,
+            AcctTerminateCause::LostCarrier => 2,
+            AcctTerminateCause::LostService => 3,
+            AcctTerminateCause::IdleTimeout => 4,
+            AcctTerminateCause::SessionTimeout => 5,
+            AcctTerminateCause::AdminReset => 6,
+            AcctTerminateCause::AdminReboot => 7,
+            AcctTerminateCause::PortError => 8,
+            AcctTerminateCause::NasError => 9,
+            AcctTerminateCause::NasRequest => 10,
+            AcctTerminateCause::NasReboot => 11,
+            AcctTerminateCause::PortUnneeded => 12,
+            AcctTerminateCause::PortPreempted => 13,
+            AcctTerminateCause::PortSuspended => 14,
+            AcctTerminateCause::ServiceUnavailable => 15,
+            AcctTerminateCause::Callback => 16,
+            AcctTerminateCause::UserError => 17,
+            AcctTerminateCause::HostRequest => 18
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<AcctTerminateCause> {
+        match value {
+            1 => Some(AcctTerminateCause::UserRequest),
+            2 => Some(AcctTerminateCause::LostCarrier),
+            3 => Some(AcctTerminateCause::LostService),
+            4 => Some(AcctTerminateCause::IdleTimeout),
+            5 => Some(AcctTerminateCause::SessionTimeout),
+            6 => Some(AcctTerminateCause::AdminReset),
+            7 => Some(AcctTerminateCause::AdminReboot),
+            8 => Some(AcctTerminateCause::PortError),
+            9 => Some(AcctTerminateCause::NasError),
+            10 => Some(AcctTerminateCause::NasRequest),
+            11 => Some(AcctTerminateCause::NasReboot),
+            12 => Some(AcctTerminateCause::PortUnneeded),
+            13 => Some(AcctTerminateCause::PortPreempted),
+            14 => Some(AcctTerminateCause::PortSuspended),
+            15 => Some(AcctTerminateCause::ServiceUnavailable),
+            16 => Some(AcctTerminateCause::Callback),
+            17 => Some(AcctTerminateCause::UserError),
+            18 => Some(AcctTerminateCause::HostRequest),
+            _ => None
+        }
+    }
+}
+
+/// Acct-Terminate-Cause attribute (49, Integer)
+#[allow(dead_code)]
+pub mod acct_terminate_cause {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::AcctTerminateCause;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Terminate-Cause";
+    /// Attribute code
+    pub const CODE: u32 = 49;
+
+    /// Creates Acct-Terminate-Cause RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: AcctTerminateCause) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Terminate-Cause RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: AcctTerminateCause) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Terminate-Cause RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<AcctTerminateCause, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            AcctTerminateCause::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Acct-Multi-Session-Id attribute (50, AsciiString)
+#[allow(dead_code)]
+pub mod acct_multi_session_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Multi-Session-Id";
+    /// Attribute code
+    pub const CODE: u32 = 50;
+
+    /// Creates Acct-Multi-Session-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Multi-Session-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Multi-Session-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Acct-Link-Count attribute (51, Integer)
+#[allow(dead_code)]
+pub mod acct_link_count {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Link-Count";
+    /// Attribute code
+    pub const CODE: u32 = 51;
+
+    /// Creates Acct-Link-Count RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Link-Count RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Link-Count RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Input-Gigawords attribute (52, Integer)
+#[allow(dead_code)]
+pub mod acct_input_gigawords {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Input-Gigawords";
+    /// Attribute code
+    pub const CODE: u32 = 52;
+
+    /// Creates Acct-Input-Gigawords RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Input-Gigawords RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Input-Gigawords RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Output-Gigawords attribute (53, Integer)
+#[allow(dead_code)]
+pub mod acct_output_gigawords {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Output-Gigawords";
+    /// Attribute code
+    pub const CODE: u32 = 53;
+
+    /// Creates Acct-Output-Gigawords RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Output-Gigawords RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Output-Gigawords RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Event-Timestamp attribute (55, Integer)
+#[allow(dead_code)]
+pub mod event_timestamp {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Event-Timestamp";
+    /// Attribute code
+    pub const CODE: u32 = 55;
+
+    /// Creates Event-Timestamp RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Event-Timestamp RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Event-Timestamp RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Egress-VLANID attribute (56, AsciiString)
+#[allow(dead_code)]
+pub mod egress_vlanid {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Egress-VLANID";
+    /// Attribute code
+    pub const CODE: u32 = 56;
+
+    /// Creates Egress-VLANID RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Egress-VLANID RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Egress-VLANID RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Ingress-Filters attribute (57, Integer)
+#[allow(dead_code)]
+pub mod ingress_filters {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Ingress-Filters";
+    /// Attribute code
+    pub const CODE: u32 = 57;
+
+    /// Creates Ingress-Filters RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Ingress-Filters RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Ingress-Filters RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Egress-VLAN-Name attribute (58, AsciiString)
+#[allow(dead_code)]
+pub mod egress_vlan_name {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Egress-VLAN-Name";
+    /// Attribute code
+    pub const CODE: u32 = 58;
+
+    /// Creates Egress-VLAN-Name RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Egress-VLAN-Name RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Egress-VLAN-Name RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// User-Priority-Table attribute (59, AsciiString)
+#[allow(dead_code)]
+pub mod user_priority_table {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "User-Priority-Table";
+    /// Attribute code
+    pub const CODE: u32 = 59;
+
+    /// Creates User-Priority-Table RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds User-Priority-Table RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first User-Priority-Table RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// CHAP-Challenge attribute (60, Octets)
+#[allow(dead_code)]
+pub mod chap_challenge {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "CHAP-Challenge";
+    /// Attribute code
+    pub const CODE: u32 = 60;
+
+    /// Creates CHAP-Challenge RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds CHAP-Challenge RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first CHAP-Challenge RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Values of NAS-Port-Type attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NasPortType {
+    /// Async (0)
+    Async,
+    /// Sync (1)
+    Sync,
+    /// ISDN (2)
+    Isdn,
+    /// ISDN-V120 (3)
+    IsdnV120,
+    /// ISDN-V110 (4)
+    IsdnV110,
+    /// Virtual (5)
+    Virtual,
+    /// PIAFS (6)
+    Piafs,
+    /// HDLC-Clear-Channel (7)
+    HdlcClearChannel,
+    /// X.25 (8)
+    X25,
+    /// X.75 (9)
+    X75,
+    /// G.3-Fax (10)
+    G3Fax,
+    /// SDSL (11)
+    Sdsl,
+    /// ADSL-CAP (12)
+    AdslCap,
+    /// ADSL-DMT (13)
+    AdslDmt,
+    /// IDSL (14)
+    Idsl,
+    /// Ethernet (15)
+    Ethernet
+}
+
+#[allow(dead_code)]
+impl NasPortType {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            NasPortType::Async => 0,
+            NasPortType::Sync => 1,
+            NasPortType::Isdn => 2,
+            NasPortType::IsdnV120 => 3,
+            NasPortType::IsdnV110 => 4,
+            NasPortType::Virtual => 5,
+            NasPortType::Piafs => 6,
+            NasPortType::HdlcClearChannel => 7,
+            NasPortType::X25 => 8,
+            NasPortType::X75 => 9,
+            NasPortType::G3Fax => 10,
+            NasPortType::Sdsl => 11,
+            NasPortType::AdslCap => 12,
+            NasPortType::AdslDmt => 13,
+            NasPortType::Idsl => 14,
+            NasPortType::Ethernet => 15
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<NasPortType> {
+        match value {
+            0 => Some(NasPortType::Async),
+            1 => Some(NasPortType::Sync),
+            2 => Some(NasPortType::Isdn),
+            3 => Some(NasPortType::IsdnV120),
+            4 => Some(NasPortType::IsdnV110),
+            5 => Some(NasPortType::Virtual),
+            6 => Some(NasPortType::Piafs),
+            7 => Some(NasPortType::HdlcClearChannel),
+            8 => Some(NasPortType::X25),
+            9 => Some(NasPortType::X75),
+            10 => Some(NasPortType::G3Fax),
+            11 => Some(NasPortType::Sdsl),
+            12 => Some(NasPortType::AdslCap),
+            13 => Some(NasPortType::AdslDmt),
+            14 => Some(NasPortType::Idsl),
+            15 => Some(NasPortType::Ethernet),
+            _ => None
+        }
+    }
+}
+
+/// NAS-Port-Type attribute (61, Integer)
+#[allow(dead_code)]
+pub mod nas_port_type {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::NasPortType;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Port-Type";
+    /// Attribute code
+    pub const CODE: u32 = 61;
+
+    /// Creates NAS-Port-Type RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: NasPortType) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Port-Type RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: NasPortType) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Port-Type RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<NasPortType, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            NasPortType::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Port-Limit attribute (62, Integer)
+#[allow(dead_code)]
+pub mod port_limit {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Port-Limit";
+    /// Attribute code
+    pub const CODE: u32 = 62;
+
+    /// Creates Port-Limit RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Port-Limit RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Port-Limit RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Login-LAT-Port attribute (63, Integer)
+#[allow(dead_code)]
+pub mod login_lat_port {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-LAT-Port";
+    /// Attribute code
+    pub const CODE: u32 = 63;
+
+    /// Creates Login-LAT-Port RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-LAT-Port RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-LAT-Port RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Tunnel-Type attribute (64, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_type {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Type";
+    /// Attribute code
+    pub const CODE: u32 = 64;
+
+    /// Creates Tunnel-Type RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Type RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Type RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Medium-Type attribute (65, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_medium_type {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Medium-Type";
+    /// Attribute code
+    pub const CODE: u32 = 65;
+
+    /// Creates Tunnel-Medium-Type RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Medium-Type RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Medium-Type RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Client-Endpoint attribute (66, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_client_endpoint {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Client-Endpoint";
+    /// Attribute code
+    pub const CODE: u32 = 66;
+
+    /// Creates Tunnel-Client-Endpoint RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Client-Endpoint RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Client-Endpoint RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Server-Endpoint attribute (67, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_server_endpoint {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Server-Endpoint";
+    /// Attribute code
+    pub const CODE: u32 = 67;
+
+    /// Creates Tunnel-Server-Endpoint RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Server-Endpoint RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Server-Endpoint RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Acct-Tunnel-Connection attribute (68, AsciiString)
+#[allow(dead_code)]
+pub mod acct_tunnel_connection {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Tunnel-Connection";
+    /// Attribute code
+    pub const CODE: u32 = 68;
+
+    /// Creates Acct-Tunnel-Connection RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Tunnel-Connection RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Tunnel-Connection RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Password attribute (69, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_password {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Password";
+    /// Attribute code
+    pub const CODE: u32 = 69;
+
+    /// Creates Tunnel-Password RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Password RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Password RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// ARAP-Password attribute (70, Octets)
+#[allow(dead_code)]
+pub mod arap_password {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Password";
+    /// Attribute code
+    pub const CODE: u32 = 70;
+
+    /// Creates ARAP-Password RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Password RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Password RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// ARAP-Features attribute (71, Octets)
+#[allow(dead_code)]
+pub mod arap_features {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Features";
+    /// Attribute code
+    pub const CODE: u32 = 71;
+
+    /// Creates ARAP-Features RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Features RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Features RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// ARAP-Zone-Access attribute (72, Integer)
+#[allow(dead_code)]
+pub mod arap_zone_access {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Zone-Access";
+    /// Attribute code
+    pub const CODE: u32 = 72;
+
+    /// Creates ARAP-Zone-Access RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Zone-Access RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Zone-Access RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// ARAP-Security attribute (73, Integer)
+#[allow(dead_code)]
+pub mod arap_security {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Security";
+    /// Attribute code
+    pub const CODE: u32 = 73;
+
+    /// Creates ARAP-Security RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Security RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Security RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// ARAP-Security-Data attribute (74, AsciiString)
+#[allow(dead_code)]
+pub mod arap_security_data {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Security-Data";
+    /// Attribute code
+    pub const CODE: u32 = 74;
+
+    /// Creates ARAP-Security-Data RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Security-Data RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Security-Data RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Password-Retry attribute (75, Integer)
+#[allow(dead_code)]
+pub mod password_retry {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Password-Retry";
+    /// Attribute code
+    pub const CODE: u32 = 75;
+
+    /// Creates Password-Retry RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Password-Retry RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Password-Retry RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Prompt attribute (76, Integer)
+#[allow(dead_code)]
+pub mod prompt {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Prompt";
+    /// Attribute code
+    pub const CODE: u32 = 76;
+
+    /// Creates Prompt RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Prompt RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Prompt RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Connect-Info attribute (77, AsciiString)
+#[allow(dead_code)]
+pub mod connect_info {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Connect-Info";
+    /// Attribute code
+    pub const CODE: u32 = 77;
+
+    /// Creates Connect-Info RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Connect-Info RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Connect-Info RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Configuration-Token attribute (78, AsciiString)
+#[allow(dead_code)]
+pub mod configuration_token {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Configuration-Token";
+    /// Attribute code
+    pub const CODE: u32 = 78;
+
+    /// Creates Configuration-Token RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Configuration-Token RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Configuration-Token RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// EAP-Message attribute (79, Octets)
+#[allow(dead_code)]
+pub mod eap_message {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "EAP-Message";
+    /// Attribute code
+    pub const CODE: u32 = 79;
+
+    /// Creates EAP-Message RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds EAP-Message RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first EAP-Message RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Message-Authenticator attribute (80, Octets)
+#[allow(dead_code)]
+pub mod message_authenticator {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Message-Authenticator";
+    /// Attribute code
+    pub const CODE: u32 = 80;
+
+    /// Creates Message-Authenticator RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Message-Authenticator RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Message-Authenticator RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Tunnel-Private-Group-ID attribute (81, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_private_group_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Private-Group-ID";
+    /// Attribute code
+    pub const CODE: u32 = 81;
+
+    /// Creates Tunnel-Private-Group-ID RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Private-Group-ID RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Private-Group-ID RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Assignment-ID attribute (82, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_assignment_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Assignment-ID";
+    /// Attribute code
+    pub const CODE: u32 = 82;
+
+    /// Creates Tunnel-Assignment-ID RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Assignment-ID RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Assignment-ID RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Preference attribute (83, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_preference {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Preference";
+    /// Attribute code
+    pub const CODE: u32 = 83;
+
+    /// Creates Tunnel-Preference RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Preference RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Preference RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// ARAP-Challenge-Response attribute (84, Octets)
+#[allow(dead_code)]
+pub mod arap_challenge_response {
+    use ::radius_rust::protocol::dictionary::Dictionary;
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "ARAP-Challenge-Response";
+    /// Attribute code
+    pub const CODE: u32 = 84;
+
+    /// Creates ARAP-Challenge-Response RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &[u8]) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds ARAP-Challenge-Response RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &[u8]) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first ARAP-Challenge-Response RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<Vec<u8>, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| Ok(attr.value().to_vec()))
+    }
+}
+
+/// Acct-Interim-Interval attribute (85, Integer)
+#[allow(dead_code)]
+pub mod acct_interim_interval {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Interim-Interval";
+    /// Attribute code
+    pub const CODE: u32 = 85;
+
+    /// Creates Acct-Interim-Interval RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Interim-Interval RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Interim-Interval RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Acct-Tunnel-Packets-Lost attribute (86, Integer)
+#[allow(dead_code)]
+pub mod acct_tunnel_packets_lost {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Acct-Tunnel-Packets-Lost";
+    /// Attribute code
+    pub const CODE: u32 = 86;
+
+    /// Creates Acct-Tunnel-Packets-Lost RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Acct-Tunnel-Packets-Lost RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Acct-Tunnel-Packets-Lost RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// NAS-Port-Id-String attribute (87, AsciiString)
+#[allow(dead_code)]
+pub mod nas_port_id_string {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Port-Id-String";
+    /// Attribute code
+    pub const CODE: u32 = 87;
+
+    /// Creates NAS-Port-Id-String RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Port-Id-String RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Port-Id-String RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-Pool attribute (88, AsciiString)
+#[allow(dead_code)]
+pub mod framed_pool {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Pool";
+    /// Attribute code
+    pub const CODE: u32 = 88;
+
+    /// Creates Framed-Pool RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Pool RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Pool RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Chargeable-User-Identity attribute (89, AsciiString)
+#[allow(dead_code)]
+pub mod chargeable_user_identity {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Chargeable-User-Identity";
+    /// Attribute code
+    pub const CODE: u32 = 89;
+
+    /// Creates Chargeable-User-Identity RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Chargeable-User-Identity RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Chargeable-User-Identity RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Client-Auth-ID attribute (90, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_client_auth_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Client-Auth-ID";
+    /// Attribute code
+    pub const CODE: u32 = 90;
+
+    /// Creates Tunnel-Client-Auth-ID RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Client-Auth-ID RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Client-Auth-ID RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Tunnel-Server-Auth-ID attribute (91, AsciiString)
+#[allow(dead_code)]
+pub mod tunnel_server_auth_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Tunnel-Server-Auth-ID";
+    /// Attribute code
+    pub const CODE: u32 = 91;
+
+    /// Creates Tunnel-Server-Auth-ID RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Tunnel-Server-Auth-ID RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Tunnel-Server-Auth-ID RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// NAS-Filter-Rule attribute (92, AsciiString)
+#[allow(dead_code)]
+pub mod nas_filter_rule {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Filter-Rule";
+    /// Attribute code
+    pub const CODE: u32 = 92;
+
+    /// Creates NAS-Filter-Rule RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Filter-Rule RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Filter-Rule RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Originating-Line-Info attribute (94, AsciiString)
+#[allow(dead_code)]
+pub mod originating_line_info {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Originating-Line-Info";
+    /// Attribute code
+    pub const CODE: u32 = 94;
+
+    /// Creates Originating-Line-Info RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Originating-Line-Info RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Originating-Line-Info RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// NAS-IPv6-Address attribute (95, IPv6Addr)
+#[allow(dead_code)]
+pub mod nas_ipv6_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-IPv6-Address";
+    /// Attribute code
+    pub const CODE: u32 = 95;
+
+    /// Creates NAS-IPv6-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-IPv6-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-IPv6-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Addr)))
+    }
+}
+
+/// Framed-Interface-Id attribute (96, IfId)
+#[allow(dead_code)]
+pub mod framed_interface_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ifid_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Interface-Id";
+    /// Attribute code
+    pub const CODE: u32 = 96;
+
+    /// Creates Framed-Interface-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ifid_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Interface-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Interface-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IfId)))
+    }
+}
+
+/// Framed-IPv6-Prefix attribute (97, IPv6Prefix)
+#[allow(dead_code)]
+pub mod framed_ipv6_prefix {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IPv6-Prefix";
+    /// Attribute code
+    pub const CODE: u32 = 97;
+
+    /// Creates Framed-IPv6-Prefix RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IPv6-Prefix RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IPv6-Prefix RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Prefix)))
+    }
+}
+
+/// Login-IPv6-Host attribute (98, IPv6Addr)
+#[allow(dead_code)]
+pub mod login_ipv6_host {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Login-IPv6-Host";
+    /// Attribute code
+    pub const CODE: u32 = 98;
+
+    /// Creates Login-IPv6-Host RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Login-IPv6-Host RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Login-IPv6-Host RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Addr)))
+    }
+}
+
+/// Framed-IPv6-Route attribute (99, AsciiString)
+#[allow(dead_code)]
+pub mod framed_ipv6_route {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IPv6-Route";
+    /// Attribute code
+    pub const CODE: u32 = 99;
+
+    /// Creates Framed-IPv6-Route RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IPv6-Route RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IPv6-Route RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-IPv6-Pool attribute (100, AsciiString)
+#[allow(dead_code)]
+pub mod framed_ipv6_pool {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IPv6-Pool";
+    /// Attribute code
+    pub const CODE: u32 = 100;
+
+    /// Creates Framed-IPv6-Pool RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IPv6-Pool RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IPv6-Pool RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Error-Cause attribute (101, Integer)
+#[allow(dead_code)]
+pub mod error_cause {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Error-Cause";
+    /// Attribute code
+    pub const CODE: u32 = 101;
+
+    /// Creates Error-Cause RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Error-Cause RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Error-Cause RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// EAP-Key-Name attribute (102, AsciiString)
+#[allow(dead_code)]
+pub mod eap_key_name {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "EAP-Key-Name";
+    /// Attribute code
+    pub const CODE: u32 = 102;
+
+    /// Creates EAP-Key-Name RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds EAP-Key-Name RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first EAP-Key-Name RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Framed-IPv6-Address attribute (168, IPv6Addr)
+#[allow(dead_code)]
+pub mod framed_ipv6_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-IPv6-Address";
+    /// Attribute code
+    pub const CODE: u32 = 168;
+
+    /// Creates Framed-IPv6-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-IPv6-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-IPv6-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Addr)))
+    }
+}
+
+/// DNS-Server-IPv6-Address attribute (169, IPv6Addr)
+#[allow(dead_code)]
+pub mod dns_server_ipv6_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "DNS-Server-IPv6-Address";
+    /// Attribute code
+    pub const CODE: u32 = 169;
+
+    /// Creates DNS-Server-IPv6-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds DNS-Server-IPv6-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first DNS-Server-IPv6-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Addr)))
+    }
+}
+
+/// Route-IPv6-Information attribute (170, IPv6Prefix)
+#[allow(dead_code)]
+pub mod route_ipv6_information {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv6_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Route-IPv6-Information";
+    /// Attribute code
+    pub const CODE: u32 = 170;
+
+    /// Creates Route-IPv6-Information RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv6_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Route-IPv6-Information RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Route-IPv6-Information RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv6Prefix)))
+    }
+}
@@ -0,0 +1,280 @@
+// Generated by radius-rust from RADIUS dictionary, do not edit
+
+/// User-Name attribute (1, AsciiString)
+#[allow(dead_code)]
+pub mod user_name {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "User-Name";
+    /// Attribute code
+    pub const CODE: u32 = 1;
+
+    /// Creates User-Name RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds User-Name RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first User-Name RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// NAS-IP-Address attribute (4, IPv4Addr)
+#[allow(dead_code)]
+pub mod nas_ip_address {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-IP-Address";
+    /// Attribute code
+    pub const CODE: u32 = 4;
+
+    /// Creates NAS-IP-Address RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-IP-Address RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-IP-Address RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
+
+/// NAS-Port-Id attribute (5, Integer)
+#[allow(dead_code)]
+pub mod nas_port_id {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "NAS-Port-Id";
+    /// Attribute code
+    pub const CODE: u32 = 5;
+
+    /// Creates NAS-Port-Id RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: u32) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value);
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds NAS-Port-Id RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: u32) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first NAS-Port-Id RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<u32, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_integer_value(&Some(SupportedAttributeTypes::Integer)).map(|value| value as u32))
+    }
+}
+
+/// Values of Framed-Protocol attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramedProtocol {
+    /// PPP (1)
+    Ppp
+}
+
+#[allow(dead_code)]
+impl FramedProtocol {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            FramedProtocol::Ppp => 1
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<FramedProtocol> {
+        match value {
+            1 => Some(FramedProtocol::Ppp),
+            _ => None
+        }
+    }
+}
+
+/// Framed-Protocol attribute (7, Integer)
+#[allow(dead_code)]
+pub mod framed_protocol {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::FramedProtocol;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Framed-Protocol";
+    /// Attribute code
+    pub const CODE: u32 = 7;
+
+    /// Creates Framed-Protocol RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: FramedProtocol) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Framed-Protocol RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: FramedProtocol) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Framed-Protocol RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<FramedProtocol, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            FramedProtocol::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Somevendor-Name attribute (1, AsciiString)
+#[allow(dead_code)]
+pub mod somevendor_name {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Somevendor-Name";
+    /// Attribute code
+    pub const CODE: u32 = 1;
+    /// Vendor-Id of attribute's vendor
+    pub const VENDOR_ID: u32 = 10;
+
+    /// Creates Somevendor-Name RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = value.as_bytes().to_vec();
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Somevendor-Name RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Somevendor-Name RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::AsciiString)))
+    }
+}
+
+/// Values of Somevendor-Number attribute
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SomevendorNumber {
+    /// Two (2)
+    Two
+}
+
+#[allow(dead_code)]
+impl SomevendorNumber {
+    /// Returns value, as defined in dictionary
+    pub fn value(&self) -> u32 {
+        match self {
+            SomevendorNumber::Two => 2
+        }
+    }
+
+    /// Returns enumerated value by its number, if it is defined in dictionary
+    pub fn from_value(value: u32) -> Option<SomevendorNumber> {
+        match value {
+            2 => Some(SomevendorNumber::Two),
+            _ => None
+        }
+    }
+}
+
+/// Somevendor-Number attribute (2, Integer)
+#[allow(dead_code)]
+pub mod somevendor_number {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::integer_to_bytes;
+    use super::SomevendorNumber;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Somevendor-Number";
+    /// Attribute code
+    pub const CODE: u32 = 2;
+    /// Vendor-Id of attribute's vendor
+    pub const VENDOR_ID: u32 = 10;
+
+    /// Creates Somevendor-Number RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: SomevendorNumber) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = integer_to_bytes(value.value());
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Somevendor-Number RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: SomevendorNumber) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Somevendor-Number RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<SomevendorNumber, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| {
+            let value = attr.original_integer_value(&Some(SupportedAttributeTypes::Integer))?;
+            SomevendorNumber::from_value(value as u32).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("{} is not a known {} value", value, NAME) })
+        })
+    }
+}
+
+/// Test-IP attribute (25, IPv4Addr)
+#[allow(dead_code)]
+pub mod test_ip {
+    use ::radius_rust::protocol::dictionary::{ Dictionary, SupportedAttributeTypes };
+    use ::radius_rust::protocol::error::RadiusError;
+    use ::radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket };
+    use ::radius_rust::tools::ipv4_string_to_bytes;
+
+    /// Attribute name, as defined in dictionary
+    pub const NAME: &str = "Test-IP";
+    /// Attribute code
+    pub const CODE: u32 = 25;
+
+    /// Creates Test-IP RadiusAttribute
+    pub fn create(dictionary: &Dictionary, value: &str) -> Result<RadiusAttribute, RadiusError> {
+        let bytes = ipv4_string_to_bytes(value)?;
+        RadiusAttribute::create_by_name(dictionary, NAME, bytes).ok_or_else(|| RadiusError::MalformedAttributeError { error: format!("Failed to create: {:?} attribute. Check if attribute exists in provided dictionary file", NAME) })
+    }
+
+    /// Adds Test-IP RadiusAttribute to RadiusPacket
+    pub fn add(packet: &mut RadiusPacket, dictionary: &Dictionary, value: &str) -> Result<(), RadiusError> {
+        packet.add_attribute(create(dictionary, value)?);
+        Ok(())
+    }
+
+    /// Returns value of the first Test-IP RadiusAttribute of RadiusPacket, if there is one
+    pub fn get(packet: &RadiusPacket) -> Option<Result<String, RadiusError>> {
+        packet.attribute_by_name(NAME).map(|attr| attr.original_string_value(&Some(SupportedAttributeTypes::IPv4Addr)))
+    }
+}
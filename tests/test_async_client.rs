@@ -53,8 +53,8 @@ impl AsyncClientTrait for ClientWrapper {
                 break;
             }
 
-            debug!("Sending: {:?}", &packet.to_bytes());
-            self.socket.send_to(&packet.to_bytes(), &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            debug!("Sending: {:?}", &packet.to_bytes()?);
+            self.socket.send_to(&packet.to_bytes()?, &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             let mut response = [0; 4096];
             let (amount, _)  = self.socket.recv_from(&mut response).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
@@ -78,7 +78,7 @@ impl AsyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, &remote).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             let mut response = [0; 4096];
             let (amount, _)  = self.socket.recv_from(&mut response).await.map_err(|error| RadiusError::SocketConnectionError(error))?;
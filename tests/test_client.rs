@@ -57,7 +57,7 @@ impl SyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
             self.socket_poll.poll(&mut events, Some(timeout)).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             for event in events.iter() {
@@ -89,7 +89,7 @@ impl SyncClientTrait for ClientWrapper {
             if retry >= self.base_client.retries() {
                 break;
             }
-            self.socket.send_to(&packet.to_bytes(), remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
+            self.socket.send_to(&packet.to_bytes()?, remote).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
             self.socket_poll.poll(&mut events, Some(timeout)).map_err(|error| RadiusError::SocketConnectionError(error))?;
 
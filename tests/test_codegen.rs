@@ -0,0 +1,79 @@
+use radius_rust::protocol::dictionary::Dictionary;
+use radius_rust::protocol::radius_packet::{ RadiusAttribute, RadiusPacket, TypeCode };
+
+// Generated with radius_rust::codegen::generate_from_file("./dict_examples/integration_dict")
+mod integration_dict {
+    include!("generated/integration_dict.rs");
+}
+
+// Generated with radius_rust::codegen::generate_from_file("./dict_examples/test_dictionary_dict")
+mod test_dictionary_dict {
+    include!("generated/test_dictionary_dict.rs");
+}
+
+use integration_dict::{ acct_session_time, class, framed_ip_address, nas_port_type, service_type, user_name, NasPortType, ServiceType };
+use test_dictionary_dict::{ somevendor_name, somevendor_number, SomevendorNumber };
+
+
+#[test]
+fn test_generated_constants() {
+    assert_eq!("User-Name",    user_name::NAME);
+    assert_eq!(1,              user_name::CODE);
+    assert_eq!("Service-Type", service_type::NAME);
+    assert_eq!(6,              service_type::CODE);
+
+    assert_eq!(2,                              ServiceType::FramedUser.value());
+    assert_eq!(Some(ServiceType::LoginUser),   ServiceType::from_value(1));
+    assert_eq!(None,                           ServiceType::from_value(1000));
+    assert_eq!(Some(NasPortType::Ethernet),    NasPortType::from_value(15));
+}
+
+#[test]
+fn test_generated_setters_and_getters() {
+    let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+    let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+
+    user_name::add(&mut packet, &dictionary, "testing").unwrap();
+    service_type::add(&mut packet, &dictionary, ServiceType::FramedUser).unwrap();
+    framed_ip_address::add(&mut packet, &dictionary, "192.168.0.10").unwrap();
+    acct_session_time::add(&mut packet, &dictionary, 3600).unwrap();
+    class::add(&mut packet, &dictionary, &[1, 2, 3]).unwrap();
+
+    let packet_bytes = packet.to_bytes().unwrap();
+    let packet       = RadiusPacket::initialise_packet_from_bytes(&dictionary, &packet_bytes).unwrap();
+
+    assert_eq!("testing",                user_name::get(&packet).unwrap().unwrap());
+    assert_eq!(ServiceType::FramedUser,  service_type::get(&packet).unwrap().unwrap());
+    assert_eq!("192.168.0.10",           framed_ip_address::get(&packet).unwrap().unwrap());
+    assert_eq!(3600,                     acct_session_time::get(&packet).unwrap().unwrap());
+    assert_eq!(vec![1, 2, 3],            class::get(&packet).unwrap().unwrap());
+    assert!(nas_port_type::get(&packet).is_none());
+}
+
+#[test]
+fn test_generated_getter_unknown_value() {
+    let dictionary = Dictionary::from_file("./dict_examples/integration_dict").unwrap();
+    let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+
+    packet.add_attribute(RadiusAttribute::create_by_name(&dictionary, service_type::NAME, vec![0, 0, 3, 232]).unwrap());
+
+    assert!(service_type::get(&packet).unwrap().is_err());
+}
+
+#[test]
+fn test_generated_vendor_attributes() {
+    let dictionary = Dictionary::from_file("./dict_examples/test_dictionary_dict").unwrap();
+    let mut packet = RadiusPacket::initialise_packet(TypeCode::AccessRequest);
+
+    assert_eq!(10, somevendor_number::VENDOR_ID);
+
+    somevendor_name::add(&mut packet, &dictionary, "testing").unwrap();
+    somevendor_number::add(&mut packet, &dictionary, SomevendorNumber::Two).unwrap();
+
+    let packet_bytes = packet.to_bytes().unwrap();
+    let packet       = RadiusPacket::initialise_packet_from_bytes(&dictionary, &packet_bytes).unwrap();
+
+    assert_eq!(Some(10),               packet.attribute_by_name(somevendor_name::NAME).unwrap().vendor_id());
+    assert_eq!("testing",              somevendor_name::get(&packet).unwrap().unwrap());
+    assert_eq!(SomevendorNumber::Two,  somevendor_number::get(&packet).unwrap().unwrap());
+}